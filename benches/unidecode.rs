@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate criterion;
+extern crate unidecode;
+
+use criterion::{black_box, Criterion};
+use unidecode::{unidecode, unidecode_cow, unidecode_into, unidecode_to_io};
+
+const FIELDS: [&str; 6] = [
+    "Æneid",
+    "étude",
+    "北亰",
+    "Hello, world!",
+    "Schrödinger's café",
+    "ᔕᓇᓇ",
+];
+
+fn bench_unidecode(c: &mut Criterion) {
+    c.bench_function("unidecode", |b| {
+        b.iter(|| {
+            for field in FIELDS.iter() {
+                black_box(unidecode(black_box(field)));
+            }
+        })
+    });
+
+    c.bench_function("unidecode_into", |b| {
+        let mut out = String::new();
+        b.iter(|| {
+            for field in FIELDS.iter() {
+                out.clear();
+                unidecode_into(black_box(field), &mut out);
+                black_box(&out);
+            }
+        })
+    });
+
+    c.bench_function("unidecode_to_io", |b| {
+        let mut out = Vec::new();
+        b.iter(|| {
+            for field in FIELDS.iter() {
+                out.clear();
+                unidecode_to_io(black_box(field), &mut out).unwrap();
+                black_box(&out);
+            }
+        })
+    });
+
+    c.bench_function("unidecode_ascii", |b| {
+        b.iter(|| black_box(unidecode(black_box("Hello, world!"))))
+    });
+
+    c.bench_function("unidecode_cow_ascii", |b| {
+        b.iter(|| black_box(unidecode_cow(black_box("Hello, world!"))))
+    });
+}
+
+criterion_group!(benches, bench_unidecode);
+criterion_main!(benches);
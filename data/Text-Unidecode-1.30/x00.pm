@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x00] = [
+"\x00", "\x01", "\x02", "\x03", "\x04", "\x05", "\x06", "\x07", # 0x00
+"\x08", "\x09", "\x0A", "\x0B", "\x0C", "\x0D", "\x0E", "\x0F", # 0x08
+"\x10", "\x11", "\x12", "\x13", "\x14", "\x15", "\x16", "\x17", # 0x10
+"\x18", "\x19", "\x1A", "\x1B", "\x1C", "\x1D", "\x1E", "\x1F", # 0x18
+' ', '!', '"', '#', '$', '%', '&', "'", # 0x20
+'(', ')', '*', '+', ',', '-', '.', '/', # 0x28
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x30
+'8', '9', ':', ';', '<', '=', '>', '?', # 0x38
+'@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', # 0x40
+'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', # 0x48
+'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', # 0x50
+'X', 'Y', 'Z', '[', "\\", ']', '^', '_', # 0x58
+'`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', # 0x60
+'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', # 0x68
+'p', 'q', 'r', 's', 't', 'u', 'v', 'w', # 0x70
+'x', 'y', 'z', '{', '|', '}', '~', "\x7F", # 0x78
+'EUR', '', ',', 'f', ',,', '...', '+', '++', # 0x80
+'^', '%0', 'S', '<', 'OE', '', 'Z', '', # 0x88
+'', "'", "'", '"', '"', '*', '-', '--', # 0x90
+'~', 'tm', 's', '>', 'oe', '', 'z', 'Y', # 0x98
+' ', '!', 'C/', 'PS', '$?', 'Y=', '|', 'SS', # 0xA0
+'"', '(c)', 'a', '<<', '!', '', '(r)', '-', # 0xA8
+'deg', '+-', '2', '3', "'", 'u', 'P', '*', # 0xB0
+',', '1', 'o', '>>', '1/4', '1/2', '3/4', '?', # 0xB8
+'A', 'A', 'A', 'A', 'A', 'A', 'AE', 'C', # 0xC0
+'E', 'E', 'E', 'E', 'I', 'I', 'I', 'I', # 0xC8
+'D', 'N', 'O', 'O', 'O', 'O', 'O', 'x', # 0xD0
+'O', 'U', 'U', 'U', 'U', 'Y', 'Th', 'ss', # 0xD8
+'a', 'a', 'a', 'a', 'a', 'a', 'ae', 'c', # 0xE0
+'e', 'e', 'e', 'e', 'i', 'i', 'i', 'i', # 0xE8
+'d', 'n', 'o', 'o', 'o', 'o', 'o', '/', # 0xF0
+'o', 'u', 'u', 'u', 'u', 'y', 'th', 'y', # 0xF8
+];
+1;
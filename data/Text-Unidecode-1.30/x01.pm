@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x01] = [
+'A', 'a', 'A', 'a', 'A', 'a', 'C', 'c', # 0x00
+'C', 'c', 'C', 'c', 'C', 'c', 'D', 'd', # 0x08
+'D', 'd', 'E', 'e', 'E', 'e', 'E', 'e', # 0x10
+'E', 'e', 'E', 'e', 'G', 'g', 'G', 'g', # 0x18
+'G', 'g', 'G', 'g', 'H', 'h', 'H', 'h', # 0x20
+'I', 'i', 'I', 'i', 'I', 'i', 'I', 'i', # 0x28
+'I', 'i', 'IJ', 'ij', 'J', 'j', 'K', 'k', # 0x30
+'k', 'L', 'l', 'L', 'l', 'L', 'l', 'L', # 0x38
+'l', 'L', 'l', 'N', 'n', 'N', 'n', 'N', # 0x40
+'n', "'n", 'ng', 'NG', 'O', 'o', 'O', 'o', # 0x48
+'O', 'o', 'OE', 'oe', 'R', 'r', 'R', 'r', # 0x50
+'R', 'r', 'S', 's', 'S', 's', 'S', 's', # 0x58
+'S', 's', 'T', 't', 'T', 't', 'T', 't', # 0x60
+'U', 'u', 'U', 'u', 'U', 'u', 'U', 'u', # 0x68
+'U', 'u', 'U', 'u', 'W', 'w', 'Y', 'y', # 0x70
+'Y', 'Z', 'z', 'Z', 'z', 'Z', 'z', 's', # 0x78
+'b', 'B', 'B', 'b', '6', '6', 'O', 'C', # 0x80
+'c', 'D', 'D', 'D', 'd', 'd', '3', '@', # 0x88
+'E', 'F', 'f', 'G', 'G', 'hv', 'I', 'I', # 0x90
+'K', 'k', 'l', 'l', 'W', 'N', 'n', 'O', # 0x98
+'O', 'o', 'OI', 'oi', 'P', 'p', 'YR', '2', # 0xA0
+'2', 'SH', 'sh', 't', 'T', 't', 'T', 'U', # 0xA8
+'u', 'Y', 'V', 'Y', 'y', 'Z', 'z', 'ZH', # 0xB0
+'ZH', 'zh', 'zh', '2', '5', '5', 'ts', 'w', # 0xB8
+'|', '||', '|=', '!', 'DZ', 'Dz', 'dz', 'LJ', # 0xC0
+'Lj', 'lj', 'NJ', 'Nj', 'nj', 'A', 'a', 'I', # 0xC8
+'i', 'O', 'o', 'U', 'u', 'U', 'u', 'U', # 0xD0
+'u', 'U', 'u', 'U', 'u', '@', 'A', 'a', # 0xD8
+'A', 'a', 'AE', 'ae', 'G', 'g', 'G', 'g', # 0xE0
+'K', 'k', 'O', 'o', 'O', 'o', 'ZH', 'zh', # 0xE8
+'j', 'DZ', 'Dz', 'dz', 'G', 'g', 'HV', 'W', # 0xF0
+'N', 'n', 'A', 'a', 'AE', 'ae', 'O', 'o', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x02] = [
+'A', 'a', 'A', 'a', 'E', 'e', 'E', 'e', # 0x00
+'I', 'i', 'I', 'i', 'O', 'o', 'O', 'o', # 0x08
+'R', 'r', 'R', 'r', 'U', 'u', 'U', 'u', # 0x10
+'S', 's', 'T', 't', 'Y', 'y', 'H', 'h', # 0x18
+'N', 'd', 'OU', 'ou', 'Z', 'z', 'A', 'a', # 0x20
+'E', 'e', 'O', 'o', 'O', 'o', 'O', 'o', # 0x28
+'O', 'o', 'Y', 'y', 'l', 'n', 't', 'j', # 0x30
+'db', 'qp', 'A', 'C', 'c', 'L', 'T', 's', # 0x38
+'z', '[?]', '[?]', 'B', 'U', '^', 'E', 'e', # 0x40
+'J', 'j', 'q', 'q', 'R', 'r', 'Y', 'y', # 0x48
+'a', 'a', 'a', 'b', 'o', 'c', 'd', 'd', # 0x50
+'e', '@', '@', 'e', 'e', 'e', 'e', 'j', # 0x58
+'g', 'g', 'g', 'g', 'u', 'Y', 'h', 'h', # 0x60
+'i', 'i', 'I', 'l', 'l', 'l', 'lZ', 'W', # 0x68
+'W', 'm', 'n', 'n', 'n', 'o', 'OE', 'O', # 0x70
+'F', 'r', 'r', 'r', 'r', 'r', 'r', 'r', # 0x78
+'R', 'R', 's', 'S', 'j', 'S', 'S', 't', # 0x80
+'t', 'u', 'U', 'v', '^', 'w', 'y', 'Y', # 0x88
+'z', 'z', 'Z', 'Z', '?', '?', '?', 'C', # 0x90
+'@', 'B', 'E', 'G', 'H', 'j', 'k', 'L', # 0x98
+'q', '?', '?', 'dz', 'dZ', 'dz', 'ts', 'tS', # 0xA0
+'tC', 'fN', 'ls', 'lz', 'WW', ']]', 'h', 'h', # 0xA8
+'h', 'h', 'j', 'r', 'r', 'r', 'r', 'w', # 0xB0
+'y', "'", '"', '`', "'", '`', '`', "'", # 0xB8
+'?', '?', '<', '>', '^', 'V', '^', 'V', # 0xC0
+"'", '-', '/', "\\", ',', '_', "\\", '/', # 0xC8
+':', '.', '`', "'", '^', 'V', '+', '-', # 0xD0
+'V', '.', '@', ',', '~', '"', 'R', 'X', # 0xD8
+'G', 'l', 's', 'x', '?', '5', '4', '3', # 0xE0
+'2', '1', '/', "\\", 'V', '=', '"', 'V', # 0xE8
+'^', '<', '>', 'o', '`', '``', '//', '~', # 0xF0
+':', '[-', '-]', '[_', '_]', '_', '_', '<', # 0xF8
+];
+1;
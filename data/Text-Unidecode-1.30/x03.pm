@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x03] = [
+'', '', '', '', '', '', '', '', # 0x00
+'', '', '', '', '', '', '', '', # 0x08
+'', '', '', '', '', '', '', '', # 0x10
+'', '', '', '', '', '', '', '', # 0x18
+'', '', '', '', '', '', '', '', # 0x20
+'', '', '', '', '', '', '', '', # 0x28
+'', '', '', '', '', '', '', '', # 0x30
+'', '', '', '', '', '', '', '', # 0x38
+'', '', '', '', '', '', '', '', # 0x40
+'', '', '', '', '', '', '', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'', '', '', 'a', 'e', 'i', 'o', 'u', # 0x60
+'c', 'd', 'h', 'm', 'r', 't', 'v', 'x', # 0x68
+'[?]', '[?]', '[?]', '[?]', "'", ',', '[?]', '[?]', # 0x70
+'[?]', '[?]', '', '[?]', '[?]', '[?]', '?', '[?]', # 0x78
+'[?]', '[?]', '[?]', '[?]', '', '', 'A', ';', # 0x80
+'E', 'E', 'I', '[?]', 'O', '[?]', 'U', 'O', # 0x88
+'I', 'A', 'B', 'G', 'D', 'E', 'Z', 'E', # 0x90
+'Th', 'I', 'K', 'L', 'M', 'N', 'Ks', 'O', # 0x98
+'P', 'R', '[?]', 'S', 'T', 'U', 'Ph', 'Kh', # 0xA0
+'Ps', 'O', 'I', 'U', 'a', 'e', 'e', 'i', # 0xA8
+'u', 'a', 'b', 'g', 'd', 'e', 'z', 'e', # 0xB0
+'th', 'i', 'k', 'l', 'm', 'n', 'x', 'o', # 0xB8
+'p', 'r', 's', 's', 't', 'u', 'ph', 'kh', # 0xC0
+'ps', 'o', 'i', 'u', 'o', 'u', 'o', '[?]', # 0xC8
+'b', 'th', 'U', 'U', 'U', 'ph', 'p', '&', # 0xD0
+'[?]', '[?]', 'St', 'st', 'W', 'w', 'Q', 'q', # 0xD8
+'Sp', 'sp', 'Sh', 'sh', 'F', 'f', 'Kh', 'kh', # 0xE0
+'H', 'h', 'G', 'g', 'CH', 'ch', 'Ti', 'ti', # 0xE8
+'k', 'r', 'c', 'j', 'TH', 'e', 'e', 'Sh', # 0xF0
+'sh', 's', '[?]', '[?]', 'r/', 'S', 'S.', 'S.', # 0xF8
+];
+1;
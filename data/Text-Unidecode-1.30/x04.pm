@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x04] = [
+'Ie', 'Io', 'Dj', 'Gj', 'E', 'Dz', 'I', 'Yi', # 0x00
+'J', 'Lj', 'Nj', 'Tsh', 'Kj', 'I', 'U', 'Dzh', # 0x08
+'A', 'B', 'V', 'G', 'D', 'E', 'Zh', 'Z', # 0x10
+'I', 'I', 'K', 'L', 'M', 'N', 'O', 'P', # 0x18
+'R', 'S', 'T', 'U', 'F', 'Kh', 'Ts', 'Ch', # 0x20
+'Sh', 'Shch', '', 'Y', "'", 'E', 'Iu', 'Ia', # 0x28
+'a', 'b', 'v', 'g', 'd', 'e', 'zh', 'z', # 0x30
+'i', 'i', 'k', 'l', 'm', 'n', 'o', 'p', # 0x38
+'r', 's', 't', 'u', 'f', 'kh', 'ts', 'ch', # 0x40
+'sh', 'shch', '', 'y', "'", 'e', 'iu', 'ia', # 0x48
+'ie', 'io', 'dj', 'gj', 'ie', 'dz', 'i', 'yi', # 0x50
+'j', 'lj', 'nj', 'tsh', 'kj', 'i', 'u', 'dzh', # 0x58
+'O', 'o', 'E', 'e', 'Ie', 'ie', 'E', 'e', # 0x60
+'Ie', 'ie', 'O', 'o', 'Io', 'io', 'Ks', 'ks', # 0x68
+'Ps', 'ps', 'F', 'f', 'Y', 'y', 'Y', 'y', # 0x70
+'u', 'u', 'O', 'o', 'O', 'o', 'Ot', 'ot', # 0x78
+'Q', 'q', '*1000*', '', '', '', '', '[?]', # 0x80
+'*100.000*', '*1.000.000*', '[?]', '[?]', '"', '"', "R'", "r'", # 0x88
+"G'", "g'", "G'", "g'", "G'", "g'", "Zh'", "zh'", # 0x90
+"Z'", "z'", "K'", "k'", "K'", "k'", "K'", "k'", # 0x98
+"K'", "k'", "N'", "n'", 'Ng', 'ng', "P'", "p'", # 0xA0
+'Kh', 'kh', "S'", "s'", "T'", "t'", 'U', 'u', # 0xA8
+"U'", "u'", "Kh'", "kh'", 'Tts', 'tts', "Ch'", "ch'", # 0xB0
+"Ch'", "ch'", 'H', 'h', 'Ch', 'ch', "Ch'", "ch'", # 0xB8
+'`', 'Zh', 'zh', "K'", "k'", '[?]', '[?]', "N'", # 0xC0
+"n'", '[?]', '[?]', 'Ch', 'ch', '[?]', '[?]', '[?]', # 0xC8
+'a', 'a', 'A', 'a', 'Ae', 'ae', 'Ie', 'ie', # 0xD0
+'@', '@', '@', '@', 'Zh', 'zh', 'Z', 'z', # 0xD8
+'Dz', 'dz', 'I', 'i', 'I', 'i', 'O', 'o', # 0xE0
+'O', 'o', 'O', 'o', 'E', 'e', 'U', 'u', # 0xE8
+'U', 'u', 'U', 'u', 'Ch', 'ch', '[?]', '[?]', # 0xF0
+'Y', 'y', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x05] = [
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x00
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x08
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x10
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x18
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x20
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x28
+'[?]', 'A', 'B', 'G', 'D', 'E', 'Z', 'E', # 0x30
+'E', 'T`', 'Zh', 'I', 'L', 'Kh', 'Ts', 'K', # 0x38
+'H', 'Dz', 'Gh', 'Ch', 'M', 'Y', 'N', 'Sh', # 0x40
+'O', 'Ch`', 'P', 'J', 'Rh', 'S', 'V', 'T', # 0x48
+'R', 'Ts`', 'W', 'P`', 'K`', 'O', 'F', '[?]', # 0x50
+'[?]', '<', "'", '/', '!', ',', '?', '.', # 0x58
+'[?]', 'a', 'b', 'g', 'd', 'e', 'z', 'e', # 0x60
+'e', 't`', 'zh', 'i', 'l', 'kh', 'ts', 'k', # 0x68
+'h', 'dz', 'gh', 'ch', 'm', 'y', 'n', 'sh', # 0x70
+'o', 'ch`', 'p', 'j', 'rh', 's', 'v', 't', # 0x78
+'r', 'ts`', 'w', 'p`', 'k`', 'o', 'f', 'ew', # 0x80
+'[?]', '.', '-', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x88
+'[?]', '', '', '', '', '', '', '', # 0x90
+'', '', '', '', '', '', '', '', # 0x98
+'', '', '[?]', '', '', '', '', '', # 0xA0
+'', '', '', '', '', '', '', '', # 0xA8
+'@', 'e', 'a', 'o', 'i', 'e', 'e', 'a', # 0xB0
+'a', 'o', '[?]', 'u', "'", '', '', '', # 0xB8
+'|', '', '', ':', '', '[?]', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'', 'b', 'g', 'd', 'h', 'v', 'z', 'kh', # 0xD0
+'t', 'y', 'k', 'k', 'l', 'm', 'm', 'n', # 0xD8
+'n', 's', '`', 'p', 'p', 'ts', 'ts', 'q', # 0xE0
+'r', 'sh', 't', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'V', 'oy', 'i', "'", '"', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x06] = [
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x00
+'[?]', '[?]', '[?]', '[?]', ',', '[?]', '[?]', '[?]', # 0x08
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x10
+'[?]', '[?]', '[?]', ';', '[?]', '[?]', '[?]', '?', # 0x18
+'[?]', '', 'a', "'", "w'", '', "y'", '', # 0x20
+'b', '@', 't', 'th', 'j', 'H', 'kh', 'd', # 0x28
+'dh', 'r', 'z', 's', 'sh', 'S', 'D', 'T', # 0x30
+'Z', '`', 'G', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x38
+'', 'f', 'q', 'k', 'l', 'm', 'n', 'h', # 0x40
+'w', '~', 'y', 'an', 'un', 'in', 'a', 'u', # 0x48
+'i', 'W', '', '', "'", "'", '[?]', '[?]', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x60
+'8', '9', '%', '.', ',', '*', '[?]', '[?]', # 0x68
+'', "'", "'", "'", '', "'", "'w", "'u", # 0x70
+"'y", 'tt', 'tth', 'b', 't', 'T', 'p', 'th', # 0x78
+'bh', "'h", 'H', 'ny', 'dy', 'H', 'ch', 'cch', # 0x80
+'dd', 'D', 'D', 'Dt', 'dh', 'ddh', 'd', 'D', # 0x88
+'D', 'rr', 'R', 'R', 'R', 'R', 'R', 'R', # 0x90
+'j', 'R', 'S', 'S', 'S', 'S', 'S', 'T', # 0x98
+'GH', 'F', 'F', 'F', 'v', 'f', 'ph', 'Q', # 0xA0
+'Q', 'kh', 'k', 'K', 'K', 'ng', 'K', 'g', # 0xA8
+'G', 'N', 'G', 'G', 'G', 'L', 'L', 'L', # 0xB0
+'L', 'N', 'N', 'N', 'N', 'N', 'h', 'Ch', # 0xB8
+'hy', 'h', 'H', '@', 'W', 'oe', 'oe', 'u', # 0xC0
+'yu', 'yu', 'W', 'v', 'y', 'Y', 'Y', 'W', # 0xC8
+'', '', 'y', "y'", '.', 'ae', '', '', # 0xD0
+'', '', '', '', '', '@', '#', '', # 0xD8
+'', '', '', '', '', '', '', '', # 0xE0
+'', '^', '', '', '', '', '[?]', '[?]', # 0xE8
+'0', '1', '2', '3', '4', '5', '6', '7', # 0xF0
+'8', '9', 'Sh', 'D', 'Gh', '&', '+m', 'h', # 0xF8
+];
+1;
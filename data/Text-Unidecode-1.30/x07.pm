@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x07] = [
+'//', '/', ',', '!', '!', '-', ',', ',', # 0x00
+';', '?', '~', '{', '}', '*', '[?]', '', # 0x08
+"'", '', 'b', 'g', 'g', 'd', 'd', 'h', # 0x10
+'w', 'z', 'H', 't', 't', 'y', 'yh', 'k', # 0x18
+'l', 'm', 'n', 's', 's', '`', 'p', 'p', # 0x20
+'S', 'q', 'r', 'sh', 't', '[?]', '[?]', '[?]', # 0x28
+'a', 'a', 'a', 'A', 'A', 'A', 'e', 'e', # 0x30
+'e', 'E', 'i', 'i', 'u', 'u', 'u', 'o', # 0x38
+'', '`', "'", '', '', 'X', 'Q', '@', # 0x40
+'@', '|', '+', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x60
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x68
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'h', 'sh', 'n', 'r', 'b', 'L', 'k', "'", # 0x80
+'v', 'm', 'f', 'dh', 'th', 'l', 'g', 'ny', # 0x88
+'s', 'd', 'z', 't', 'y', 'p', 'j', 'ch', # 0x90
+'tt', 'hh', 'kh', 'th', 'z', 'sh', 's', 'd', # 0x98
+'t', 'z', '`', 'gh', 'q', 'w', 'a', 'aa', # 0xA0
+'i', 'ee', 'u', 'oo', 'e', 'ey', 'o', 'oa', # 0xA8
+'', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
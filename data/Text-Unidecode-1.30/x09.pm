@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x09] = [
+'[?]', 'N', 'N', 'H', '[?]', 'a', 'aa', 'i', # 0x00
+'ii', 'u', 'uu', 'R', 'L', 'eN', 'e', 'e', # 0x08
+'ai', 'oN', 'o', 'o', 'au', 'k', 'kh', 'g', # 0x10
+'gh', 'ng', 'c', 'ch', 'j', 'jh', 'ny', 'tt', # 0x18
+'tth', 'dd', 'ddh', 'nn', 't', 'th', 'd', 'dh', # 0x20
+'n', 'nnn', 'p', 'ph', 'b', 'bh', 'm', 'y', # 0x28
+'r', 'rr', 'l', 'l', 'lll', 'v', 'sh', 'ss', # 0x30
+'s', 'h', '[?]', '[?]', "'", "'", 'aa', 'i', # 0x38
+'ii', 'u', 'uu', 'R', 'RR', 'eN', 'e', 'e', # 0x40
+'ai', 'oN', 'o', 'o', 'au', '', '[?]', '[?]', # 0x48
+'AUM', "'", "'", '`', "'", '[?]', '[?]', '[?]', # 0x50
+'q', 'khh', 'ghh', 'z', 'dddh', 'rh', 'f', 'yy', # 0x58
+'RR', 'LL', 'L', 'LL', ' / ', ' // ', '0', '1', # 0x60
+'2', '3', '4', '5', '6', '7', '8', '9', # 0x68
+'.', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'[?]', 'N', 'N', 'H', '[?]', 'a', 'aa', 'i', # 0x80
+'ii', 'u', 'uu', 'R', 'RR', '[?]', '[?]', 'e', # 0x88
+'ai', '[?]', '[?]', 'o', 'au', 'k', 'kh', 'g', # 0x90
+'gh', 'ng', 'c', 'ch', 'j', 'jh', 'ny', 'tt', # 0x98
+'tth', 'dd', 'ddh', 'nn', 't', 'th', 'd', 'dh', # 0xA0
+'n', '[?]', 'p', 'ph', 'b', 'bh', 'm', 'y', # 0xA8
+'r', '[?]', 'l', '[?]', '[?]', '[?]', 'sh', 'ss', # 0xB0
+'s', 'h', '[?]', '[?]', "'", '[?]', 'aa', 'i', # 0xB8
+'ii', 'u', 'uu', 'R', 'RR', '[?]', '[?]', 'e', # 0xC0
+'ai', '[?]', '[?]', 'o', 'au', '', '[?]', '[?]', # 0xC8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '+', # 0xD0
+'[?]', '[?]', '[?]', '[?]', 'rr', 'rh', '[?]', 'yy', # 0xD8
+'RR', 'LL', 'L', 'LL', '[?]', '[?]', '0', '1', # 0xE0
+'2', '3', '4', '5', '6', '7', '8', '9', # 0xE8
+"r'", 'r`', 'Rs', 'Rs', '1/', '2/', '3/', '4/', # 0xF0
+' 1 - 1/', '/16', '', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x0a] = [
+'[?]', '[?]', 'N', '[?]', '[?]', 'a', 'aa', 'i', # 0x00
+'ii', 'u', 'uu', '[?]', '[?]', '[?]', '[?]', 'ee', # 0x08
+'ai', '[?]', '[?]', 'oo', 'au', 'k', 'kh', 'g', # 0x10
+'gh', 'ng', 'c', 'ch', 'j', 'jh', 'ny', 'tt', # 0x18
+'tth', 'dd', 'ddh', 'nn', 't', 'th', 'd', 'dh', # 0x20
+'n', '[?]', 'p', 'ph', 'b', 'bb', 'm', 'y', # 0x28
+'r', '[?]', 'l', 'll', '[?]', 'v', 'sh', '[?]', # 0x30
+'s', 'h', '[?]', '[?]', "'", '[?]', 'aa', 'i', # 0x38
+'ii', 'u', 'uu', '[?]', '[?]', '[?]', '[?]', 'ee', # 0x40
+'ai', '[?]', '[?]', 'oo', 'au', '', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x50
+'[?]', 'khh', 'ghh', 'z', 'rr', '[?]', 'f', '[?]', # 0x58
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '0', '1', # 0x60
+'2', '3', '4', '5', '6', '7', '8', '9', # 0x68
+'N', 'H', '', '', 'G.E.O.', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'[?]', 'N', 'N', 'H', '[?]', 'a', 'aa', 'i', # 0x80
+'ii', 'u', 'uu', 'R', '[?]', 'eN', '[?]', 'e', # 0x88
+'ai', 'oN', '[?]', 'o', 'au', 'k', 'kh', 'g', # 0x90
+'gh', 'ng', 'c', 'ch', 'j', 'jh', 'ny', 'tt', # 0x98
+'tth', 'dd', 'ddh', 'nn', 't', 'th', 'd', 'dh', # 0xA0
+'n', '[?]', 'p', 'ph', 'b', 'bh', 'm', 'ya', # 0xA8
+'r', '[?]', 'l', 'll', '[?]', 'v', 'sh', 'ss', # 0xB0
+'s', 'h', '[?]', '[?]', "'", "'", 'aa', 'i', # 0xB8
+'ii', 'u', 'uu', 'R', 'RR', 'eN', '[?]', 'e', # 0xC0
+'ai', 'oN', '[?]', 'o', 'au', '', '[?]', '[?]', # 0xC8
+'AUM', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD8
+'RR', '[?]', '[?]', '[?]', '[?]', '[?]', '0', '1', # 0xE0
+'2', '3', '4', '5', '6', '7', '8', '9', # 0xE8
+'.', 'R', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', 'zh', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x0d] = [
+'[?]', '[?]', 'N', 'H', '[?]', 'a', 'aa', 'i', # 0x00
+'ii', 'u', 'uu', 'R', 'L', '[?]', 'e', 'ee', # 0x08
+'ai', '[?]', 'o', 'oo', 'au', 'k', 'kh', 'g', # 0x10
+'gh', 'ng', 'c', 'ch', 'j', 'jh', 'ny', 'tt', # 0x18
+'tth', 'dd', 'ddh', 'nn', 't', 'th', 'd', 'dh', # 0x20
+'n', '[?]', 'p', 'ph', 'b', 'bh', 'm', 'y', # 0x28
+'r', 'rr', 'l', 'll', 'lll', 'v', 'sh', 'ss', # 0x30
+'s', 'h', '[?]', '[?]', '[?]', '[?]', 'aa', 'i', # 0x38
+'ii', 'u', 'uu', 'R', '[?]', '[?]', 'e', 'ee', # 0x40
+'ai', '', 'o', 'oo', 'au', '', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '+', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'RR', 'LL', '[?]', '[?]', '[?]', '[?]', '0', '1', # 0x60
+'2', '3', '4', '5', '6', '7', '8', '9', # 0x68
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'[?]', '[?]', 'N', 'H', '[?]', 'a', 'aa', 'ae', # 0x80
+'aae', 'i', 'ii', 'u', 'uu', 'R', 'RR', 'L', # 0x88
+'LL', 'e', 'ee', 'ai', 'o', 'oo', 'au', '[?]', # 0x90
+'[?]', '[?]', 'k', 'kh', 'g', 'gh', 'ng', 'nng', # 0x98
+'c', 'ch', 'j', 'jh', 'ny', 'jny', 'nyj', 'tt', # 0xA0
+'tth', 'dd', 'ddh', 'nn', 'nndd', 't', 'th', 'd', # 0xA8
+'dh', 'n', '[?]', 'nd', 'p', 'ph', 'b', 'bh', # 0xB0
+'m', 'mb', 'y', 'r', '[?]', 'l', '[?]', '[?]', # 0xB8
+'v', 'sh', 'ss', 's', 'h', 'll', 'f', '[?]', # 0xC0
+'[?]', '[?]', '', '[?]', '[?]', '[?]', '[?]', 'aa', # 0xC8
+'ae', 'aae', 'i', 'ii', 'u', '[?]', 'uu', '[?]', # 0xD0
+'R', 'e', 'ee', 'ai', 'o', 'oo', 'au', 'L', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', 'RR', 'LL', ' . ', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x0e] = [
+'[?]', 'k', 'kh', 'kh', 'kh', 'kh', 'kh', 'ng', # 0x00
+'cch', 'ch', 'ch', 'ch', 'ch', 'y', 'd', 't', # 0x08
+'th', 'th', 'th', 'n', 'd', 't', 'th', 'th', # 0x10
+'th', 'n', 'b', 'p', 'ph', 'f', 'ph', 'f', # 0x18
+'ph', 'm', 'y', 'r', 'R', 'l', 'L', 'w', # 0x20
+'s', 's', 's', 'h', 'l', '`', 'h', '~', # 0x28
+'a', 'a', 'aa', 'am', 'i', 'ii', 'ue', 'uue', # 0x30
+'u', 'uu', "'", '[?]', '[?]', '[?]', '[?]', 'Bh.', # 0x38
+'e', 'ae', 'o', 'ai', 'ai', 'ao', '+', '', # 0x40
+'', '', '', '', '', 'M', '', ' * ', # 0x48
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x50
+'8', '9', ' // ', ' /// ', '[?]', '[?]', '[?]', '[?]', # 0x58
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x60
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x68
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'[?]', 'k', 'kh', '[?]', 'kh', '[?]', '[?]', 'ng', # 0x80
+'ch', '[?]', 's', '[?]', '[?]', 'ny', '[?]', '[?]', # 0x88
+'[?]', '[?]', '[?]', '[?]', 'd', 'h', 'th', 'th', # 0x90
+'[?]', 'n', 'b', 'p', 'ph', 'f', 'ph', 'f', # 0x98
+'[?]', 'm', 'y', 'r', '[?]', 'l', '[?]', 'w', # 0xA0
+'[?]', '[?]', 's', 'h', '[?]', '`', '', '~', # 0xA8
+'a', '', 'aa', 'am', 'i', 'ii', 'y', 'yy', # 0xB0
+'u', 'uu', '[?]', 'o', 'l', 'ny', '[?]', '[?]', # 0xB8
+'e', 'ei', 'o', 'ay', 'ai', '[?]', '+', '[?]', # 0xC0
+'', '', '', '', '', 'M', '[?]', '[?]', # 0xC8
+'0', '1', '2', '3', '4', '5', '6', '7', # 0xD0
+'8', '9', '[?]', '[?]', 'hn', 'hm', '[?]', '[?]', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x0f] = [
+'AUM', '', '', '', '', '', '', '', # 0x00
+' // ', ' * ', '', '-', ' / ', ' / ', ' // ', ' -/ ', # 0x08
+' +/ ', ' X/ ', ' /XX/ ', ' /X/ ', ', ', '', '', '', # 0x10
+'', '', '', '', '', '', '', '', # 0x18
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x20
+'8', '9', '.5', '1.5', '2.5', '3.5', '4.5', '5.5', # 0x28
+'6.5', '7.5', '8.5', '-.5', '+', '*', '^', '_', # 0x30
+'', '~', '[?]', ']', '[[', ']]', '', '', # 0x38
+'k', 'kh', 'g', 'gh', 'ng', 'c', 'ch', 'j', # 0x40
+'[?]', 'ny', 'tt', 'tth', 'dd', 'ddh', 'nn', 't', # 0x48
+'th', 'd', 'dh', 'n', 'p', 'ph', 'b', 'bh', # 0x50
+'m', 'ts', 'tsh', 'dz', 'dzh', 'w', 'zh', 'z', # 0x58
+"'", 'y', 'r', 'l', 'sh', 'ssh', 's', 'h', # 0x60
+'a', 'kss', 'r', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x68
+'[?]', 'aa', 'i', 'ii', 'u', 'uu', 'R', 'RR', # 0x70
+'L', 'LL', 'e', 'ee', 'o', 'oo', 'M', 'H', # 0x78
+'i', 'ii', '', '', '', '', '', '', # 0x80
+'', '', '', '', '[?]', '[?]', '[?]', '[?]', # 0x88
+'k', 'kh', 'g', 'gh', 'ng', 'c', 'ch', 'j', # 0x90
+'[?]', 'ny', 'tt', 'tth', 'dd', 'ddh', 'nn', 't', # 0x98
+'th', 'd', 'dh', 'n', 'p', 'ph', 'b', 'bh', # 0xA0
+'m', 'ts', 'tsh', 'dz', 'dzh', 'w', 'zh', 'z', # 0xA8
+"'", 'y', 'r', 'l', 'sh', 'ss', 's', 'h', # 0xB0
+'a', 'kss', 'w', 'y', 'r', '[?]', 'X', ' :X: ', # 0xB8
+' /O/ ', ' /o/ ', " \\o\\ ", ' (O) ', '', '', '', '', # 0xC0
+'', '', '', '', '', '[?]', '[?]', '', # 0xC8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
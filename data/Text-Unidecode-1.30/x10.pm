@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x10] = [
+'k', 'kh', 'g', 'gh', 'ng', 'c', 'ch', 'j', # 0x00
+'jh', 'ny', 'nny', 'tt', 'tth', 'dd', 'ddh', 'nn', # 0x08
+'tt', 'th', 'd', 'dh', 'n', 'p', 'ph', 'b', # 0x10
+'bh', 'm', 'y', 'r', 'l', 'w', 's', 'h', # 0x18
+'ll', 'a', '[?]', 'i', 'ii', 'u', 'uu', 'e', # 0x20
+'[?]', 'o', 'au', '[?]', 'aa', 'i', 'ii', 'u', # 0x28
+'uu', 'e', 'ai', '[?]', '[?]', '[?]', 'N', "'", # 0x30
+':', '', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x38
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x40
+'8', '9', ' / ', ' // ', 'n*', 'r*', 'l*', 'e*', # 0x48
+'sh', 'ss', 'R', 'RR', 'L', 'LL', 'R', 'RR', # 0x50
+'L', 'LL', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x60
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x68
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x80
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x88
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x90
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x98
+'A', 'B', 'G', 'D', 'E', 'V', 'Z', 'T`', # 0xA0
+'I', 'K', 'L', 'M', 'N', 'O', 'P', 'Zh', # 0xA8
+'R', 'S', 'T', 'U', 'P`', 'K`', "G'", 'Q', # 0xB0
+'Sh', 'Ch`', 'C`', "Z'", 'C', 'Ch', 'X', 'J', # 0xB8
+'H', 'E', 'Y', 'W', 'Xh', 'OE', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'a', 'b', 'g', 'd', 'e', 'v', 'z', 't`', # 0xD0
+'i', 'k', 'l', 'm', 'n', 'o', 'p', 'zh', # 0xD8
+'r', 's', 't', 'u', 'p`', 'k`', "g'", 'q', # 0xE0
+'sh', 'ch`', 'c`', "z'", 'c', 'ch', 'x', 'j', # 0xE8
+'h', 'e', 'y', 'w', 'xh', 'oe', 'f', '[?]', # 0xF0
+'[?]', '[?]', '[?]', ' // ', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
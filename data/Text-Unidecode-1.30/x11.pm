@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x11] = [
+'g', 'gg', 'n', 'd', 'dd', 'r', 'm', 'b', # 0x00
+'bb', 's', 'ss', '', 'j', 'jj', 'c', 'k', # 0x08
+'t', 'p', 'h', 'ng', 'nn', 'nd', 'nb', 'dg', # 0x10
+'rn', 'rr', 'rh', 'rN', 'mb', 'mN', 'bg', 'bn', # 0x18
+'', 'bs', 'bsg', 'bst', 'bsb', 'bss', 'bsj', 'bj', # 0x20
+'bc', 'bt', 'bp', 'bN', 'bbN', 'sg', 'sn', 'sd', # 0x28
+'sr', 'sm', 'sb', 'sbg', 'sss', 's', 'sj', 'sc', # 0x30
+'sk', 'st', 'sp', 'sh', '', '', '', '', # 0x38
+'Z', 'g', 'd', 'm', 'b', 's', 'Z', '', # 0x40
+'j', 'c', 't', 'p', 'N', 'j', '', '', # 0x48
+'', '', 'ck', 'ch', '', '', 'pb', 'pN', # 0x50
+'hh', 'Q', '[?]', '[?]', '[?]', '[?]', '[?]', '', # 0x58
+'', 'a', 'ae', 'ya', 'yae', 'eo', 'e', 'yeo', # 0x60
+'ye', 'o', 'wa', 'wae', 'oe', 'yo', 'u', 'weo', # 0x68
+'we', 'wi', 'yu', 'eu', 'yi', 'i', 'a-o', 'a-u', # 0x70
+'ya-o', 'ya-yo', 'eo-o', 'eo-u', 'eo-eu', 'yeo-o', 'yeo-u', 'o-eo', # 0x78
+'o-e', 'o-ye', 'o-o', 'o-u', 'yo-ya', 'yo-yae', 'yo-yeo', 'yo-o', # 0x80
+'yo-i', 'u-a', 'u-ae', 'u-eo-eu', 'u-ye', 'u-u', 'yu-a', 'yu-eo', # 0x88
+'yu-e', 'yu-yeo', 'yu-ye', 'yu-u', 'yu-i', 'eu-u', 'eu-eu', 'yi-u', # 0x90
+'i-a', 'i-ya', 'i-o', 'i-u', 'i-eu', 'i-U', 'U', 'U-eo', # 0x98
+'U-u', 'U-i', 'UU', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xA0
+'g', 'gg', 'gs', 'n', 'nj', 'nh', 'd', 'l', # 0xA8
+'lg', 'lm', 'lb', 'ls', 'lt', 'lp', 'lh', 'm', # 0xB0
+'b', 'bs', 's', 'ss', 'ng', 'j', 'c', 'k', # 0xB8
+'t', 'p', 'h', 'gl', 'gsg', 'ng', 'nd', 'ns', # 0xC0
+'nZ', 'nt', 'dg', 'tl', 'lgs', 'ln', 'ld', 'lth', # 0xC8
+'ll', 'lmg', 'lms', 'lbs', 'lbh', 'rNp', 'lss', 'lZ', # 0xD0
+'lk', 'lQ', 'mg', 'ml', 'mb', 'ms', 'mss', 'mZ', # 0xD8
+'mc', 'mh', 'mN', 'bl', 'bp', 'ph', 'pN', 'sg', # 0xE0
+'sd', 'sl', 'sb', 'Z', 'g', 'ss', '', 'kh', # 0xE8
+'N', 'Ns', 'NZ', 'pb', 'pN', 'hn', 'hl', 'hm', # 0xF0
+'hb', 'Q', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x12] = [
+'ha', 'hu', 'hi', 'haa', 'hee', 'he', 'ho', '[?]', # 0x00
+'la', 'lu', 'li', 'laa', 'lee', 'le', 'lo', 'lwa', # 0x08
+'hha', 'hhu', 'hhi', 'hhaa', 'hhee', 'hhe', 'hho', 'hhwa', # 0x10
+'ma', 'mu', 'mi', 'maa', 'mee', 'me', 'mo', 'mwa', # 0x18
+'sza', 'szu', 'szi', 'szaa', 'szee', 'sze', 'szo', 'szwa', # 0x20
+'ra', 'ru', 'ri', 'raa', 'ree', 're', 'ro', 'rwa', # 0x28
+'sa', 'su', 'si', 'saa', 'see', 'se', 'so', 'swa', # 0x30
+'sha', 'shu', 'shi', 'shaa', 'shee', 'she', 'sho', 'shwa', # 0x38
+'qa', 'qu', 'qi', 'qaa', 'qee', 'qe', 'qo', '[?]', # 0x40
+'qwa', '[?]', 'qwi', 'qwaa', 'qwee', 'qwe', '[?]', '[?]', # 0x48
+'qha', 'qhu', 'qhi', 'qhaa', 'qhee', 'qhe', 'qho', '[?]', # 0x50
+'qhwa', '[?]', 'qhwi', 'qhwaa', 'qhwee', 'qhwe', '[?]', '[?]', # 0x58
+'ba', 'bu', 'bi', 'baa', 'bee', 'be', 'bo', 'bwa', # 0x60
+'va', 'vu', 'vi', 'vaa', 'vee', 've', 'vo', 'vwa', # 0x68
+'ta', 'tu', 'ti', 'taa', 'tee', 'te', 'to', 'twa', # 0x70
+'ca', 'cu', 'ci', 'caa', 'cee', 'ce', 'co', 'cwa', # 0x78
+'xa', 'xu', 'xi', 'xaa', 'xee', 'xe', 'xo', '[?]', # 0x80
+'xwa', '[?]', 'xwi', 'xwaa', 'xwee', 'xwe', '[?]', '[?]', # 0x88
+'na', 'nu', 'ni', 'naa', 'nee', 'ne', 'no', 'nwa', # 0x90
+'nya', 'nyu', 'nyi', 'nyaa', 'nyee', 'nye', 'nyo', 'nywa', # 0x98
+"'a", "'u", '[?]', "'aa", "'ee", "'e", "'o", "'wa", # 0xA0
+'ka', 'ku', 'ki', 'kaa', 'kee', 'ke', 'ko', '[?]', # 0xA8
+'kwa', '[?]', 'kwi', 'kwaa', 'kwee', 'kwe', '[?]', '[?]', # 0xB0
+'kxa', 'kxu', 'kxi', 'kxaa', 'kxee', 'kxe', 'kxo', '[?]', # 0xB8
+'kxwa', '[?]', 'kxwi', 'kxwaa', 'kxwee', 'kxwe', '[?]', '[?]', # 0xC0
+'wa', 'wu', 'wi', 'waa', 'wee', 'we', 'wo', '[?]', # 0xC8
+'`a', '`u', '`i', '`aa', '`ee', '`e', '`o', '[?]', # 0xD0
+'za', 'zu', 'zi', 'zaa', 'zee', 'ze', 'zo', 'zwa', # 0xD8
+'zha', 'zhu', 'zhi', 'zhaa', 'zhee', 'zhe', 'zho', 'zhwa', # 0xE0
+'ya', 'yu', 'yi', 'yaa', 'yee', 'ye', 'yo', '[?]', # 0xE8
+'da', 'du', 'di', 'daa', 'dee', 'de', 'do', 'dwa', # 0xF0
+'dda', 'ddu', 'ddi', 'ddaa', 'ddee', 'dde', 'ddo', 'ddwa', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x13] = [
+'ja', 'ju', 'ji', 'jaa', 'jee', 'je', 'jo', 'jwa', # 0x00
+'ga', 'gu', 'gi', 'gaa', 'gee', 'ge', 'go', '[?]', # 0x08
+'gwa', '[?]', 'gwi', 'gwaa', 'gwee', 'gwe', '[?]', '[?]', # 0x10
+'gga', 'ggu', 'ggi', 'ggaa', 'ggee', 'gge', 'ggo', '[?]', # 0x18
+'tha', 'thu', 'thi', 'thaa', 'thee', 'the', 'tho', 'thwa', # 0x20
+'cha', 'chu', 'chi', 'chaa', 'chee', 'che', 'cho', 'chwa', # 0x28
+'pha', 'phu', 'phi', 'phaa', 'phee', 'phe', 'pho', 'phwa', # 0x30
+'tsa', 'tsu', 'tsi', 'tsaa', 'tsee', 'tse', 'tso', 'tswa', # 0x38
+'tza', 'tzu', 'tzi', 'tzaa', 'tzee', 'tze', 'tzo', '[?]', # 0x40
+'fa', 'fu', 'fi', 'faa', 'fee', 'fe', 'fo', 'fwa', # 0x48
+'pa', 'pu', 'pi', 'paa', 'pee', 'pe', 'po', 'pwa', # 0x50
+'rya', 'mya', 'fya', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'[?]', ' ', '.', ',', ';', ':', ':: ', '?', # 0x60
+'//', '1', '2', '3', '4', '5', '6', '7', # 0x68
+'8', '9', '10+', '20+', '30+', '40+', '50+', '60+', # 0x70
+'70+', '80+', '90+', '100+', '10,000+', '[?]', '[?]', '[?]', # 0x78
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x80
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x88
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x90
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x98
+'a', 'e', 'i', 'o', 'u', 'v', 'ga', 'ka', # 0xA0
+'ge', 'gi', 'go', 'gu', 'gv', 'ha', 'he', 'hi', # 0xA8
+'ho', 'hu', 'hv', 'la', 'le', 'li', 'lo', 'lu', # 0xB0
+'lv', 'ma', 'me', 'mi', 'mo', 'mu', 'na', 'hna', # 0xB8
+'nah', 'ne', 'ni', 'no', 'nu', 'nv', 'qua', 'que', # 0xC0
+'qui', 'quo', 'quu', 'quv', 'sa', 's', 'se', 'si', # 0xC8
+'so', 'su', 'sv', 'da', 'ta', 'de', 'te', 'di', # 0xD0
+'ti', 'do', 'du', 'dv', 'dla', 'tla', 'tle', 'tli', # 0xD8
+'tlo', 'tlu', 'tlv', 'tsa', 'tse', 'tsi', 'tso', 'tsu', # 0xE0
+'tsv', 'wa', 'we', 'wi', 'wo', 'wu', 'wv', 'ya', # 0xE8
+'ye', 'yi', 'yo', 'yu', 'yv', 'MV', '[?]', '[?]', # 0xF0
+'ye', 'yi', 'yo', 'yu', 'yv', 'mv', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x14] = [
+'[?]', 'e', 'aai', 'i', 'ii', 'o', 'oo', 'oo', # 0x00
+'ee', 'i', 'a', 'aa', 'we', 'we', 'wi', 'wi', # 0x08
+'wii', 'wii', 'wo', 'wo', 'woo', 'woo', 'woo', 'wa', # 0x10
+'wa', 'waa', 'waa', 'waa', 'ai', 'w', "'", 't', # 0x18
+'k', 'sh', 's', 'n', 'w', 'n', '[?]', 'w', # 0x20
+'c', '?', 'l', 'en', 'in', 'on', 'an', 'pe', # 0x28
+'paai', 'pi', 'pii', 'po', 'poo', 'poo', 'hee', 'hi', # 0x30
+'pa', 'paa', 'pwe', 'pwe', 'pwi', 'pwi', 'pwii', 'pwii', # 0x38
+'pwo', 'pwo', 'pwoo', 'pwoo', 'pwa', 'pwa', 'pwaa', 'pwaa', # 0x40
+'pwaa', 'p', 'p', 'h', 'te', 'taai', 'ti', 'tii', # 0x48
+'to', 'too', 'too', 'dee', 'di', 'ta', 'taa', 'twe', # 0x50
+'twe', 'twi', 'twi', 'twii', 'twii', 'two', 'two', 'twoo', # 0x58
+'twoo', 'twa', 'twa', 'twaa', 'twaa', 'twaa', 't', 'tte', # 0x60
+'tti', 'tto', 'tta', 'ke', 'kaai', 'ki', 'kii', 'ko', # 0x68
+'koo', 'koo', 'ka', 'kaa', 'kwe', 'kwe', 'kwi', 'kwi', # 0x70
+'kwii', 'kwii', 'kwo', 'kwo', 'kwoo', 'kwoo', 'kwa', 'kwa', # 0x78
+'kwaa', 'kwaa', 'kwaa', 'k', 'kw', 'keh', 'kih', 'koh', # 0x80
+'kah', 'ce', 'caai', 'ci', 'cii', 'co', 'coo', 'coo', # 0x88
+'ca', 'caa', 'cwe', 'cwe', 'cwi', 'cwi', 'cwii', 'cwii', # 0x90
+'cwo', 'cwo', 'cwoo', 'cwoo', 'cwa', 'cwa', 'cwaa', 'cwaa', # 0x98
+'cwaa', 'c', 'th', 'me', 'maai', 'mi', 'mii', 'mo', # 0xA0
+'moo', 'moo', 'ma', 'maa', 'mwe', 'mwe', 'mwi', 'mwi', # 0xA8
+'mwii', 'mwii', 'mwo', 'mwo', 'mwoo', 'mwoo', 'mwa', 'mwa', # 0xB0
+'mwaa', 'mwaa', 'mwaa', 'm', 'm', 'mh', 'm', 'm', # 0xB8
+'ne', 'naai', 'ni', 'nii', 'no', 'noo', 'noo', 'na', # 0xC0
+'naa', 'nwe', 'nwe', 'nwa', 'nwa', 'nwaa', 'nwaa', 'nwaa', # 0xC8
+'n', 'ng', 'nh', 'le', 'laai', 'li', 'lii', 'lo', # 0xD0
+'loo', 'loo', 'la', 'laa', 'lwe', 'lwe', 'lwi', 'lwi', # 0xD8
+'lwii', 'lwii', 'lwo', 'lwo', 'lwoo', 'lwoo', 'lwa', 'lwa', # 0xE0
+'lwaa', 'lwaa', 'l', 'l', 'l', 'se', 'saai', 'si', # 0xE8
+'sii', 'so', 'soo', 'soo', 'sa', 'saa', 'swe', 'swe', # 0xF0
+'swi', 'swi', 'swii', 'swii', 'swo', 'swo', 'swoo', 'swoo', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x15] = [
+'swa', 'swa', 'swaa', 'swaa', 'swaa', 's', 's', 'sw', # 0x00
+'s', 'sk', 'skw', 'sW', 'spwa', 'stwa', 'skwa', 'scwa', # 0x08
+'she', 'shi', 'shii', 'sho', 'shoo', 'sha', 'shaa', 'shwe', # 0x10
+'shwe', 'shwi', 'shwi', 'shwii', 'shwii', 'shwo', 'shwo', 'shwoo', # 0x18
+'shwoo', 'shwa', 'shwa', 'shwaa', 'shwaa', 'sh', 'ye', 'yaai', # 0x20
+'yi', 'yii', 'yo', 'yoo', 'yoo', 'ya', 'yaa', 'ywe', # 0x28
+'ywe', 'ywi', 'ywi', 'ywii', 'ywii', 'ywo', 'ywo', 'ywoo', # 0x30
+'ywoo', 'ywa', 'ywa', 'ywaa', 'ywaa', 'ywaa', 'y', 'y', # 0x38
+'y', 'yi', 're', 're', 'le', 'raai', 'ri', 'rii', # 0x40
+'ro', 'roo', 'lo', 'ra', 'raa', 'la', 'rwaa', 'rwaa', # 0x48
+'r', 'r', 'r', 'fe', 'faai', 'fi', 'fii', 'fo', # 0x50
+'foo', 'fa', 'faa', 'fwaa', 'fwaa', 'f', 'the', 'the', # 0x58
+'thi', 'thi', 'thii', 'thii', 'tho', 'thoo', 'tha', 'thaa', # 0x60
+'thwaa', 'thwaa', 'th', 'tthe', 'tthi', 'ttho', 'ttha', 'tth', # 0x68
+'tye', 'tyi', 'tyo', 'tya', 'he', 'hi', 'hii', 'ho', # 0x70
+'hoo', 'ha', 'haa', 'h', 'h', 'hk', 'qaai', 'qi', # 0x78
+'qii', 'qo', 'qoo', 'qa', 'qaa', 'q', 'tlhe', 'tlhi', # 0x80
+'tlho', 'tlha', 're', 'ri', 'ro', 'ra', 'ngaai', 'ngi', # 0x88
+'ngii', 'ngo', 'ngoo', 'nga', 'ngaa', 'ng', 'nng', 'she', # 0x90
+'shi', 'sho', 'sha', 'the', 'thi', 'tho', 'tha', 'th', # 0x98
+'lhi', 'lhii', 'lho', 'lhoo', 'lha', 'lhaa', 'lh', 'the', # 0xA0
+'thi', 'thii', 'tho', 'thoo', 'tha', 'thaa', 'th', 'b', # 0xA8
+'e', 'i', 'o', 'a', 'we', 'wi', 'wo', 'wa', # 0xB0
+'ne', 'ni', 'no', 'na', 'ke', 'ki', 'ko', 'ka', # 0xB8
+'he', 'hi', 'ho', 'ha', 'ghu', 'gho', 'ghe', 'ghee', # 0xC0
+'ghi', 'gha', 'ru', 'ro', 're', 'ree', 'ri', 'ra', # 0xC8
+'wu', 'wo', 'we', 'wee', 'wi', 'wa', 'hwu', 'hwo', # 0xD0
+'hwe', 'hwee', 'hwi', 'hwa', 'thu', 'tho', 'the', 'thee', # 0xD8
+'thi', 'tha', 'ttu', 'tto', 'tte', 'ttee', 'tti', 'tta', # 0xE0
+'pu', 'po', 'pe', 'pee', 'pi', 'pa', 'p', 'gu', # 0xE8
+'go', 'ge', 'gee', 'gi', 'ga', 'khu', 'kho', 'khe', # 0xF0
+'khee', 'khi', 'kha', 'kku', 'kko', 'kke', 'kkee', 'kki', # 0xF8
+];
+1;
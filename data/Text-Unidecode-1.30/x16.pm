@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x16] = [
+'kka', 'kk', 'nu', 'no', 'ne', 'nee', 'ni', 'na', # 0x00
+'mu', 'mo', 'me', 'mee', 'mi', 'ma', 'yu', 'yo', # 0x08
+'ye', 'yee', 'yi', 'ya', 'ju', 'ju', 'jo', 'je', # 0x10
+'jee', 'ji', 'ji', 'ja', 'jju', 'jjo', 'jje', 'jjee', # 0x18
+'jji', 'jja', 'lu', 'lo', 'le', 'lee', 'li', 'la', # 0x20
+'dlu', 'dlo', 'dle', 'dlee', 'dli', 'dla', 'lhu', 'lho', # 0x28
+'lhe', 'lhee', 'lhi', 'lha', 'tlhu', 'tlho', 'tlhe', 'tlhee', # 0x30
+'tlhi', 'tlha', 'tlu', 'tlo', 'tle', 'tlee', 'tli', 'tla', # 0x38
+'zu', 'zo', 'ze', 'zee', 'zi', 'za', 'z', 'z', # 0x40
+'dzu', 'dzo', 'dze', 'dzee', 'dzi', 'dza', 'su', 'so', # 0x48
+'se', 'see', 'si', 'sa', 'shu', 'sho', 'she', 'shee', # 0x50
+'shi', 'sha', 'sh', 'tsu', 'tso', 'tse', 'tsee', 'tsi', # 0x58
+'tsa', 'chu', 'cho', 'che', 'chee', 'chi', 'cha', 'ttsu', # 0x60
+'ttso', 'ttse', 'ttsee', 'ttsi', 'ttsa', 'X', '.', 'qai', # 0x68
+'ngai', 'nngi', 'nngii', 'nngo', 'nngoo', 'nnga', 'nngaa', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+' ', 'b', 'l', 'f', 's', 'n', 'h', 'd', # 0x80
+'t', 'c', 'q', 'm', 'g', 'ng', 'z', 'r', # 0x88
+'a', 'o', 'u', 'e', 'i', 'ch', 'th', 'ph', # 0x90
+'p', 'x', 'p', '<', '>', '[?]', '[?]', '[?]', # 0x98
+'f', 'v', 'u', 'yr', 'y', 'w', 'th', 'th', # 0xA0
+'a', 'o', 'ac', 'ae', 'o', 'o', 'o', 'oe', # 0xA8
+'on', 'r', 'k', 'c', 'k', 'g', 'ng', 'g', # 0xB0
+'g', 'w', 'h', 'h', 'h', 'h', 'n', 'n', # 0xB8
+'n', 'i', 'e', 'j', 'g', 'ae', 'a', 'eo', # 0xC0
+'p', 'z', 's', 's', 's', 'c', 'z', 't', # 0xC8
+'t', 'd', 'b', 'b', 'p', 'p', 'e', 'm', # 0xD0
+'m', 'm', 'l', 'l', 'ng', 'ng', 'd', 'o', # 0xD8
+'ear', 'ior', 'qu', 'qu', 'qu', 's', 'yr', 'yr', # 0xE0
+'yr', 'q', 'x', '.', ':', '+', '17', '18', # 0xE8
+'19', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
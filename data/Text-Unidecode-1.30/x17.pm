@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x17] = [
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x00
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x08
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x10
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x18
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x20
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x28
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x30
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x38
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x40
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x60
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x68
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'k', 'kh', 'g', 'gh', 'ng', 'c', 'ch', 'j', # 0x80
+'jh', 'ny', 't', 'tth', 'd', 'ddh', 'nn', 't', # 0x88
+'th', 'd', 'dh', 'n', 'p', 'ph', 'b', 'bh', # 0x90
+'m', 'y', 'r', 'l', 'v', 'sh', 'ss', 's', # 0x98
+'h', 'l', 'q', 'a', 'aa', 'i', 'ii', 'u', # 0xA0
+'uk', 'uu', 'uuv', 'ry', 'ryy', 'ly', 'lyy', 'e', # 0xA8
+'ai', 'oo', 'oo', 'au', 'a', 'aa', 'aa', 'i', # 0xB0
+'ii', 'y', 'yy', 'u', 'uu', 'ua', 'oe', 'ya', # 0xB8
+'ie', 'e', 'ae', 'ai', 'oo', 'au', 'M', 'H', # 0xC0
+'a`', '', '', '', 'r', '', '!', '', # 0xC8
+'', '', '', '', '.', ' // ', ':', '+', # 0xD0
+'++', ' * ', ' /// ', 'KR', "'", '[?]', '[?]', '[?]', # 0xD8
+'0', '1', '2', '3', '4', '5', '6', '7', # 0xE0
+'8', '9', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
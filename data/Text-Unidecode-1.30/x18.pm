@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x18] = [
+' @ ', ' ... ', ', ', '. ', ': ', ' // ', '', '-', # 0x00
+', ', '. ', '', '', '', '', '', '[?]', # 0x08
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x10
+'8', '9', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x18
+'a', 'e', 'i', 'o', 'u', 'O', 'U', 'ee', # 0x20
+'n', 'ng', 'b', 'p', 'q', 'g', 'm', 'l', # 0x28
+'s', 'sh', 't', 'd', 'ch', 'j', 'y', 'r', # 0x30
+'w', 'f', 'k', 'kha', 'ts', 'z', 'h', 'zr', # 0x38
+'lh', 'zh', 'ch', '-', 'e', 'i', 'o', 'u', # 0x40
+'O', 'U', 'ng', 'b', 'p', 'q', 'g', 'm', # 0x48
+'t', 'd', 'ch', 'j', 'ts', 'y', 'w', 'k', # 0x50
+'g', 'h', 'jy', 'ny', 'dz', 'e', 'i', 'iy', # 0x58
+'U', 'u', 'ng', 'k', 'g', 'h', 'p', 'sh', # 0x60
+'t', 'd', 'j', 'f', 'g', 'h', 'ts', 'z', # 0x68
+'r', 'ch', 'zh', 'i', 'k', 'r', 'f', 'zh', # 0x70
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x78
+'[?]', 'H', 'X', 'W', 'M', ' 3 ', ' 333 ', 'a', # 0x80
+'i', 'k', 'ng', 'c', 'tt', 'tth', 'dd', 'nn', # 0x88
+'t', 'd', 'p', 'ph', 'ss', 'zh', 'z', 'a', # 0x90
+'t', 'zh', 'gh', 'ng', 'c', 'jh', 'tta', 'ddh', # 0x98
+'t', 'dh', 'ss', 'cy', 'zh', 'z', 'u', 'y', # 0xA0
+'bh', "'", '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xA8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
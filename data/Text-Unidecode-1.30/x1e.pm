@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x1e] = [
+'A', 'a', 'B', 'b', 'B', 'b', 'B', 'b', # 0x00
+'C', 'c', 'D', 'd', 'D', 'd', 'D', 'd', # 0x08
+'D', 'd', 'D', 'd', 'E', 'e', 'E', 'e', # 0x10
+'E', 'e', 'E', 'e', 'E', 'e', 'F', 'f', # 0x18
+'G', 'g', 'H', 'h', 'H', 'h', 'H', 'h', # 0x20
+'H', 'h', 'H', 'h', 'I', 'i', 'I', 'i', # 0x28
+'K', 'k', 'K', 'k', 'K', 'k', 'L', 'l', # 0x30
+'L', 'l', 'L', 'l', 'L', 'l', 'M', 'm', # 0x38
+'M', 'm', 'M', 'm', 'N', 'n', 'N', 'n', # 0x40
+'N', 'n', 'N', 'n', 'O', 'o', 'O', 'o', # 0x48
+'O', 'o', 'O', 'o', 'P', 'p', 'P', 'p', # 0x50
+'R', 'r', 'R', 'r', 'R', 'r', 'R', 'r', # 0x58
+'S', 's', 'S', 's', 'S', 's', 'S', 's', # 0x60
+'S', 's', 'T', 't', 'T', 't', 'T', 't', # 0x68
+'T', 't', 'U', 'u', 'U', 'u', 'U', 'u', # 0x70
+'U', 'u', 'U', 'u', 'V', 'v', 'V', 'v', # 0x78
+'W', 'w', 'W', 'w', 'W', 'w', 'W', 'w', # 0x80
+'W', 'w', 'X', 'x', 'X', 'x', 'Y', 'y', # 0x88
+'Z', 'z', 'Z', 'z', 'Z', 'z', 'h', 't', # 0x90
+'w', 'y', 'a', 's', 's', 's', 'Ss', 'd', # 0x98
+'A', 'a', 'A', 'a', 'A', 'a', 'A', 'a', # 0xA0
+'A', 'a', 'A', 'a', 'A', 'a', 'A', 'a', # 0xA8
+'A', 'a', 'A', 'a', 'A', 'a', 'A', 'a', # 0xB0
+'E', 'e', 'E', 'e', 'E', 'e', 'E', 'e', # 0xB8
+'E', 'e', 'E', 'e', 'E', 'e', 'E', 'e', # 0xC0
+'I', 'i', 'I', 'i', 'O', 'o', 'O', 'o', # 0xC8
+'O', 'o', 'O', 'o', 'O', 'o', 'O', 'o', # 0xD0
+'O', 'o', 'O', 'o', 'O', 'o', 'O', 'o', # 0xD8
+'O', 'o', 'O', 'o', 'U', 'u', 'U', 'u', # 0xE0
+'U', 'u', 'U', 'u', 'U', 'u', 'U', 'u', # 0xE8
+'U', 'u', 'Y', 'y', 'Y', 'y', 'Y', 'y', # 0xF0
+'Y', 'y', 'LL', 'll', 'V', 'v', 'Y', 'y', # 0xF8
+];
+1;
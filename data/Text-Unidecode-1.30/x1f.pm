@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x1f] = [
+'a', 'a', 'a', 'a', 'a', 'a', 'a', 'a', # 0x00
+'A', 'A', 'A', 'A', 'A', 'A', 'A', 'A', # 0x08
+'e', 'e', 'e', 'e', 'e', 'e', '[?]', '[?]', # 0x10
+'E', 'E', 'E', 'E', 'E', 'E', '[?]', '[?]', # 0x18
+'e', 'e', 'e', 'e', 'e', 'e', 'e', 'e', # 0x20
+'E', 'E', 'E', 'E', 'E', 'E', 'E', 'E', # 0x28
+'i', 'i', 'i', 'i', 'i', 'i', 'i', 'i', # 0x30
+'I', 'I', 'I', 'I', 'I', 'I', 'I', 'I', # 0x38
+'o', 'o', 'o', 'o', 'o', 'o', '[?]', '[?]', # 0x40
+'O', 'O', 'O', 'O', 'O', 'O', '[?]', '[?]', # 0x48
+'u', 'u', 'u', 'u', 'u', 'u', 'u', 'u', # 0x50
+'[?]', 'U', '[?]', 'U', '[?]', 'U', '[?]', 'U', # 0x58
+'o', 'o', 'o', 'o', 'o', 'o', 'o', 'o', # 0x60
+'O', 'O', 'O', 'O', 'O', 'O', 'O', 'O', # 0x68
+'a', 'a', 'e', 'e', 'e', 'e', 'i', 'i', # 0x70
+'o', 'o', 'u', 'u', 'o', 'o', '[?]', '[?]', # 0x78
+'a', 'a', 'a', 'a', 'a', 'a', 'a', 'a', # 0x80
+'A', 'A', 'A', 'A', 'A', 'A', 'A', 'A', # 0x88
+'e', 'e', 'e', 'e', 'e', 'e', 'e', 'e', # 0x90
+'E', 'E', 'E', 'E', 'E', 'E', 'E', 'E', # 0x98
+'o', 'o', 'o', 'o', 'o', 'o', 'o', 'o', # 0xA0
+'O', 'O', 'O', 'O', 'O', 'O', 'O', 'O', # 0xA8
+'a', 'a', 'a', 'a', 'a', '[?]', 'a', 'a', # 0xB0
+'A', 'A', 'A', 'A', 'A', "'", 'i', "'", # 0xB8
+'~', '"~', 'e', 'e', 'e', '[?]', 'e', 'e', # 0xC0
+'E', 'E', 'E', 'E', 'E', "'`", "''", "'~", # 0xC8
+'i', 'i', 'i', 'i', '[?]', '[?]', 'i', 'i', # 0xD0
+'I', 'I', 'I', 'I', '[?]', "`'", "`'", '`~', # 0xD8
+'u', 'u', 'u', 'u', 'R', 'R', 'u', 'u', # 0xE0
+'U', 'U', 'U', 'U', 'R', '"`', "\"'", '`', # 0xE8
+'[?]', '[?]', 'o', 'o', 'o', '[?]', 'o', 'o', # 0xF0
+'O', 'O', 'O', 'O', 'O', "'", '`', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x20] = [
+' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', # 0x00
+' ', ' ', ' ', ' ', '', '', '', '', # 0x08
+'-', '-', '-', '-', '--', '--', '||', '_', # 0x10
+"'", "'", ',', "'", '"', '"', ',,', '"', # 0x18
+'+', '++', '*', '*>', '.', '..', '...', '.', # 0x20
+"\x0A", "\x0A\x0A", '', '', '', '', '', ' ', # 0x28
+'%0', '%00', "'", "''", "'''", '`', '``', '```', # 0x30
+'^', '<', '>', '*', '!!', '!?', '-', '_', # 0x38
+'-', '^', '***', '--', '/', '-[', ']-', '[?]', # 0x40
+'?!', '!?', '7', 'PP', '(]', '[)', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x60
+'[?]', '[?]', '', '', '', '', '', '', # 0x68
+'0', '', '', '', '4', '5', '6', '7', # 0x70
+'8', '9', '+', '-', '=', '(', ')', 'n', # 0x78
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x80
+'8', '9', '+', '-', '=', '(', ')', '[?]', # 0x88
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x90
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x98
+'ECU', 'CL', 'Cr', 'FF', 'L', 'mil', 'N', 'Pts', # 0xA0
+'Rs', 'W', 'NS', 'D', 'EUR', 'K', 'T', 'Dr', # 0xA8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'', '', '', '', '', '', '', '', # 0xD0
+'', '', '', '', '', '', '', '', # 0xD8
+'', '', '', '', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x21] = [
+'', '', '', '', '', '', '', '', # 0x00
+'', '', '', '', '', '', '', '', # 0x08
+'', '', '', '', '', '', '', '', # 0x10
+'', '', '', '', '', '', '', '', # 0x18
+'', '', 'tm', '', '', '', '', '', # 0x20
+'', '', '', '', '', '', '', '', # 0x28
+'', '', '', '', '', '', '', '', # 0x30
+'', '', '', '', '', '', '', '', # 0x38
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x40
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', ' 1/3 ', ' 2/3 ', ' 1/5 ', ' 2/5 ', ' 3/5 ', # 0x50
+' 4/5 ', ' 1/6 ', ' 5/6 ', ' 1/8 ', ' 3/8 ', ' 5/8 ', ' 7/8 ', ' 1/', # 0x58
+'I', 'II', 'III', 'IV', 'V', 'VI', 'VII', 'VIII', # 0x60
+'IX', 'X', 'XI', 'XII', 'L', 'C', 'D', 'M', # 0x68
+'i', 'ii', 'iii', 'iv', 'v', 'vi', 'vii', 'viii', # 0x70
+'ix', 'x', 'xi', 'xii', 'l', 'c', 'd', 'm', # 0x78
+'(D', 'D)', '((|))', ')', '[?]', '[?]', '[?]', '[?]', # 0x80
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x88
+'-', '|', '-', '|', '-', '|', "\\", '/', # 0x90
+"\\", '/', '-', '-', '~', '~', '-', '|', # 0x98
+'-', '|', '-', '-', '-', '|', '-', '|', # 0xA0
+'|', '-', '-', '-', '-', '-', '-', '|', # 0xA8
+'|', '|', '|', '|', '|', '|', '^', 'V', # 0xB0
+"\\", '=', 'V', '^', '-', '-', '|', '|', # 0xB8
+'-', '-', '|', '|', '=', '|', '=', '=', # 0xC0
+'|', '=', '|', '=', '=', '=', '=', '=', # 0xC8
+'=', '|', '=', '|', '=', '|', "\\", '/', # 0xD0
+"\\", '/', '=', '=', '~', '~', '|', '|', # 0xD8
+'-', '|', '-', '|', '-', '-', '-', '|', # 0xE0
+'-', '|', '|', '|', '|', '|', '|', '|', # 0xE8
+'-', "\\", "\\", '|', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
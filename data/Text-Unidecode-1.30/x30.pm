@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x30] = [
+' ', ', ', '. ', '"', '[JIS]', '"', '/', '0', # 0x00
+'<', '> ', '<<', '>> ', '[', '] ', '{', '} ', # 0x08
+'[(', ')] ', '@', 'X ', '[', '] ', '[[', ']] ', # 0x10
+'((', ')) ', '[[', ']] ', '~ ', '``', "''", ',,', # 0x18
+'@', '1', '2', '3', '4', '5', '6', '7', # 0x20
+'8', '9', '', '', '', '', '', '', # 0x28
+'~', '+', '+', '+', '+', '', '@', ' // ', # 0x30
+'+10+', '+20+', '+30+', '[?]', '[?]', '[?]', '', '', # 0x38
+'[?]', 'a', 'a', 'i', 'i', 'u', 'u', 'e', # 0x40
+'e', 'o', 'o', 'ka', 'ga', 'ki', 'gi', 'ku', # 0x48
+'gu', 'ke', 'ge', 'ko', 'go', 'sa', 'za', 'si', # 0x50
+'zi', 'su', 'zu', 'se', 'ze', 'so', 'zo', 'ta', # 0x58
+'da', 'ti', 'di', 'tu', 'tu', 'du', 'te', 'de', # 0x60
+'to', 'do', 'na', 'ni', 'nu', 'ne', 'no', 'ha', # 0x68
+'ba', 'pa', 'hi', 'bi', 'pi', 'hu', 'bu', 'pu', # 0x70
+'he', 'be', 'pe', 'ho', 'bo', 'po', 'ma', 'mi', # 0x78
+'mu', 'me', 'mo', 'ya', 'ya', 'yu', 'yu', 'yo', # 0x80
+'yo', 'ra', 'ri', 'ru', 're', 'ro', 'wa', 'wa', # 0x88
+'wi', 'we', 'wo', 'n', 'vu', '[?]', '[?]', '[?]', # 0x90
+'[?]', '', '', '', '', '"', '"', '[?]', # 0x98
+'[?]', 'a', 'a', 'i', 'i', 'u', 'u', 'e', # 0xA0
+'e', 'o', 'o', 'ka', 'ga', 'ki', 'gi', 'ku', # 0xA8
+'gu', 'ke', 'ge', 'ko', 'go', 'sa', 'za', 'si', # 0xB0
+'zi', 'su', 'zu', 'se', 'ze', 'so', 'zo', 'ta', # 0xB8
+'da', 'ti', 'di', 'tu', 'tu', 'du', 'te', 'de', # 0xC0
+'to', 'do', 'na', 'ni', 'nu', 'ne', 'no', 'ha', # 0xC8
+'ba', 'pa', 'hi', 'bi', 'pi', 'hu', 'bu', 'pu', # 0xD0
+'he', 'be', 'pe', 'ho', 'bo', 'po', 'ma', 'mi', # 0xD8
+'mu', 'me', 'mo', 'ya', 'ya', 'yu', 'yu', 'yo', # 0xE0
+'yo', 'ra', 'ri', 'ru', 're', 'ro', 'wa', 'wa', # 0xE8
+'wi', 'we', 'wo', 'n', 'vu', 'ka', 'ke', 'va', # 0xF0
+'vi', 've', 'vo', '', '', '"', '"', 'koto', # 0xF8
+];
+1;
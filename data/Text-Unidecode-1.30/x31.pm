@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x31] = [
+'[?]', '[?]', '[?]', '[?]', '[?]', 'B', 'P', 'M', # 0x00
+'F', 'D', 'T', 'N', 'L', 'G', 'K', 'H', # 0x08
+'J', 'Q', 'X', 'ZH', 'CH', 'SH', 'R', 'Z', # 0x10
+'C', 'S', 'A', 'O', 'E', 'EH', 'AI', 'EI', # 0x18
+'AU', 'OU', 'AN', 'EN', 'ANG', 'ENG', 'ER', 'I', # 0x20
+'U', 'IU', 'V', 'NG', 'GN', '[?]', '[?]', '[?]', # 0x28
+'[?]', 'g', 'gg', 'gs', 'n', 'nj', 'nh', 'd', # 0x30
+'dd', 'r', 'lg', 'lm', 'lb', 'ls', 'lt', 'lp', # 0x38
+'rh', 'm', 'b', 'bb', 'bs', 's', 'ss', '', # 0x40
+'j', 'jj', 'c', 'k', 't', 'p', 'h', 'a', # 0x48
+'ae', 'ya', 'yae', 'eo', 'e', 'yeo', 'ye', 'o', # 0x50
+'wa', 'wae', 'oe', 'yo', 'u', 'weo', 'we', 'wi', # 0x58
+'yu', 'eu', 'yi', 'i', '', 'nn', 'nd', 'ns', # 0x60
+'nZ', 'lgs', 'ld', 'lbs', 'lZ', 'lQ', 'mb', 'ms', # 0x68
+'mZ', 'mN', 'bg', '', 'bsg', 'bst', 'bj', 'bt', # 0x70
+'bN', 'bbN', 'sg', 'sn', 'sd', 'sb', 'sj', 'Z', # 0x78
+'', 'N', 'Ns', 'NZ', 'pN', 'hh', 'Q', 'yo-ya', # 0x80
+'yo-yae', 'yo-i', 'yu-yeo', 'yu-ye', 'yu-i', 'U', 'U-i', '[?]', # 0x88
+'', '', '', '', '', '', '', '', # 0x90
+'', '', '', '', '', '', '', '', # 0x98
+'BU', 'ZI', 'JI', 'GU', 'EE', 'ENN', 'OO', 'ONN', # 0xA0
+'IR', 'ANN', 'INN', 'UNN', 'IM', 'NGG', 'AINN', 'AUNN', # 0xA8
+'AM', 'OM', 'ONG', 'INNN', 'P', 'T', 'K', 'H', # 0xB0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
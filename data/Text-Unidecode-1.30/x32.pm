@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x32] = [
+'(g)', '(n)', '(d)', '(r)', '(m)', '(b)', '(s)', '()', # 0x00
+'(j)', '(c)', '(k)', '(t)', '(p)', '(h)', '(ga)', '(na)', # 0x08
+'(da)', '(ra)', '(ma)', '(ba)', '(sa)', '(a)', '(ja)', '(ca)', # 0x10
+'(ka)', '(ta)', '(pa)', '(ha)', '(ju)', '[?]', '[?]', '[?]', # 0x18
+'(1) ', '(2) ', '(3) ', '(4) ', '(5) ', '(6) ', '(7) ', '(8) ', # 0x20
+'(9) ', '(10) ', '(Yue) ', '(Huo) ', '(Shui) ', '(Mu) ', '(Jin) ', '(Tu) ', # 0x28
+'(Ri) ', '(Zhu) ', '(You) ', '(She) ', '(Ming) ', '(Te) ', '(Cai) ', '(Zhu) ', # 0x30
+'(Lao) ', '(Dai) ', '(Hu) ', '(Xue) ', '(Jian) ', '(Qi) ', '(Zi) ', '(Xie) ', # 0x38
+'(Ji) ', '(Xiu) ', '<<', '>>', '[?]', '[?]', '[?]', '[?]', # 0x40
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x48
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x50
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x58
+'(g)', '(n)', '(d)', '(r)', '(m)', '(b)', '(s)', '()', # 0x60
+'(j)', '(c)', '(k)', '(t)', '(p)', '(h)', '(ga)', '(na)', # 0x68
+'(da)', '(ra)', '(ma)', '(ba)', '(sa)', '(a)', '(ja)', '(ca)', # 0x70
+'(ka)', '(ta)', '(pa)', '(ha)', '[?]', '[?]', '[?]', 'KIS ', # 0x78
+'(1) ', '(2) ', '(3) ', '(4) ', '(5) ', '(6) ', '(7) ', '(8) ', # 0x80
+'(9) ', '(10) ', '(Yue) ', '(Huo) ', '(Shui) ', '(Mu) ', '(Jin) ', '(Tu) ', # 0x88
+'(Ri) ', '(Zhu) ', '(You) ', '(She) ', '(Ming) ', '(Te) ', '(Cai) ', '(Zhu) ', # 0x90
+'(Lao) ', '(Mi) ', '(Nan) ', '(Nu) ', '(Shi) ', '(You) ', '(Yin) ', '(Zhu) ', # 0x98
+'(Xiang) ', '(Xiu) ', '(Xie) ', '(Zheng) ', '(Shang) ', '(Zhong) ', '(Xia) ', '(Zuo) ', # 0xA0
+'(You) ', '(Yi) ', '(Zong) ', '(Xue) ', '(Jian) ', '(Qi) ', '(Zi) ', '(Xie) ', # 0xA8
+'(Ye) ', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB8
+'1M', '2M', '3M', '4M', '5M', '6M', '7M', '8M', # 0xC0
+'9M', '10M', '11M', '12M', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'a', 'i', 'u', 'u', 'o', 'ka', 'ki', 'ku', # 0xD0
+'ke', 'ko', 'sa', 'si', 'su', 'se', 'so', 'ta', # 0xD8
+'ti', 'tu', 'te', 'to', 'na', 'ni', 'nu', 'ne', # 0xE0
+'no', 'ha', 'hi', 'hu', 'he', 'ho', 'ma', 'mi', # 0xE8
+'mu', 'me', 'mo', 'ya', 'yu', 'yo', 'ra', 'ri', # 0xF0
+'ru', 're', 'ro', 'wa', 'wi', 'we', 'wo', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0x33] = [
+'apartment', 'alpha', 'ampere', 'are', 'inning', 'inch', 'won', 'escudo', # 0x00
+'acre', 'ounce', 'ohm', 'kai-ri', 'carat', 'calorie', 'gallon', 'gamma', # 0x08
+'giga', 'guinea', 'curie', 'guilder', 'kilo', 'kilogram', 'kilometer', 'kilowatt', # 0x10
+'gram', 'gram ton', 'cruzeiro', 'krone', 'case', 'koruna', 'co-op', 'cycle', # 0x18
+'centime', 'shilling', 'centi', 'cent', 'dozen', 'desi', 'dollar', 'ton', # 0x20
+'nano', 'knot', 'heights', 'percent', 'parts', 'barrel', 'piaster', 'picul', # 0x28
+'pico', 'building', 'farad', 'feet', 'bushel', 'franc', 'hectare', 'peso', # 0x30
+'pfennig', 'hertz', 'pence', 'page', 'beta', 'point', 'volt', 'hon', # 0x38
+'pound', 'hall', 'horn', 'micro', 'mile', 'mach', 'mark', 'mansion', # 0x40
+'micron', 'milli', 'millibar', 'mega', 'megaton', 'meter', 'yard', 'yard', # 0x48
+'yuan', 'liter', 'lira', 'rupee', 'ruble', 'rem', 'roentgen', 'watt', # 0x50
+'0h', '1h', '2h', '3h', '4h', '5h', '6h', '7h', # 0x58
+'8h', '9h', '10h', '11h', '12h', '13h', '14h', '15h', # 0x60
+'16h', '17h', '18h', '19h', '20h', '21h', '22h', '23h', # 0x68
+'24h', 'HPA', 'da', 'AU', 'bar', 'oV', 'pc', '[?]', # 0x70
+'[?]', '[?]', '[?]', 'Heisei', 'Syouwa', 'Taisyou', 'Meiji', 'Inc.', # 0x78
+'pA', 'nA', 'microamp', 'mA', 'kA', 'kB', 'MB', 'GB', # 0x80
+'cal', 'kcal', 'pF', 'nF', 'microFarad', 'microgram', 'mg', 'kg', # 0x88
+'Hz', 'kHz', 'MHz', 'GHz', 'THz', 'microliter', 'ml', 'dl', # 0x90
+'kl', 'fm', 'nm', 'micrometer', 'mm', 'cm', 'km', 'mm^2', # 0x98
+'cm^2', 'm^2', 'km^2', 'mm^4', 'cm^3', 'm^3', 'km^3', 'm/s', # 0xA0
+'m/s^2', 'Pa', 'kPa', 'MPa', 'GPa', 'rad', 'rad/s', 'rad/s^2', # 0xA8
+'ps', 'ns', 'microsecond', 'ms', 'pV', 'nV', 'microvolt', 'mV', # 0xB0
+'kV', 'MV', 'pW', 'nW', 'microwatt', 'mW', 'kW', 'MW', # 0xB8
+'kOhm', 'MOhm', 'a.m.', 'Bq', 'cc', 'cd', 'C/kg', 'Co.', # 0xC0
+'dB', 'Gy', 'ha', 'HP', 'in', 'K.K.', 'KM', 'kt', # 0xC8
+'lm', 'ln', 'log', 'lx', 'mb', 'mil', 'mol', 'pH', # 0xD0
+'p.m.', 'PPM', 'PR', 'sr', 'Sv', 'Wb', '[?]', '[?]', # 0xD8
+'1d', '2d', '3d', '4d', '5d', '6d', '7d', '8d', # 0xE0
+'9d', '10d', '11d', '12d', '13d', '14d', '15d', '16d', # 0xE8
+'17d', '18d', '19d', '20d', '21d', '22d', '23d', '24d', # 0xF0
+'25d', '26d', '27d', '28d', '29d', '30d', '31d', 'gal', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xa0] = [
+'it', 'ix', 'i', 'ip', 'iet', 'iex', 'ie', 'iep', # 0x00
+'at', 'ax', 'a', 'ap', 'uox', 'uo', 'uop', 'ot', # 0x08
+'ox', 'o', 'op', 'ex', 'e', 'wu', 'bit', 'bix', # 0x10
+'bi', 'bip', 'biet', 'biex', 'bie', 'biep', 'bat', 'bax', # 0x18
+'ba', 'bap', 'buox', 'buo', 'buop', 'bot', 'box', 'bo', # 0x20
+'bop', 'bex', 'be', 'bep', 'but', 'bux', 'bu', 'bup', # 0x28
+'burx', 'bur', 'byt', 'byx', 'by', 'byp', 'byrx', 'byr', # 0x30
+'pit', 'pix', 'pi', 'pip', 'piex', 'pie', 'piep', 'pat', # 0x38
+'pax', 'pa', 'pap', 'puox', 'puo', 'puop', 'pot', 'pox', # 0x40
+'po', 'pop', 'put', 'pux', 'pu', 'pup', 'purx', 'pur', # 0x48
+'pyt', 'pyx', 'py', 'pyp', 'pyrx', 'pyr', 'bbit', 'bbix', # 0x50
+'bbi', 'bbip', 'bbiet', 'bbiex', 'bbie', 'bbiep', 'bbat', 'bbax', # 0x58
+'bba', 'bbap', 'bbuox', 'bbuo', 'bbuop', 'bbot', 'bbox', 'bbo', # 0x60
+'bbop', 'bbex', 'bbe', 'bbep', 'bbut', 'bbux', 'bbu', 'bbup', # 0x68
+'bburx', 'bbur', 'bbyt', 'bbyx', 'bby', 'bbyp', 'nbit', 'nbix', # 0x70
+'nbi', 'nbip', 'nbiex', 'nbie', 'nbiep', 'nbat', 'nbax', 'nba', # 0x78
+'nbap', 'nbot', 'nbox', 'nbo', 'nbop', 'nbut', 'nbux', 'nbu', # 0x80
+'nbup', 'nburx', 'nbur', 'nbyt', 'nbyx', 'nby', 'nbyp', 'nbyrx', # 0x88
+'nbyr', 'hmit', 'hmix', 'hmi', 'hmip', 'hmiex', 'hmie', 'hmiep', # 0x90
+'hmat', 'hmax', 'hma', 'hmap', 'hmuox', 'hmuo', 'hmuop', 'hmot', # 0x98
+'hmox', 'hmo', 'hmop', 'hmut', 'hmux', 'hmu', 'hmup', 'hmurx', # 0xA0
+'hmur', 'hmyx', 'hmy', 'hmyp', 'hmyrx', 'hmyr', 'mit', 'mix', # 0xA8
+'mi', 'mip', 'miex', 'mie', 'miep', 'mat', 'max', 'ma', # 0xB0
+'map', 'muot', 'muox', 'muo', 'muop', 'mot', 'mox', 'mo', # 0xB8
+'mop', 'mex', 'me', 'mut', 'mux', 'mu', 'mup', 'murx', # 0xC0
+'mur', 'myt', 'myx', 'my', 'myp', 'fit', 'fix', 'fi', # 0xC8
+'fip', 'fat', 'fax', 'fa', 'fap', 'fox', 'fo', 'fop', # 0xD0
+'fut', 'fux', 'fu', 'fup', 'furx', 'fur', 'fyt', 'fyx', # 0xD8
+'fy', 'fyp', 'vit', 'vix', 'vi', 'vip', 'viet', 'viex', # 0xE0
+'vie', 'viep', 'vat', 'vax', 'va', 'vap', 'vot', 'vox', # 0xE8
+'vo', 'vop', 'vex', 'vep', 'vut', 'vux', 'vu', 'vup', # 0xF0
+'vurx', 'vur', 'vyt', 'vyx', 'vy', 'vyp', 'vyrx', 'vyr', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xa1] = [
+'dit', 'dix', 'di', 'dip', 'diex', 'die', 'diep', 'dat', # 0x00
+'dax', 'da', 'dap', 'duox', 'duo', 'dot', 'dox', 'do', # 0x08
+'dop', 'dex', 'de', 'dep', 'dut', 'dux', 'du', 'dup', # 0x10
+'durx', 'dur', 'tit', 'tix', 'ti', 'tip', 'tiex', 'tie', # 0x18
+'tiep', 'tat', 'tax', 'ta', 'tap', 'tuot', 'tuox', 'tuo', # 0x20
+'tuop', 'tot', 'tox', 'to', 'top', 'tex', 'te', 'tep', # 0x28
+'tut', 'tux', 'tu', 'tup', 'turx', 'tur', 'ddit', 'ddix', # 0x30
+'ddi', 'ddip', 'ddiex', 'ddie', 'ddiep', 'ddat', 'ddax', 'dda', # 0x38
+'ddap', 'dduox', 'dduo', 'dduop', 'ddot', 'ddox', 'ddo', 'ddop', # 0x40
+'ddex', 'dde', 'ddep', 'ddut', 'ddux', 'ddu', 'ddup', 'ddurx', # 0x48
+'ddur', 'ndit', 'ndix', 'ndi', 'ndip', 'ndiex', 'ndie', 'ndat', # 0x50
+'ndax', 'nda', 'ndap', 'ndot', 'ndox', 'ndo', 'ndop', 'ndex', # 0x58
+'nde', 'ndep', 'ndut', 'ndux', 'ndu', 'ndup', 'ndurx', 'ndur', # 0x60
+'hnit', 'hnix', 'hni', 'hnip', 'hniet', 'hniex', 'hnie', 'hniep', # 0x68
+'hnat', 'hnax', 'hna', 'hnap', 'hnuox', 'hnuo', 'hnot', 'hnox', # 0x70
+'hnop', 'hnex', 'hne', 'hnep', 'hnut', 'nit', 'nix', 'ni', # 0x78
+'nip', 'niex', 'nie', 'niep', 'nax', 'na', 'nap', 'nuox', # 0x80
+'nuo', 'nuop', 'not', 'nox', 'no', 'nop', 'nex', 'ne', # 0x88
+'nep', 'nut', 'nux', 'nu', 'nup', 'nurx', 'nur', 'hlit', # 0x90
+'hlix', 'hli', 'hlip', 'hliex', 'hlie', 'hliep', 'hlat', 'hlax', # 0x98
+'hla', 'hlap', 'hluox', 'hluo', 'hluop', 'hlox', 'hlo', 'hlop', # 0xA0
+'hlex', 'hle', 'hlep', 'hlut', 'hlux', 'hlu', 'hlup', 'hlurx', # 0xA8
+'hlur', 'hlyt', 'hlyx', 'hly', 'hlyp', 'hlyrx', 'hlyr', 'lit', # 0xB0
+'lix', 'li', 'lip', 'liet', 'liex', 'lie', 'liep', 'lat', # 0xB8
+'lax', 'la', 'lap', 'luot', 'luox', 'luo', 'luop', 'lot', # 0xC0
+'lox', 'lo', 'lop', 'lex', 'le', 'lep', 'lut', 'lux', # 0xC8
+'lu', 'lup', 'lurx', 'lur', 'lyt', 'lyx', 'ly', 'lyp', # 0xD0
+'lyrx', 'lyr', 'git', 'gix', 'gi', 'gip', 'giet', 'giex', # 0xD8
+'gie', 'giep', 'gat', 'gax', 'ga', 'gap', 'guot', 'guox', # 0xE0
+'guo', 'guop', 'got', 'gox', 'go', 'gop', 'get', 'gex', # 0xE8
+'ge', 'gep', 'gut', 'gux', 'gu', 'gup', 'gurx', 'gur', # 0xF0
+'kit', 'kix', 'ki', 'kip', 'kiex', 'kie', 'kiep', 'kat', # 0xF8
+];
+1;
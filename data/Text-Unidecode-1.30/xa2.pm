@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xa2] = [
+'kax', 'ka', 'kap', 'kuox', 'kuo', 'kuop', 'kot', 'kox', # 0x00
+'ko', 'kop', 'ket', 'kex', 'ke', 'kep', 'kut', 'kux', # 0x08
+'ku', 'kup', 'kurx', 'kur', 'ggit', 'ggix', 'ggi', 'ggiex', # 0x10
+'ggie', 'ggiep', 'ggat', 'ggax', 'gga', 'ggap', 'gguot', 'gguox', # 0x18
+'gguo', 'gguop', 'ggot', 'ggox', 'ggo', 'ggop', 'gget', 'ggex', # 0x20
+'gge', 'ggep', 'ggut', 'ggux', 'ggu', 'ggup', 'ggurx', 'ggur', # 0x28
+'mgiex', 'mgie', 'mgat', 'mgax', 'mga', 'mgap', 'mguox', 'mguo', # 0x30
+'mguop', 'mgot', 'mgox', 'mgo', 'mgop', 'mgex', 'mge', 'mgep', # 0x38
+'mgut', 'mgux', 'mgu', 'mgup', 'mgurx', 'mgur', 'hxit', 'hxix', # 0x40
+'hxi', 'hxip', 'hxiet', 'hxiex', 'hxie', 'hxiep', 'hxat', 'hxax', # 0x48
+'hxa', 'hxap', 'hxuot', 'hxuox', 'hxuo', 'hxuop', 'hxot', 'hxox', # 0x50
+'hxo', 'hxop', 'hxex', 'hxe', 'hxep', 'ngiex', 'ngie', 'ngiep', # 0x58
+'ngat', 'ngax', 'nga', 'ngap', 'nguot', 'nguox', 'nguo', 'ngot', # 0x60
+'ngox', 'ngo', 'ngop', 'ngex', 'nge', 'ngep', 'hit', 'hiex', # 0x68
+'hie', 'hat', 'hax', 'ha', 'hap', 'huot', 'huox', 'huo', # 0x70
+'huop', 'hot', 'hox', 'ho', 'hop', 'hex', 'he', 'hep', # 0x78
+'wat', 'wax', 'wa', 'wap', 'wuox', 'wuo', 'wuop', 'wox', # 0x80
+'wo', 'wop', 'wex', 'we', 'wep', 'zit', 'zix', 'zi', # 0x88
+'zip', 'ziex', 'zie', 'ziep', 'zat', 'zax', 'za', 'zap', # 0x90
+'zuox', 'zuo', 'zuop', 'zot', 'zox', 'zo', 'zop', 'zex', # 0x98
+'ze', 'zep', 'zut', 'zux', 'zu', 'zup', 'zurx', 'zur', # 0xA0
+'zyt', 'zyx', 'zy', 'zyp', 'zyrx', 'zyr', 'cit', 'cix', # 0xA8
+'ci', 'cip', 'ciet', 'ciex', 'cie', 'ciep', 'cat', 'cax', # 0xB0
+'ca', 'cap', 'cuox', 'cuo', 'cuop', 'cot', 'cox', 'co', # 0xB8
+'cop', 'cex', 'ce', 'cep', 'cut', 'cux', 'cu', 'cup', # 0xC0
+'curx', 'cur', 'cyt', 'cyx', 'cy', 'cyp', 'cyrx', 'cyr', # 0xC8
+'zzit', 'zzix', 'zzi', 'zzip', 'zziet', 'zziex', 'zzie', 'zziep', # 0xD0
+'zzat', 'zzax', 'zza', 'zzap', 'zzox', 'zzo', 'zzop', 'zzex', # 0xD8
+'zze', 'zzep', 'zzux', 'zzu', 'zzup', 'zzurx', 'zzur', 'zzyt', # 0xE0
+'zzyx', 'zzy', 'zzyp', 'zzyrx', 'zzyr', 'nzit', 'nzix', 'nzi', # 0xE8
+'nzip', 'nziex', 'nzie', 'nziep', 'nzat', 'nzax', 'nza', 'nzap', # 0xF0
+'nzuox', 'nzuo', 'nzox', 'nzop', 'nzex', 'nze', 'nzux', 'nzu', # 0xF8
+];
+1;
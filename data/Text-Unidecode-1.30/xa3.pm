@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xa3] = [
+'nzup', 'nzurx', 'nzur', 'nzyt', 'nzyx', 'nzy', 'nzyp', 'nzyrx', # 0x00
+'nzyr', 'sit', 'six', 'si', 'sip', 'siex', 'sie', 'siep', # 0x08
+'sat', 'sax', 'sa', 'sap', 'suox', 'suo', 'suop', 'sot', # 0x10
+'sox', 'so', 'sop', 'sex', 'se', 'sep', 'sut', 'sux', # 0x18
+'su', 'sup', 'surx', 'sur', 'syt', 'syx', 'sy', 'syp', # 0x20
+'syrx', 'syr', 'ssit', 'ssix', 'ssi', 'ssip', 'ssiex', 'ssie', # 0x28
+'ssiep', 'ssat', 'ssax', 'ssa', 'ssap', 'ssot', 'ssox', 'sso', # 0x30
+'ssop', 'ssex', 'sse', 'ssep', 'ssut', 'ssux', 'ssu', 'ssup', # 0x38
+'ssyt', 'ssyx', 'ssy', 'ssyp', 'ssyrx', 'ssyr', 'zhat', 'zhax', # 0x40
+'zha', 'zhap', 'zhuox', 'zhuo', 'zhuop', 'zhot', 'zhox', 'zho', # 0x48
+'zhop', 'zhet', 'zhex', 'zhe', 'zhep', 'zhut', 'zhux', 'zhu', # 0x50
+'zhup', 'zhurx', 'zhur', 'zhyt', 'zhyx', 'zhy', 'zhyp', 'zhyrx', # 0x58
+'zhyr', 'chat', 'chax', 'cha', 'chap', 'chuot', 'chuox', 'chuo', # 0x60
+'chuop', 'chot', 'chox', 'cho', 'chop', 'chet', 'chex', 'che', # 0x68
+'chep', 'chux', 'chu', 'chup', 'churx', 'chur', 'chyt', 'chyx', # 0x70
+'chy', 'chyp', 'chyrx', 'chyr', 'rrax', 'rra', 'rruox', 'rruo', # 0x78
+'rrot', 'rrox', 'rro', 'rrop', 'rret', 'rrex', 'rre', 'rrep', # 0x80
+'rrut', 'rrux', 'rru', 'rrup', 'rrurx', 'rrur', 'rryt', 'rryx', # 0x88
+'rry', 'rryp', 'rryrx', 'rryr', 'nrat', 'nrax', 'nra', 'nrap', # 0x90
+'nrox', 'nro', 'nrop', 'nret', 'nrex', 'nre', 'nrep', 'nrut', # 0x98
+'nrux', 'nru', 'nrup', 'nrurx', 'nrur', 'nryt', 'nryx', 'nry', # 0xA0
+'nryp', 'nryrx', 'nryr', 'shat', 'shax', 'sha', 'shap', 'shuox', # 0xA8
+'shuo', 'shuop', 'shot', 'shox', 'sho', 'shop', 'shet', 'shex', # 0xB0
+'she', 'shep', 'shut', 'shux', 'shu', 'shup', 'shurx', 'shur', # 0xB8
+'shyt', 'shyx', 'shy', 'shyp', 'shyrx', 'shyr', 'rat', 'rax', # 0xC0
+'ra', 'rap', 'ruox', 'ruo', 'ruop', 'rot', 'rox', 'ro', # 0xC8
+'rop', 'rex', 're', 'rep', 'rut', 'rux', 'ru', 'rup', # 0xD0
+'rurx', 'rur', 'ryt', 'ryx', 'ry', 'ryp', 'ryrx', 'ryr', # 0xD8
+'jit', 'jix', 'ji', 'jip', 'jiet', 'jiex', 'jie', 'jiep', # 0xE0
+'juot', 'juox', 'juo', 'juop', 'jot', 'jox', 'jo', 'jop', # 0xE8
+'jut', 'jux', 'ju', 'jup', 'jurx', 'jur', 'jyt', 'jyx', # 0xF0
+'jy', 'jyp', 'jyrx', 'jyr', 'qit', 'qix', 'qi', 'qip', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xa4] = [
+'qiet', 'qiex', 'qie', 'qiep', 'quot', 'quox', 'quo', 'quop', # 0x00
+'qot', 'qox', 'qo', 'qop', 'qut', 'qux', 'qu', 'qup', # 0x08
+'qurx', 'qur', 'qyt', 'qyx', 'qy', 'qyp', 'qyrx', 'qyr', # 0x10
+'jjit', 'jjix', 'jji', 'jjip', 'jjiet', 'jjiex', 'jjie', 'jjiep', # 0x18
+'jjuox', 'jjuo', 'jjuop', 'jjot', 'jjox', 'jjo', 'jjop', 'jjut', # 0x20
+'jjux', 'jju', 'jjup', 'jjurx', 'jjur', 'jjyt', 'jjyx', 'jjy', # 0x28
+'jjyp', 'njit', 'njix', 'nji', 'njip', 'njiet', 'njiex', 'njie', # 0x30
+'njiep', 'njuox', 'njuo', 'njot', 'njox', 'njo', 'njop', 'njux', # 0x38
+'nju', 'njup', 'njurx', 'njur', 'njyt', 'njyx', 'njy', 'njyp', # 0x40
+'njyrx', 'njyr', 'nyit', 'nyix', 'nyi', 'nyip', 'nyiet', 'nyiex', # 0x48
+'nyie', 'nyiep', 'nyuox', 'nyuo', 'nyuop', 'nyot', 'nyox', 'nyo', # 0x50
+'nyop', 'nyut', 'nyux', 'nyu', 'nyup', 'xit', 'xix', 'xi', # 0x58
+'xip', 'xiet', 'xiex', 'xie', 'xiep', 'xuox', 'xuo', 'xot', # 0x60
+'xox', 'xo', 'xop', 'xyt', 'xyx', 'xy', 'xyp', 'xyrx', # 0x68
+'xyr', 'yit', 'yix', 'yi', 'yip', 'yiet', 'yiex', 'yie', # 0x70
+'yiep', 'yuot', 'yuox', 'yuo', 'yuop', 'yot', 'yox', 'yo', # 0x78
+'yop', 'yut', 'yux', 'yu', 'yup', 'yurx', 'yur', 'yyt', # 0x80
+'yyx', 'yy', 'yyp', 'yyrx', 'yyr', '[?]', '[?]', '[?]', # 0x88
+'Qot', 'Li', 'Kit', 'Nyip', 'Cyp', 'Ssi', 'Ggop', 'Gep', # 0x90
+'Mi', 'Hxit', 'Lyr', 'Bbut', 'Mop', 'Yo', 'Put', 'Hxuo', # 0x98
+'Tat', 'Ga', '[?]', '[?]', 'Ddur', 'Bur', 'Gguo', 'Nyop', # 0xA0
+'Tu', 'Op', 'Jjut', 'Zot', 'Pyt', 'Hmo', 'Yit', 'Vur', # 0xA8
+'Shy', 'Vep', 'Za', 'Jo', '[?]', 'Jjy', 'Got', 'Jjie', # 0xB0
+'Wo', 'Du', 'Shur', 'Lie', 'Cy', 'Cuop', 'Cip', 'Hxop', # 0xB8
+'Shat', '[?]', 'Shop', 'Che', 'Zziet', '[?]', 'Ke', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xD8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xac] = [
+'ga', 'gag', 'gagg', 'gags', 'gan', 'ganj', 'ganh', 'gad', # 0x00
+'gal', 'galg', 'galm', 'galb', 'gals', 'galt', 'galp', 'galh', # 0x08
+'gam', 'gab', 'gabs', 'gas', 'gass', 'gang', 'gaj', 'gac', # 0x10
+'gak', 'gat', 'gap', 'gah', 'gae', 'gaeg', 'gaegg', 'gaegs', # 0x18
+'gaen', 'gaenj', 'gaenh', 'gaed', 'gael', 'gaelg', 'gaelm', 'gaelb', # 0x20
+'gaels', 'gaelt', 'gaelp', 'gaelh', 'gaem', 'gaeb', 'gaebs', 'gaes', # 0x28
+'gaess', 'gaeng', 'gaej', 'gaec', 'gaek', 'gaet', 'gaep', 'gaeh', # 0x30
+'gya', 'gyag', 'gyagg', 'gyags', 'gyan', 'gyanj', 'gyanh', 'gyad', # 0x38
+'gyal', 'gyalg', 'gyalm', 'gyalb', 'gyals', 'gyalt', 'gyalp', 'gyalh', # 0x40
+'gyam', 'gyab', 'gyabs', 'gyas', 'gyass', 'gyang', 'gyaj', 'gyac', # 0x48
+'gyak', 'gyat', 'gyap', 'gyah', 'gyae', 'gyaeg', 'gyaegg', 'gyaegs', # 0x50
+'gyaen', 'gyaenj', 'gyaenh', 'gyaed', 'gyael', 'gyaelg', 'gyaelm', 'gyaelb', # 0x58
+'gyaels', 'gyaelt', 'gyaelp', 'gyaelh', 'gyaem', 'gyaeb', 'gyaebs', 'gyaes', # 0x60
+'gyaess', 'gyaeng', 'gyaej', 'gyaec', 'gyaek', 'gyaet', 'gyaep', 'gyaeh', # 0x68
+'geo', 'geog', 'geogg', 'geogs', 'geon', 'geonj', 'geonh', 'geod', # 0x70
+'geol', 'geolg', 'geolm', 'geolb', 'geols', 'geolt', 'geolp', 'geolh', # 0x78
+'geom', 'geob', 'geobs', 'geos', 'geoss', 'geong', 'geoj', 'geoc', # 0x80
+'geok', 'geot', 'geop', 'geoh', 'ge', 'geg', 'gegg', 'gegs', # 0x88
+'gen', 'genj', 'genh', 'ged', 'gel', 'gelg', 'gelm', 'gelb', # 0x90
+'gels', 'gelt', 'gelp', 'gelh', 'gem', 'geb', 'gebs', 'ges', # 0x98
+'gess', 'geng', 'gej', 'gec', 'gek', 'get', 'gep', 'geh', # 0xA0
+'gyeo', 'gyeog', 'gyeogg', 'gyeogs', 'gyeon', 'gyeonj', 'gyeonh', 'gyeod', # 0xA8
+'gyeol', 'gyeolg', 'gyeolm', 'gyeolb', 'gyeols', 'gyeolt', 'gyeolp', 'gyeolh', # 0xB0
+'gyeom', 'gyeob', 'gyeobs', 'gyeos', 'gyeoss', 'gyeong', 'gyeoj', 'gyeoc', # 0xB8
+'gyeok', 'gyeot', 'gyeop', 'gyeoh', 'gye', 'gyeg', 'gyegg', 'gyegs', # 0xC0
+'gyen', 'gyenj', 'gyenh', 'gyed', 'gyel', 'gyelg', 'gyelm', 'gyelb', # 0xC8
+'gyels', 'gyelt', 'gyelp', 'gyelh', 'gyem', 'gyeb', 'gyebs', 'gyes', # 0xD0
+'gyess', 'gyeng', 'gyej', 'gyec', 'gyek', 'gyet', 'gyep', 'gyeh', # 0xD8
+'go', 'gog', 'gogg', 'gogs', 'gon', 'gonj', 'gonh', 'god', # 0xE0
+'gol', 'golg', 'golm', 'golb', 'gols', 'golt', 'golp', 'golh', # 0xE8
+'gom', 'gob', 'gobs', 'gos', 'goss', 'gong', 'goj', 'goc', # 0xF0
+'gok', 'got', 'gop', 'goh', 'gwa', 'gwag', 'gwagg', 'gwags', # 0xF8
+];
+1;
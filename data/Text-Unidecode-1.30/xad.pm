@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xad] = [
+'gwan', 'gwanj', 'gwanh', 'gwad', 'gwal', 'gwalg', 'gwalm', 'gwalb', # 0x00
+'gwals', 'gwalt', 'gwalp', 'gwalh', 'gwam', 'gwab', 'gwabs', 'gwas', # 0x08
+'gwass', 'gwang', 'gwaj', 'gwac', 'gwak', 'gwat', 'gwap', 'gwah', # 0x10
+'gwae', 'gwaeg', 'gwaegg', 'gwaegs', 'gwaen', 'gwaenj', 'gwaenh', 'gwaed', # 0x18
+'gwael', 'gwaelg', 'gwaelm', 'gwaelb', 'gwaels', 'gwaelt', 'gwaelp', 'gwaelh', # 0x20
+'gwaem', 'gwaeb', 'gwaebs', 'gwaes', 'gwaess', 'gwaeng', 'gwaej', 'gwaec', # 0x28
+'gwaek', 'gwaet', 'gwaep', 'gwaeh', 'goe', 'goeg', 'goegg', 'goegs', # 0x30
+'goen', 'goenj', 'goenh', 'goed', 'goel', 'goelg', 'goelm', 'goelb', # 0x38
+'goels', 'goelt', 'goelp', 'goelh', 'goem', 'goeb', 'goebs', 'goes', # 0x40
+'goess', 'goeng', 'goej', 'goec', 'goek', 'goet', 'goep', 'goeh', # 0x48
+'gyo', 'gyog', 'gyogg', 'gyogs', 'gyon', 'gyonj', 'gyonh', 'gyod', # 0x50
+'gyol', 'gyolg', 'gyolm', 'gyolb', 'gyols', 'gyolt', 'gyolp', 'gyolh', # 0x58
+'gyom', 'gyob', 'gyobs', 'gyos', 'gyoss', 'gyong', 'gyoj', 'gyoc', # 0x60
+'gyok', 'gyot', 'gyop', 'gyoh', 'gu', 'gug', 'gugg', 'gugs', # 0x68
+'gun', 'gunj', 'gunh', 'gud', 'gul', 'gulg', 'gulm', 'gulb', # 0x70
+'guls', 'gult', 'gulp', 'gulh', 'gum', 'gub', 'gubs', 'gus', # 0x78
+'guss', 'gung', 'guj', 'guc', 'guk', 'gut', 'gup', 'guh', # 0x80
+'gweo', 'gweog', 'gweogg', 'gweogs', 'gweon', 'gweonj', 'gweonh', 'gweod', # 0x88
+'gweol', 'gweolg', 'gweolm', 'gweolb', 'gweols', 'gweolt', 'gweolp', 'gweolh', # 0x90
+'gweom', 'gweob', 'gweobs', 'gweos', 'gweoss', 'gweong', 'gweoj', 'gweoc', # 0x98
+'gweok', 'gweot', 'gweop', 'gweoh', 'gwe', 'gweg', 'gwegg', 'gwegs', # 0xA0
+'gwen', 'gwenj', 'gwenh', 'gwed', 'gwel', 'gwelg', 'gwelm', 'gwelb', # 0xA8
+'gwels', 'gwelt', 'gwelp', 'gwelh', 'gwem', 'gweb', 'gwebs', 'gwes', # 0xB0
+'gwess', 'gweng', 'gwej', 'gwec', 'gwek', 'gwet', 'gwep', 'gweh', # 0xB8
+'gwi', 'gwig', 'gwigg', 'gwigs', 'gwin', 'gwinj', 'gwinh', 'gwid', # 0xC0
+'gwil', 'gwilg', 'gwilm', 'gwilb', 'gwils', 'gwilt', 'gwilp', 'gwilh', # 0xC8
+'gwim', 'gwib', 'gwibs', 'gwis', 'gwiss', 'gwing', 'gwij', 'gwic', # 0xD0
+'gwik', 'gwit', 'gwip', 'gwih', 'gyu', 'gyug', 'gyugg', 'gyugs', # 0xD8
+'gyun', 'gyunj', 'gyunh', 'gyud', 'gyul', 'gyulg', 'gyulm', 'gyulb', # 0xE0
+'gyuls', 'gyult', 'gyulp', 'gyulh', 'gyum', 'gyub', 'gyubs', 'gyus', # 0xE8
+'gyuss', 'gyung', 'gyuj', 'gyuc', 'gyuk', 'gyut', 'gyup', 'gyuh', # 0xF0
+'geu', 'geug', 'geugg', 'geugs', 'geun', 'geunj', 'geunh', 'geud', # 0xF8
+];
+1;
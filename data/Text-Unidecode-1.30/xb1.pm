@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb1] = [
+'nyaess', 'nyaeng', 'nyaej', 'nyaec', 'nyaek', 'nyaet', 'nyaep', 'nyaeh', # 0x00
+'neo', 'neog', 'neogg', 'neogs', 'neon', 'neonj', 'neonh', 'neod', # 0x08
+'neol', 'neolg', 'neolm', 'neolb', 'neols', 'neolt', 'neolp', 'neolh', # 0x10
+'neom', 'neob', 'neobs', 'neos', 'neoss', 'neong', 'neoj', 'neoc', # 0x18
+'neok', 'neot', 'neop', 'neoh', 'ne', 'neg', 'negg', 'negs', # 0x20
+'nen', 'nenj', 'nenh', 'ned', 'nel', 'nelg', 'nelm', 'nelb', # 0x28
+'nels', 'nelt', 'nelp', 'nelh', 'nem', 'neb', 'nebs', 'nes', # 0x30
+'ness', 'neng', 'nej', 'nec', 'nek', 'net', 'nep', 'neh', # 0x38
+'nyeo', 'nyeog', 'nyeogg', 'nyeogs', 'nyeon', 'nyeonj', 'nyeonh', 'nyeod', # 0x40
+'nyeol', 'nyeolg', 'nyeolm', 'nyeolb', 'nyeols', 'nyeolt', 'nyeolp', 'nyeolh', # 0x48
+'nyeom', 'nyeob', 'nyeobs', 'nyeos', 'nyeoss', 'nyeong', 'nyeoj', 'nyeoc', # 0x50
+'nyeok', 'nyeot', 'nyeop', 'nyeoh', 'nye', 'nyeg', 'nyegg', 'nyegs', # 0x58
+'nyen', 'nyenj', 'nyenh', 'nyed', 'nyel', 'nyelg', 'nyelm', 'nyelb', # 0x60
+'nyels', 'nyelt', 'nyelp', 'nyelh', 'nyem', 'nyeb', 'nyebs', 'nyes', # 0x68
+'nyess', 'nyeng', 'nyej', 'nyec', 'nyek', 'nyet', 'nyep', 'nyeh', # 0x70
+'no', 'nog', 'nogg', 'nogs', 'non', 'nonj', 'nonh', 'nod', # 0x78
+'nol', 'nolg', 'nolm', 'nolb', 'nols', 'nolt', 'nolp', 'nolh', # 0x80
+'nom', 'nob', 'nobs', 'nos', 'noss', 'nong', 'noj', 'noc', # 0x88
+'nok', 'not', 'nop', 'noh', 'nwa', 'nwag', 'nwagg', 'nwags', # 0x90
+'nwan', 'nwanj', 'nwanh', 'nwad', 'nwal', 'nwalg', 'nwalm', 'nwalb', # 0x98
+'nwals', 'nwalt', 'nwalp', 'nwalh', 'nwam', 'nwab', 'nwabs', 'nwas', # 0xA0
+'nwass', 'nwang', 'nwaj', 'nwac', 'nwak', 'nwat', 'nwap', 'nwah', # 0xA8
+'nwae', 'nwaeg', 'nwaegg', 'nwaegs', 'nwaen', 'nwaenj', 'nwaenh', 'nwaed', # 0xB0
+'nwael', 'nwaelg', 'nwaelm', 'nwaelb', 'nwaels', 'nwaelt', 'nwaelp', 'nwaelh', # 0xB8
+'nwaem', 'nwaeb', 'nwaebs', 'nwaes', 'nwaess', 'nwaeng', 'nwaej', 'nwaec', # 0xC0
+'nwaek', 'nwaet', 'nwaep', 'nwaeh', 'noe', 'noeg', 'noegg', 'noegs', # 0xC8
+'noen', 'noenj', 'noenh', 'noed', 'noel', 'noelg', 'noelm', 'noelb', # 0xD0
+'noels', 'noelt', 'noelp', 'noelh', 'noem', 'noeb', 'noebs', 'noes', # 0xD8
+'noess', 'noeng', 'noej', 'noec', 'noek', 'noet', 'noep', 'noeh', # 0xE0
+'nyo', 'nyog', 'nyogg', 'nyogs', 'nyon', 'nyonj', 'nyonh', 'nyod', # 0xE8
+'nyol', 'nyolg', 'nyolm', 'nyolb', 'nyols', 'nyolt', 'nyolp', 'nyolh', # 0xF0
+'nyom', 'nyob', 'nyobs', 'nyos', 'nyoss', 'nyong', 'nyoj', 'nyoc', # 0xF8
+];
+1;
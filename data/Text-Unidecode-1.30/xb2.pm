@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb2] = [
+'nyok', 'nyot', 'nyop', 'nyoh', 'nu', 'nug', 'nugg', 'nugs', # 0x00
+'nun', 'nunj', 'nunh', 'nud', 'nul', 'nulg', 'nulm', 'nulb', # 0x08
+'nuls', 'nult', 'nulp', 'nulh', 'num', 'nub', 'nubs', 'nus', # 0x10
+'nuss', 'nung', 'nuj', 'nuc', 'nuk', 'nut', 'nup', 'nuh', # 0x18
+'nweo', 'nweog', 'nweogg', 'nweogs', 'nweon', 'nweonj', 'nweonh', 'nweod', # 0x20
+'nweol', 'nweolg', 'nweolm', 'nweolb', 'nweols', 'nweolt', 'nweolp', 'nweolh', # 0x28
+'nweom', 'nweob', 'nweobs', 'nweos', 'nweoss', 'nweong', 'nweoj', 'nweoc', # 0x30
+'nweok', 'nweot', 'nweop', 'nweoh', 'nwe', 'nweg', 'nwegg', 'nwegs', # 0x38
+'nwen', 'nwenj', 'nwenh', 'nwed', 'nwel', 'nwelg', 'nwelm', 'nwelb', # 0x40
+'nwels', 'nwelt', 'nwelp', 'nwelh', 'nwem', 'nweb', 'nwebs', 'nwes', # 0x48
+'nwess', 'nweng', 'nwej', 'nwec', 'nwek', 'nwet', 'nwep', 'nweh', # 0x50
+'nwi', 'nwig', 'nwigg', 'nwigs', 'nwin', 'nwinj', 'nwinh', 'nwid', # 0x58
+'nwil', 'nwilg', 'nwilm', 'nwilb', 'nwils', 'nwilt', 'nwilp', 'nwilh', # 0x60
+'nwim', 'nwib', 'nwibs', 'nwis', 'nwiss', 'nwing', 'nwij', 'nwic', # 0x68
+'nwik', 'nwit', 'nwip', 'nwih', 'nyu', 'nyug', 'nyugg', 'nyugs', # 0x70
+'nyun', 'nyunj', 'nyunh', 'nyud', 'nyul', 'nyulg', 'nyulm', 'nyulb', # 0x78
+'nyuls', 'nyult', 'nyulp', 'nyulh', 'nyum', 'nyub', 'nyubs', 'nyus', # 0x80
+'nyuss', 'nyung', 'nyuj', 'nyuc', 'nyuk', 'nyut', 'nyup', 'nyuh', # 0x88
+'neu', 'neug', 'neugg', 'neugs', 'neun', 'neunj', 'neunh', 'neud', # 0x90
+'neul', 'neulg', 'neulm', 'neulb', 'neuls', 'neult', 'neulp', 'neulh', # 0x98
+'neum', 'neub', 'neubs', 'neus', 'neuss', 'neung', 'neuj', 'neuc', # 0xA0
+'neuk', 'neut', 'neup', 'neuh', 'nyi', 'nyig', 'nyigg', 'nyigs', # 0xA8
+'nyin', 'nyinj', 'nyinh', 'nyid', 'nyil', 'nyilg', 'nyilm', 'nyilb', # 0xB0
+'nyils', 'nyilt', 'nyilp', 'nyilh', 'nyim', 'nyib', 'nyibs', 'nyis', # 0xB8
+'nyiss', 'nying', 'nyij', 'nyic', 'nyik', 'nyit', 'nyip', 'nyih', # 0xC0
+'ni', 'nig', 'nigg', 'nigs', 'nin', 'ninj', 'ninh', 'nid', # 0xC8
+'nil', 'nilg', 'nilm', 'nilb', 'nils', 'nilt', 'nilp', 'nilh', # 0xD0
+'nim', 'nib', 'nibs', 'nis', 'niss', 'ning', 'nij', 'nic', # 0xD8
+'nik', 'nit', 'nip', 'nih', 'da', 'dag', 'dagg', 'dags', # 0xE0
+'dan', 'danj', 'danh', 'dad', 'dal', 'dalg', 'dalm', 'dalb', # 0xE8
+'dals', 'dalt', 'dalp', 'dalh', 'dam', 'dab', 'dabs', 'das', # 0xF0
+'dass', 'dang', 'daj', 'dac', 'dak', 'dat', 'dap', 'dah', # 0xF8
+];
+1;
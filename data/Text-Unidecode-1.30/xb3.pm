@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb3] = [
+'dae', 'daeg', 'daegg', 'daegs', 'daen', 'daenj', 'daenh', 'daed', # 0x00
+'dael', 'daelg', 'daelm', 'daelb', 'daels', 'daelt', 'daelp', 'daelh', # 0x08
+'daem', 'daeb', 'daebs', 'daes', 'daess', 'daeng', 'daej', 'daec', # 0x10
+'daek', 'daet', 'daep', 'daeh', 'dya', 'dyag', 'dyagg', 'dyags', # 0x18
+'dyan', 'dyanj', 'dyanh', 'dyad', 'dyal', 'dyalg', 'dyalm', 'dyalb', # 0x20
+'dyals', 'dyalt', 'dyalp', 'dyalh', 'dyam', 'dyab', 'dyabs', 'dyas', # 0x28
+'dyass', 'dyang', 'dyaj', 'dyac', 'dyak', 'dyat', 'dyap', 'dyah', # 0x30
+'dyae', 'dyaeg', 'dyaegg', 'dyaegs', 'dyaen', 'dyaenj', 'dyaenh', 'dyaed', # 0x38
+'dyael', 'dyaelg', 'dyaelm', 'dyaelb', 'dyaels', 'dyaelt', 'dyaelp', 'dyaelh', # 0x40
+'dyaem', 'dyaeb', 'dyaebs', 'dyaes', 'dyaess', 'dyaeng', 'dyaej', 'dyaec', # 0x48
+'dyaek', 'dyaet', 'dyaep', 'dyaeh', 'deo', 'deog', 'deogg', 'deogs', # 0x50
+'deon', 'deonj', 'deonh', 'deod', 'deol', 'deolg', 'deolm', 'deolb', # 0x58
+'deols', 'deolt', 'deolp', 'deolh', 'deom', 'deob', 'deobs', 'deos', # 0x60
+'deoss', 'deong', 'deoj', 'deoc', 'deok', 'deot', 'deop', 'deoh', # 0x68
+'de', 'deg', 'degg', 'degs', 'den', 'denj', 'denh', 'ded', # 0x70
+'del', 'delg', 'delm', 'delb', 'dels', 'delt', 'delp', 'delh', # 0x78
+'dem', 'deb', 'debs', 'des', 'dess', 'deng', 'dej', 'dec', # 0x80
+'dek', 'det', 'dep', 'deh', 'dyeo', 'dyeog', 'dyeogg', 'dyeogs', # 0x88
+'dyeon', 'dyeonj', 'dyeonh', 'dyeod', 'dyeol', 'dyeolg', 'dyeolm', 'dyeolb', # 0x90
+'dyeols', 'dyeolt', 'dyeolp', 'dyeolh', 'dyeom', 'dyeob', 'dyeobs', 'dyeos', # 0x98
+'dyeoss', 'dyeong', 'dyeoj', 'dyeoc', 'dyeok', 'dyeot', 'dyeop', 'dyeoh', # 0xA0
+'dye', 'dyeg', 'dyegg', 'dyegs', 'dyen', 'dyenj', 'dyenh', 'dyed', # 0xA8
+'dyel', 'dyelg', 'dyelm', 'dyelb', 'dyels', 'dyelt', 'dyelp', 'dyelh', # 0xB0
+'dyem', 'dyeb', 'dyebs', 'dyes', 'dyess', 'dyeng', 'dyej', 'dyec', # 0xB8
+'dyek', 'dyet', 'dyep', 'dyeh', 'do', 'dog', 'dogg', 'dogs', # 0xC0
+'don', 'donj', 'donh', 'dod', 'dol', 'dolg', 'dolm', 'dolb', # 0xC8
+'dols', 'dolt', 'dolp', 'dolh', 'dom', 'dob', 'dobs', 'dos', # 0xD0
+'doss', 'dong', 'doj', 'doc', 'dok', 'dot', 'dop', 'doh', # 0xD8
+'dwa', 'dwag', 'dwagg', 'dwags', 'dwan', 'dwanj', 'dwanh', 'dwad', # 0xE0
+'dwal', 'dwalg', 'dwalm', 'dwalb', 'dwals', 'dwalt', 'dwalp', 'dwalh', # 0xE8
+'dwam', 'dwab', 'dwabs', 'dwas', 'dwass', 'dwang', 'dwaj', 'dwac', # 0xF0
+'dwak', 'dwat', 'dwap', 'dwah', 'dwae', 'dwaeg', 'dwaegg', 'dwaegs', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb4] = [
+'dwaen', 'dwaenj', 'dwaenh', 'dwaed', 'dwael', 'dwaelg', 'dwaelm', 'dwaelb', # 0x00
+'dwaels', 'dwaelt', 'dwaelp', 'dwaelh', 'dwaem', 'dwaeb', 'dwaebs', 'dwaes', # 0x08
+'dwaess', 'dwaeng', 'dwaej', 'dwaec', 'dwaek', 'dwaet', 'dwaep', 'dwaeh', # 0x10
+'doe', 'doeg', 'doegg', 'doegs', 'doen', 'doenj', 'doenh', 'doed', # 0x18
+'doel', 'doelg', 'doelm', 'doelb', 'doels', 'doelt', 'doelp', 'doelh', # 0x20
+'doem', 'doeb', 'doebs', 'does', 'doess', 'doeng', 'doej', 'doec', # 0x28
+'doek', 'doet', 'doep', 'doeh', 'dyo', 'dyog', 'dyogg', 'dyogs', # 0x30
+'dyon', 'dyonj', 'dyonh', 'dyod', 'dyol', 'dyolg', 'dyolm', 'dyolb', # 0x38
+'dyols', 'dyolt', 'dyolp', 'dyolh', 'dyom', 'dyob', 'dyobs', 'dyos', # 0x40
+'dyoss', 'dyong', 'dyoj', 'dyoc', 'dyok', 'dyot', 'dyop', 'dyoh', # 0x48
+'du', 'dug', 'dugg', 'dugs', 'dun', 'dunj', 'dunh', 'dud', # 0x50
+'dul', 'dulg', 'dulm', 'dulb', 'duls', 'dult', 'dulp', 'dulh', # 0x58
+'dum', 'dub', 'dubs', 'dus', 'duss', 'dung', 'duj', 'duc', # 0x60
+'duk', 'dut', 'dup', 'duh', 'dweo', 'dweog', 'dweogg', 'dweogs', # 0x68
+'dweon', 'dweonj', 'dweonh', 'dweod', 'dweol', 'dweolg', 'dweolm', 'dweolb', # 0x70
+'dweols', 'dweolt', 'dweolp', 'dweolh', 'dweom', 'dweob', 'dweobs', 'dweos', # 0x78
+'dweoss', 'dweong', 'dweoj', 'dweoc', 'dweok', 'dweot', 'dweop', 'dweoh', # 0x80
+'dwe', 'dweg', 'dwegg', 'dwegs', 'dwen', 'dwenj', 'dwenh', 'dwed', # 0x88
+'dwel', 'dwelg', 'dwelm', 'dwelb', 'dwels', 'dwelt', 'dwelp', 'dwelh', # 0x90
+'dwem', 'dweb', 'dwebs', 'dwes', 'dwess', 'dweng', 'dwej', 'dwec', # 0x98
+'dwek', 'dwet', 'dwep', 'dweh', 'dwi', 'dwig', 'dwigg', 'dwigs', # 0xA0
+'dwin', 'dwinj', 'dwinh', 'dwid', 'dwil', 'dwilg', 'dwilm', 'dwilb', # 0xA8
+'dwils', 'dwilt', 'dwilp', 'dwilh', 'dwim', 'dwib', 'dwibs', 'dwis', # 0xB0
+'dwiss', 'dwing', 'dwij', 'dwic', 'dwik', 'dwit', 'dwip', 'dwih', # 0xB8
+'dyu', 'dyug', 'dyugg', 'dyugs', 'dyun', 'dyunj', 'dyunh', 'dyud', # 0xC0
+'dyul', 'dyulg', 'dyulm', 'dyulb', 'dyuls', 'dyult', 'dyulp', 'dyulh', # 0xC8
+'dyum', 'dyub', 'dyubs', 'dyus', 'dyuss', 'dyung', 'dyuj', 'dyuc', # 0xD0
+'dyuk', 'dyut', 'dyup', 'dyuh', 'deu', 'deug', 'deugg', 'deugs', # 0xD8
+'deun', 'deunj', 'deunh', 'deud', 'deul', 'deulg', 'deulm', 'deulb', # 0xE0
+'deuls', 'deult', 'deulp', 'deulh', 'deum', 'deub', 'deubs', 'deus', # 0xE8
+'deuss', 'deung', 'deuj', 'deuc', 'deuk', 'deut', 'deup', 'deuh', # 0xF0
+'dyi', 'dyig', 'dyigg', 'dyigs', 'dyin', 'dyinj', 'dyinh', 'dyid', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb7] = [
+'ddwim', 'ddwib', 'ddwibs', 'ddwis', 'ddwiss', 'ddwing', 'ddwij', 'ddwic', # 0x00
+'ddwik', 'ddwit', 'ddwip', 'ddwih', 'ddyu', 'ddyug', 'ddyugg', 'ddyugs', # 0x08
+'ddyun', 'ddyunj', 'ddyunh', 'ddyud', 'ddyul', 'ddyulg', 'ddyulm', 'ddyulb', # 0x10
+'ddyuls', 'ddyult', 'ddyulp', 'ddyulh', 'ddyum', 'ddyub', 'ddyubs', 'ddyus', # 0x18
+'ddyuss', 'ddyung', 'ddyuj', 'ddyuc', 'ddyuk', 'ddyut', 'ddyup', 'ddyuh', # 0x20
+'ddeu', 'ddeug', 'ddeugg', 'ddeugs', 'ddeun', 'ddeunj', 'ddeunh', 'ddeud', # 0x28
+'ddeul', 'ddeulg', 'ddeulm', 'ddeulb', 'ddeuls', 'ddeult', 'ddeulp', 'ddeulh', # 0x30
+'ddeum', 'ddeub', 'ddeubs', 'ddeus', 'ddeuss', 'ddeung', 'ddeuj', 'ddeuc', # 0x38
+'ddeuk', 'ddeut', 'ddeup', 'ddeuh', 'ddyi', 'ddyig', 'ddyigg', 'ddyigs', # 0x40
+'ddyin', 'ddyinj', 'ddyinh', 'ddyid', 'ddyil', 'ddyilg', 'ddyilm', 'ddyilb', # 0x48
+'ddyils', 'ddyilt', 'ddyilp', 'ddyilh', 'ddyim', 'ddyib', 'ddyibs', 'ddyis', # 0x50
+'ddyiss', 'ddying', 'ddyij', 'ddyic', 'ddyik', 'ddyit', 'ddyip', 'ddyih', # 0x58
+'ddi', 'ddig', 'ddigg', 'ddigs', 'ddin', 'ddinj', 'ddinh', 'ddid', # 0x60
+'ddil', 'ddilg', 'ddilm', 'ddilb', 'ddils', 'ddilt', 'ddilp', 'ddilh', # 0x68
+'ddim', 'ddib', 'ddibs', 'ddis', 'ddiss', 'dding', 'ddij', 'ddic', # 0x70
+'ddik', 'ddit', 'ddip', 'ddih', 'ra', 'rag', 'ragg', 'rags', # 0x78
+'ran', 'ranj', 'ranh', 'rad', 'ral', 'ralg', 'ralm', 'ralb', # 0x80
+'rals', 'ralt', 'ralp', 'ralh', 'ram', 'rab', 'rabs', 'ras', # 0x88
+'rass', 'rang', 'raj', 'rac', 'rak', 'rat', 'rap', 'rah', # 0x90
+'rae', 'raeg', 'raegg', 'raegs', 'raen', 'raenj', 'raenh', 'raed', # 0x98
+'rael', 'raelg', 'raelm', 'raelb', 'raels', 'raelt', 'raelp', 'raelh', # 0xA0
+'raem', 'raeb', 'raebs', 'raes', 'raess', 'raeng', 'raej', 'raec', # 0xA8
+'raek', 'raet', 'raep', 'raeh', 'rya', 'ryag', 'ryagg', 'ryags', # 0xB0
+'ryan', 'ryanj', 'ryanh', 'ryad', 'ryal', 'ryalg', 'ryalm', 'ryalb', # 0xB8
+'ryals', 'ryalt', 'ryalp', 'ryalh', 'ryam', 'ryab', 'ryabs', 'ryas', # 0xC0
+'ryass', 'ryang', 'ryaj', 'ryac', 'ryak', 'ryat', 'ryap', 'ryah', # 0xC8
+'ryae', 'ryaeg', 'ryaegg', 'ryaegs', 'ryaen', 'ryaenj', 'ryaenh', 'ryaed', # 0xD0
+'ryael', 'ryaelg', 'ryaelm', 'ryaelb', 'ryaels', 'ryaelt', 'ryaelp', 'ryaelh', # 0xD8
+'ryaem', 'ryaeb', 'ryaebs', 'ryaes', 'ryaess', 'ryaeng', 'ryaej', 'ryaec', # 0xE0
+'ryaek', 'ryaet', 'ryaep', 'ryaeh', 'reo', 'reog', 'reogg', 'reogs', # 0xE8
+'reon', 'reonj', 'reonh', 'reod', 'reol', 'reolg', 'reolm', 'reolb', # 0xF0
+'reols', 'reolt', 'reolp', 'reolh', 'reom', 'reob', 'reobs', 'reos', # 0xF8
+];
+1;
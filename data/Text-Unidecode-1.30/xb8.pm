@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb8] = [
+'reoss', 'reong', 'reoj', 'reoc', 'reok', 'reot', 'reop', 'reoh', # 0x00
+'re', 'reg', 'regg', 'regs', 'ren', 'renj', 'renh', 'red', # 0x08
+'rel', 'relg', 'relm', 'relb', 'rels', 'relt', 'relp', 'relh', # 0x10
+'rem', 'reb', 'rebs', 'res', 'ress', 'reng', 'rej', 'rec', # 0x18
+'rek', 'ret', 'rep', 'reh', 'ryeo', 'ryeog', 'ryeogg', 'ryeogs', # 0x20
+'ryeon', 'ryeonj', 'ryeonh', 'ryeod', 'ryeol', 'ryeolg', 'ryeolm', 'ryeolb', # 0x28
+'ryeols', 'ryeolt', 'ryeolp', 'ryeolh', 'ryeom', 'ryeob', 'ryeobs', 'ryeos', # 0x30
+'ryeoss', 'ryeong', 'ryeoj', 'ryeoc', 'ryeok', 'ryeot', 'ryeop', 'ryeoh', # 0x38
+'rye', 'ryeg', 'ryegg', 'ryegs', 'ryen', 'ryenj', 'ryenh', 'ryed', # 0x40
+'ryel', 'ryelg', 'ryelm', 'ryelb', 'ryels', 'ryelt', 'ryelp', 'ryelh', # 0x48
+'ryem', 'ryeb', 'ryebs', 'ryes', 'ryess', 'ryeng', 'ryej', 'ryec', # 0x50
+'ryek', 'ryet', 'ryep', 'ryeh', 'ro', 'rog', 'rogg', 'rogs', # 0x58
+'ron', 'ronj', 'ronh', 'rod', 'rol', 'rolg', 'rolm', 'rolb', # 0x60
+'rols', 'rolt', 'rolp', 'rolh', 'rom', 'rob', 'robs', 'ros', # 0x68
+'ross', 'rong', 'roj', 'roc', 'rok', 'rot', 'rop', 'roh', # 0x70
+'rwa', 'rwag', 'rwagg', 'rwags', 'rwan', 'rwanj', 'rwanh', 'rwad', # 0x78
+'rwal', 'rwalg', 'rwalm', 'rwalb', 'rwals', 'rwalt', 'rwalp', 'rwalh', # 0x80
+'rwam', 'rwab', 'rwabs', 'rwas', 'rwass', 'rwang', 'rwaj', 'rwac', # 0x88
+'rwak', 'rwat', 'rwap', 'rwah', 'rwae', 'rwaeg', 'rwaegg', 'rwaegs', # 0x90
+'rwaen', 'rwaenj', 'rwaenh', 'rwaed', 'rwael', 'rwaelg', 'rwaelm', 'rwaelb', # 0x98
+'rwaels', 'rwaelt', 'rwaelp', 'rwaelh', 'rwaem', 'rwaeb', 'rwaebs', 'rwaes', # 0xA0
+'rwaess', 'rwaeng', 'rwaej', 'rwaec', 'rwaek', 'rwaet', 'rwaep', 'rwaeh', # 0xA8
+'roe', 'roeg', 'roegg', 'roegs', 'roen', 'roenj', 'roenh', 'roed', # 0xB0
+'roel', 'roelg', 'roelm', 'roelb', 'roels', 'roelt', 'roelp', 'roelh', # 0xB8
+'roem', 'roeb', 'roebs', 'roes', 'roess', 'roeng', 'roej', 'roec', # 0xC0
+'roek', 'roet', 'roep', 'roeh', 'ryo', 'ryog', 'ryogg', 'ryogs', # 0xC8
+'ryon', 'ryonj', 'ryonh', 'ryod', 'ryol', 'ryolg', 'ryolm', 'ryolb', # 0xD0
+'ryols', 'ryolt', 'ryolp', 'ryolh', 'ryom', 'ryob', 'ryobs', 'ryos', # 0xD8
+'ryoss', 'ryong', 'ryoj', 'ryoc', 'ryok', 'ryot', 'ryop', 'ryoh', # 0xE0
+'ru', 'rug', 'rugg', 'rugs', 'run', 'runj', 'runh', 'rud', # 0xE8
+'rul', 'rulg', 'rulm', 'rulb', 'ruls', 'rult', 'rulp', 'rulh', # 0xF0
+'rum', 'rub', 'rubs', 'rus', 'russ', 'rung', 'ruj', 'ruc', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xb9] = [
+'ruk', 'rut', 'rup', 'ruh', 'rweo', 'rweog', 'rweogg', 'rweogs', # 0x00
+'rweon', 'rweonj', 'rweonh', 'rweod', 'rweol', 'rweolg', 'rweolm', 'rweolb', # 0x08
+'rweols', 'rweolt', 'rweolp', 'rweolh', 'rweom', 'rweob', 'rweobs', 'rweos', # 0x10
+'rweoss', 'rweong', 'rweoj', 'rweoc', 'rweok', 'rweot', 'rweop', 'rweoh', # 0x18
+'rwe', 'rweg', 'rwegg', 'rwegs', 'rwen', 'rwenj', 'rwenh', 'rwed', # 0x20
+'rwel', 'rwelg', 'rwelm', 'rwelb', 'rwels', 'rwelt', 'rwelp', 'rwelh', # 0x28
+'rwem', 'rweb', 'rwebs', 'rwes', 'rwess', 'rweng', 'rwej', 'rwec', # 0x30
+'rwek', 'rwet', 'rwep', 'rweh', 'rwi', 'rwig', 'rwigg', 'rwigs', # 0x38
+'rwin', 'rwinj', 'rwinh', 'rwid', 'rwil', 'rwilg', 'rwilm', 'rwilb', # 0x40
+'rwils', 'rwilt', 'rwilp', 'rwilh', 'rwim', 'rwib', 'rwibs', 'rwis', # 0x48
+'rwiss', 'rwing', 'rwij', 'rwic', 'rwik', 'rwit', 'rwip', 'rwih', # 0x50
+'ryu', 'ryug', 'ryugg', 'ryugs', 'ryun', 'ryunj', 'ryunh', 'ryud', # 0x58
+'ryul', 'ryulg', 'ryulm', 'ryulb', 'ryuls', 'ryult', 'ryulp', 'ryulh', # 0x60
+'ryum', 'ryub', 'ryubs', 'ryus', 'ryuss', 'ryung', 'ryuj', 'ryuc', # 0x68
+'ryuk', 'ryut', 'ryup', 'ryuh', 'reu', 'reug', 'reugg', 'reugs', # 0x70
+'reun', 'reunj', 'reunh', 'reud', 'reul', 'reulg', 'reulm', 'reulb', # 0x78
+'reuls', 'reult', 'reulp', 'reulh', 'reum', 'reub', 'reubs', 'reus', # 0x80
+'reuss', 'reung', 'reuj', 'reuc', 'reuk', 'reut', 'reup', 'reuh', # 0x88
+'ryi', 'ryig', 'ryigg', 'ryigs', 'ryin', 'ryinj', 'ryinh', 'ryid', # 0x90
+'ryil', 'ryilg', 'ryilm', 'ryilb', 'ryils', 'ryilt', 'ryilp', 'ryilh', # 0x98
+'ryim', 'ryib', 'ryibs', 'ryis', 'ryiss', 'rying', 'ryij', 'ryic', # 0xA0
+'ryik', 'ryit', 'ryip', 'ryih', 'ri', 'rig', 'rigg', 'rigs', # 0xA8
+'rin', 'rinj', 'rinh', 'rid', 'ril', 'rilg', 'rilm', 'rilb', # 0xB0
+'rils', 'rilt', 'rilp', 'rilh', 'rim', 'rib', 'ribs', 'ris', # 0xB8
+'riss', 'ring', 'rij', 'ric', 'rik', 'rit', 'rip', 'rih', # 0xC0
+'ma', 'mag', 'magg', 'mags', 'man', 'manj', 'manh', 'mad', # 0xC8
+'mal', 'malg', 'malm', 'malb', 'mals', 'malt', 'malp', 'malh', # 0xD0
+'mam', 'mab', 'mabs', 'mas', 'mass', 'mang', 'maj', 'mac', # 0xD8
+'mak', 'mat', 'map', 'mah', 'mae', 'maeg', 'maegg', 'maegs', # 0xE0
+'maen', 'maenj', 'maenh', 'maed', 'mael', 'maelg', 'maelm', 'maelb', # 0xE8
+'maels', 'maelt', 'maelp', 'maelh', 'maem', 'maeb', 'maebs', 'maes', # 0xF0
+'maess', 'maeng', 'maej', 'maec', 'maek', 'maet', 'maep', 'maeh', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xba] = [
+'mya', 'myag', 'myagg', 'myags', 'myan', 'myanj', 'myanh', 'myad', # 0x00
+'myal', 'myalg', 'myalm', 'myalb', 'myals', 'myalt', 'myalp', 'myalh', # 0x08
+'myam', 'myab', 'myabs', 'myas', 'myass', 'myang', 'myaj', 'myac', # 0x10
+'myak', 'myat', 'myap', 'myah', 'myae', 'myaeg', 'myaegg', 'myaegs', # 0x18
+'myaen', 'myaenj', 'myaenh', 'myaed', 'myael', 'myaelg', 'myaelm', 'myaelb', # 0x20
+'myaels', 'myaelt', 'myaelp', 'myaelh', 'myaem', 'myaeb', 'myaebs', 'myaes', # 0x28
+'myaess', 'myaeng', 'myaej', 'myaec', 'myaek', 'myaet', 'myaep', 'myaeh', # 0x30
+'meo', 'meog', 'meogg', 'meogs', 'meon', 'meonj', 'meonh', 'meod', # 0x38
+'meol', 'meolg', 'meolm', 'meolb', 'meols', 'meolt', 'meolp', 'meolh', # 0x40
+'meom', 'meob', 'meobs', 'meos', 'meoss', 'meong', 'meoj', 'meoc', # 0x48
+'meok', 'meot', 'meop', 'meoh', 'me', 'meg', 'megg', 'megs', # 0x50
+'men', 'menj', 'menh', 'med', 'mel', 'melg', 'melm', 'melb', # 0x58
+'mels', 'melt', 'melp', 'melh', 'mem', 'meb', 'mebs', 'mes', # 0x60
+'mess', 'meng', 'mej', 'mec', 'mek', 'met', 'mep', 'meh', # 0x68
+'myeo', 'myeog', 'myeogg', 'myeogs', 'myeon', 'myeonj', 'myeonh', 'myeod', # 0x70
+'myeol', 'myeolg', 'myeolm', 'myeolb', 'myeols', 'myeolt', 'myeolp', 'myeolh', # 0x78
+'myeom', 'myeob', 'myeobs', 'myeos', 'myeoss', 'myeong', 'myeoj', 'myeoc', # 0x80
+'myeok', 'myeot', 'myeop', 'myeoh', 'mye', 'myeg', 'myegg', 'myegs', # 0x88
+'myen', 'myenj', 'myenh', 'myed', 'myel', 'myelg', 'myelm', 'myelb', # 0x90
+'myels', 'myelt', 'myelp', 'myelh', 'myem', 'myeb', 'myebs', 'myes', # 0x98
+'myess', 'myeng', 'myej', 'myec', 'myek', 'myet', 'myep', 'myeh', # 0xA0
+'mo', 'mog', 'mogg', 'mogs', 'mon', 'monj', 'monh', 'mod', # 0xA8
+'mol', 'molg', 'molm', 'molb', 'mols', 'molt', 'molp', 'molh', # 0xB0
+'mom', 'mob', 'mobs', 'mos', 'moss', 'mong', 'moj', 'moc', # 0xB8
+'mok', 'mot', 'mop', 'moh', 'mwa', 'mwag', 'mwagg', 'mwags', # 0xC0
+'mwan', 'mwanj', 'mwanh', 'mwad', 'mwal', 'mwalg', 'mwalm', 'mwalb', # 0xC8
+'mwals', 'mwalt', 'mwalp', 'mwalh', 'mwam', 'mwab', 'mwabs', 'mwas', # 0xD0
+'mwass', 'mwang', 'mwaj', 'mwac', 'mwak', 'mwat', 'mwap', 'mwah', # 0xD8
+'mwae', 'mwaeg', 'mwaegg', 'mwaegs', 'mwaen', 'mwaenj', 'mwaenh', 'mwaed', # 0xE0
+'mwael', 'mwaelg', 'mwaelm', 'mwaelb', 'mwaels', 'mwaelt', 'mwaelp', 'mwaelh', # 0xE8
+'mwaem', 'mwaeb', 'mwaebs', 'mwaes', 'mwaess', 'mwaeng', 'mwaej', 'mwaec', # 0xF0
+'mwaek', 'mwaet', 'mwaep', 'mwaeh', 'moe', 'moeg', 'moegg', 'moegs', # 0xF8
+];
+1;
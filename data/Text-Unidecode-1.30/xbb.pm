@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xbb] = [
+'moen', 'moenj', 'moenh', 'moed', 'moel', 'moelg', 'moelm', 'moelb', # 0x00
+'moels', 'moelt', 'moelp', 'moelh', 'moem', 'moeb', 'moebs', 'moes', # 0x08
+'moess', 'moeng', 'moej', 'moec', 'moek', 'moet', 'moep', 'moeh', # 0x10
+'myo', 'myog', 'myogg', 'myogs', 'myon', 'myonj', 'myonh', 'myod', # 0x18
+'myol', 'myolg', 'myolm', 'myolb', 'myols', 'myolt', 'myolp', 'myolh', # 0x20
+'myom', 'myob', 'myobs', 'myos', 'myoss', 'myong', 'myoj', 'myoc', # 0x28
+'myok', 'myot', 'myop', 'myoh', 'mu', 'mug', 'mugg', 'mugs', # 0x30
+'mun', 'munj', 'munh', 'mud', 'mul', 'mulg', 'mulm', 'mulb', # 0x38
+'muls', 'mult', 'mulp', 'mulh', 'mum', 'mub', 'mubs', 'mus', # 0x40
+'muss', 'mung', 'muj', 'muc', 'muk', 'mut', 'mup', 'muh', # 0x48
+'mweo', 'mweog', 'mweogg', 'mweogs', 'mweon', 'mweonj', 'mweonh', 'mweod', # 0x50
+'mweol', 'mweolg', 'mweolm', 'mweolb', 'mweols', 'mweolt', 'mweolp', 'mweolh', # 0x58
+'mweom', 'mweob', 'mweobs', 'mweos', 'mweoss', 'mweong', 'mweoj', 'mweoc', # 0x60
+'mweok', 'mweot', 'mweop', 'mweoh', 'mwe', 'mweg', 'mwegg', 'mwegs', # 0x68
+'mwen', 'mwenj', 'mwenh', 'mwed', 'mwel', 'mwelg', 'mwelm', 'mwelb', # 0x70
+'mwels', 'mwelt', 'mwelp', 'mwelh', 'mwem', 'mweb', 'mwebs', 'mwes', # 0x78
+'mwess', 'mweng', 'mwej', 'mwec', 'mwek', 'mwet', 'mwep', 'mweh', # 0x80
+'mwi', 'mwig', 'mwigg', 'mwigs', 'mwin', 'mwinj', 'mwinh', 'mwid', # 0x88
+'mwil', 'mwilg', 'mwilm', 'mwilb', 'mwils', 'mwilt', 'mwilp', 'mwilh', # 0x90
+'mwim', 'mwib', 'mwibs', 'mwis', 'mwiss', 'mwing', 'mwij', 'mwic', # 0x98
+'mwik', 'mwit', 'mwip', 'mwih', 'myu', 'myug', 'myugg', 'myugs', # 0xA0
+'myun', 'myunj', 'myunh', 'myud', 'myul', 'myulg', 'myulm', 'myulb', # 0xA8
+'myuls', 'myult', 'myulp', 'myulh', 'myum', 'myub', 'myubs', 'myus', # 0xB0
+'myuss', 'myung', 'myuj', 'myuc', 'myuk', 'myut', 'myup', 'myuh', # 0xB8
+'meu', 'meug', 'meugg', 'meugs', 'meun', 'meunj', 'meunh', 'meud', # 0xC0
+'meul', 'meulg', 'meulm', 'meulb', 'meuls', 'meult', 'meulp', 'meulh', # 0xC8
+'meum', 'meub', 'meubs', 'meus', 'meuss', 'meung', 'meuj', 'meuc', # 0xD0
+'meuk', 'meut', 'meup', 'meuh', 'myi', 'myig', 'myigg', 'myigs', # 0xD8
+'myin', 'myinj', 'myinh', 'myid', 'myil', 'myilg', 'myilm', 'myilb', # 0xE0
+'myils', 'myilt', 'myilp', 'myilh', 'myim', 'myib', 'myibs', 'myis', # 0xE8
+'myiss', 'mying', 'myij', 'myic', 'myik', 'myit', 'myip', 'myih', # 0xF0
+'mi', 'mig', 'migg', 'migs', 'min', 'minj', 'minh', 'mid', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xbc] = [
+'mil', 'milg', 'milm', 'milb', 'mils', 'milt', 'milp', 'milh', # 0x00
+'mim', 'mib', 'mibs', 'mis', 'miss', 'ming', 'mij', 'mic', # 0x08
+'mik', 'mit', 'mip', 'mih', 'ba', 'bag', 'bagg', 'bags', # 0x10
+'ban', 'banj', 'banh', 'bad', 'bal', 'balg', 'balm', 'balb', # 0x18
+'bals', 'balt', 'balp', 'balh', 'bam', 'bab', 'babs', 'bas', # 0x20
+'bass', 'bang', 'baj', 'bac', 'bak', 'bat', 'bap', 'bah', # 0x28
+'bae', 'baeg', 'baegg', 'baegs', 'baen', 'baenj', 'baenh', 'baed', # 0x30
+'bael', 'baelg', 'baelm', 'baelb', 'baels', 'baelt', 'baelp', 'baelh', # 0x38
+'baem', 'baeb', 'baebs', 'baes', 'baess', 'baeng', 'baej', 'baec', # 0x40
+'baek', 'baet', 'baep', 'baeh', 'bya', 'byag', 'byagg', 'byags', # 0x48
+'byan', 'byanj', 'byanh', 'byad', 'byal', 'byalg', 'byalm', 'byalb', # 0x50
+'byals', 'byalt', 'byalp', 'byalh', 'byam', 'byab', 'byabs', 'byas', # 0x58
+'byass', 'byang', 'byaj', 'byac', 'byak', 'byat', 'byap', 'byah', # 0x60
+'byae', 'byaeg', 'byaegg', 'byaegs', 'byaen', 'byaenj', 'byaenh', 'byaed', # 0x68
+'byael', 'byaelg', 'byaelm', 'byaelb', 'byaels', 'byaelt', 'byaelp', 'byaelh', # 0x70
+'byaem', 'byaeb', 'byaebs', 'byaes', 'byaess', 'byaeng', 'byaej', 'byaec', # 0x78
+'byaek', 'byaet', 'byaep', 'byaeh', 'beo', 'beog', 'beogg', 'beogs', # 0x80
+'beon', 'beonj', 'beonh', 'beod', 'beol', 'beolg', 'beolm', 'beolb', # 0x88
+'beols', 'beolt', 'beolp', 'beolh', 'beom', 'beob', 'beobs', 'beos', # 0x90
+'beoss', 'beong', 'beoj', 'beoc', 'beok', 'beot', 'beop', 'beoh', # 0x98
+'be', 'beg', 'begg', 'begs', 'ben', 'benj', 'benh', 'bed', # 0xA0
+'bel', 'belg', 'belm', 'belb', 'bels', 'belt', 'belp', 'belh', # 0xA8
+'bem', 'beb', 'bebs', 'bes', 'bess', 'beng', 'bej', 'bec', # 0xB0
+'bek', 'bet', 'bep', 'beh', 'byeo', 'byeog', 'byeogg', 'byeogs', # 0xB8
+'byeon', 'byeonj', 'byeonh', 'byeod', 'byeol', 'byeolg', 'byeolm', 'byeolb', # 0xC0
+'byeols', 'byeolt', 'byeolp', 'byeolh', 'byeom', 'byeob', 'byeobs', 'byeos', # 0xC8
+'byeoss', 'byeong', 'byeoj', 'byeoc', 'byeok', 'byeot', 'byeop', 'byeoh', # 0xD0
+'bye', 'byeg', 'byegg', 'byegs', 'byen', 'byenj', 'byenh', 'byed', # 0xD8
+'byel', 'byelg', 'byelm', 'byelb', 'byels', 'byelt', 'byelp', 'byelh', # 0xE0
+'byem', 'byeb', 'byebs', 'byes', 'byess', 'byeng', 'byej', 'byec', # 0xE8
+'byek', 'byet', 'byep', 'byeh', 'bo', 'bog', 'bogg', 'bogs', # 0xF0
+'bon', 'bonj', 'bonh', 'bod', 'bol', 'bolg', 'bolm', 'bolb', # 0xF8
+];
+1;
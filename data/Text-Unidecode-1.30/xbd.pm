@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xbd] = [
+'bols', 'bolt', 'bolp', 'bolh', 'bom', 'bob', 'bobs', 'bos', # 0x00
+'boss', 'bong', 'boj', 'boc', 'bok', 'bot', 'bop', 'boh', # 0x08
+'bwa', 'bwag', 'bwagg', 'bwags', 'bwan', 'bwanj', 'bwanh', 'bwad', # 0x10
+'bwal', 'bwalg', 'bwalm', 'bwalb', 'bwals', 'bwalt', 'bwalp', 'bwalh', # 0x18
+'bwam', 'bwab', 'bwabs', 'bwas', 'bwass', 'bwang', 'bwaj', 'bwac', # 0x20
+'bwak', 'bwat', 'bwap', 'bwah', 'bwae', 'bwaeg', 'bwaegg', 'bwaegs', # 0x28
+'bwaen', 'bwaenj', 'bwaenh', 'bwaed', 'bwael', 'bwaelg', 'bwaelm', 'bwaelb', # 0x30
+'bwaels', 'bwaelt', 'bwaelp', 'bwaelh', 'bwaem', 'bwaeb', 'bwaebs', 'bwaes', # 0x38
+'bwaess', 'bwaeng', 'bwaej', 'bwaec', 'bwaek', 'bwaet', 'bwaep', 'bwaeh', # 0x40
+'boe', 'boeg', 'boegg', 'boegs', 'boen', 'boenj', 'boenh', 'boed', # 0x48
+'boel', 'boelg', 'boelm', 'boelb', 'boels', 'boelt', 'boelp', 'boelh', # 0x50
+'boem', 'boeb', 'boebs', 'boes', 'boess', 'boeng', 'boej', 'boec', # 0x58
+'boek', 'boet', 'boep', 'boeh', 'byo', 'byog', 'byogg', 'byogs', # 0x60
+'byon', 'byonj', 'byonh', 'byod', 'byol', 'byolg', 'byolm', 'byolb', # 0x68
+'byols', 'byolt', 'byolp', 'byolh', 'byom', 'byob', 'byobs', 'byos', # 0x70
+'byoss', 'byong', 'byoj', 'byoc', 'byok', 'byot', 'byop', 'byoh', # 0x78
+'bu', 'bug', 'bugg', 'bugs', 'bun', 'bunj', 'bunh', 'bud', # 0x80
+'bul', 'bulg', 'bulm', 'bulb', 'buls', 'bult', 'bulp', 'bulh', # 0x88
+'bum', 'bub', 'bubs', 'bus', 'buss', 'bung', 'buj', 'buc', # 0x90
+'buk', 'but', 'bup', 'buh', 'bweo', 'bweog', 'bweogg', 'bweogs', # 0x98
+'bweon', 'bweonj', 'bweonh', 'bweod', 'bweol', 'bweolg', 'bweolm', 'bweolb', # 0xA0
+'bweols', 'bweolt', 'bweolp', 'bweolh', 'bweom', 'bweob', 'bweobs', 'bweos', # 0xA8
+'bweoss', 'bweong', 'bweoj', 'bweoc', 'bweok', 'bweot', 'bweop', 'bweoh', # 0xB0
+'bwe', 'bweg', 'bwegg', 'bwegs', 'bwen', 'bwenj', 'bwenh', 'bwed', # 0xB8
+'bwel', 'bwelg', 'bwelm', 'bwelb', 'bwels', 'bwelt', 'bwelp', 'bwelh', # 0xC0
+'bwem', 'bweb', 'bwebs', 'bwes', 'bwess', 'bweng', 'bwej', 'bwec', # 0xC8
+'bwek', 'bwet', 'bwep', 'bweh', 'bwi', 'bwig', 'bwigg', 'bwigs', # 0xD0
+'bwin', 'bwinj', 'bwinh', 'bwid', 'bwil', 'bwilg', 'bwilm', 'bwilb', # 0xD8
+'bwils', 'bwilt', 'bwilp', 'bwilh', 'bwim', 'bwib', 'bwibs', 'bwis', # 0xE0
+'bwiss', 'bwing', 'bwij', 'bwic', 'bwik', 'bwit', 'bwip', 'bwih', # 0xE8
+'byu', 'byug', 'byugg', 'byugs', 'byun', 'byunj', 'byunh', 'byud', # 0xF0
+'byul', 'byulg', 'byulm', 'byulb', 'byuls', 'byult', 'byulp', 'byulh', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xbe] = [
+'byum', 'byub', 'byubs', 'byus', 'byuss', 'byung', 'byuj', 'byuc', # 0x00
+'byuk', 'byut', 'byup', 'byuh', 'beu', 'beug', 'beugg', 'beugs', # 0x08
+'beun', 'beunj', 'beunh', 'beud', 'beul', 'beulg', 'beulm', 'beulb', # 0x10
+'beuls', 'beult', 'beulp', 'beulh', 'beum', 'beub', 'beubs', 'beus', # 0x18
+'beuss', 'beung', 'beuj', 'beuc', 'beuk', 'beut', 'beup', 'beuh', # 0x20
+'byi', 'byig', 'byigg', 'byigs', 'byin', 'byinj', 'byinh', 'byid', # 0x28
+'byil', 'byilg', 'byilm', 'byilb', 'byils', 'byilt', 'byilp', 'byilh', # 0x30
+'byim', 'byib', 'byibs', 'byis', 'byiss', 'bying', 'byij', 'byic', # 0x38
+'byik', 'byit', 'byip', 'byih', 'bi', 'big', 'bigg', 'bigs', # 0x40
+'bin', 'binj', 'binh', 'bid', 'bil', 'bilg', 'bilm', 'bilb', # 0x48
+'bils', 'bilt', 'bilp', 'bilh', 'bim', 'bib', 'bibs', 'bis', # 0x50
+'biss', 'bing', 'bij', 'bic', 'bik', 'bit', 'bip', 'bih', # 0x58
+'bba', 'bbag', 'bbagg', 'bbags', 'bban', 'bbanj', 'bbanh', 'bbad', # 0x60
+'bbal', 'bbalg', 'bbalm', 'bbalb', 'bbals', 'bbalt', 'bbalp', 'bbalh', # 0x68
+'bbam', 'bbab', 'bbabs', 'bbas', 'bbass', 'bbang', 'bbaj', 'bbac', # 0x70
+'bbak', 'bbat', 'bbap', 'bbah', 'bbae', 'bbaeg', 'bbaegg', 'bbaegs', # 0x78
+'bbaen', 'bbaenj', 'bbaenh', 'bbaed', 'bbael', 'bbaelg', 'bbaelm', 'bbaelb', # 0x80
+'bbaels', 'bbaelt', 'bbaelp', 'bbaelh', 'bbaem', 'bbaeb', 'bbaebs', 'bbaes', # 0x88
+'bbaess', 'bbaeng', 'bbaej', 'bbaec', 'bbaek', 'bbaet', 'bbaep', 'bbaeh', # 0x90
+'bbya', 'bbyag', 'bbyagg', 'bbyags', 'bbyan', 'bbyanj', 'bbyanh', 'bbyad', # 0x98
+'bbyal', 'bbyalg', 'bbyalm', 'bbyalb', 'bbyals', 'bbyalt', 'bbyalp', 'bbyalh', # 0xA0
+'bbyam', 'bbyab', 'bbyabs', 'bbyas', 'bbyass', 'bbyang', 'bbyaj', 'bbyac', # 0xA8
+'bbyak', 'bbyat', 'bbyap', 'bbyah', 'bbyae', 'bbyaeg', 'bbyaegg', 'bbyaegs', # 0xB0
+'bbyaen', 'bbyaenj', 'bbyaenh', 'bbyaed', 'bbyael', 'bbyaelg', 'bbyaelm', 'bbyaelb', # 0xB8
+'bbyaels', 'bbyaelt', 'bbyaelp', 'bbyaelh', 'bbyaem', 'bbyaeb', 'bbyaebs', 'bbyaes', # 0xC0
+'bbyaess', 'bbyaeng', 'bbyaej', 'bbyaec', 'bbyaek', 'bbyaet', 'bbyaep', 'bbyaeh', # 0xC8
+'bbeo', 'bbeog', 'bbeogg', 'bbeogs', 'bbeon', 'bbeonj', 'bbeonh', 'bbeod', # 0xD0
+'bbeol', 'bbeolg', 'bbeolm', 'bbeolb', 'bbeols', 'bbeolt', 'bbeolp', 'bbeolh', # 0xD8
+'bbeom', 'bbeob', 'bbeobs', 'bbeos', 'bbeoss', 'bbeong', 'bbeoj', 'bbeoc', # 0xE0
+'bbeok', 'bbeot', 'bbeop', 'bbeoh', 'bbe', 'bbeg', 'bbegg', 'bbegs', # 0xE8
+'bben', 'bbenj', 'bbenh', 'bbed', 'bbel', 'bbelg', 'bbelm', 'bbelb', # 0xF0
+'bbels', 'bbelt', 'bbelp', 'bbelh', 'bbem', 'bbeb', 'bbebs', 'bbes', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc0] = [
+'bbweok', 'bbweot', 'bbweop', 'bbweoh', 'bbwe', 'bbweg', 'bbwegg', 'bbwegs', # 0x00
+'bbwen', 'bbwenj', 'bbwenh', 'bbwed', 'bbwel', 'bbwelg', 'bbwelm', 'bbwelb', # 0x08
+'bbwels', 'bbwelt', 'bbwelp', 'bbwelh', 'bbwem', 'bbweb', 'bbwebs', 'bbwes', # 0x10
+'bbwess', 'bbweng', 'bbwej', 'bbwec', 'bbwek', 'bbwet', 'bbwep', 'bbweh', # 0x18
+'bbwi', 'bbwig', 'bbwigg', 'bbwigs', 'bbwin', 'bbwinj', 'bbwinh', 'bbwid', # 0x20
+'bbwil', 'bbwilg', 'bbwilm', 'bbwilb', 'bbwils', 'bbwilt', 'bbwilp', 'bbwilh', # 0x28
+'bbwim', 'bbwib', 'bbwibs', 'bbwis', 'bbwiss', 'bbwing', 'bbwij', 'bbwic', # 0x30
+'bbwik', 'bbwit', 'bbwip', 'bbwih', 'bbyu', 'bbyug', 'bbyugg', 'bbyugs', # 0x38
+'bbyun', 'bbyunj', 'bbyunh', 'bbyud', 'bbyul', 'bbyulg', 'bbyulm', 'bbyulb', # 0x40
+'bbyuls', 'bbyult', 'bbyulp', 'bbyulh', 'bbyum', 'bbyub', 'bbyubs', 'bbyus', # 0x48
+'bbyuss', 'bbyung', 'bbyuj', 'bbyuc', 'bbyuk', 'bbyut', 'bbyup', 'bbyuh', # 0x50
+'bbeu', 'bbeug', 'bbeugg', 'bbeugs', 'bbeun', 'bbeunj', 'bbeunh', 'bbeud', # 0x58
+'bbeul', 'bbeulg', 'bbeulm', 'bbeulb', 'bbeuls', 'bbeult', 'bbeulp', 'bbeulh', # 0x60
+'bbeum', 'bbeub', 'bbeubs', 'bbeus', 'bbeuss', 'bbeung', 'bbeuj', 'bbeuc', # 0x68
+'bbeuk', 'bbeut', 'bbeup', 'bbeuh', 'bbyi', 'bbyig', 'bbyigg', 'bbyigs', # 0x70
+'bbyin', 'bbyinj', 'bbyinh', 'bbyid', 'bbyil', 'bbyilg', 'bbyilm', 'bbyilb', # 0x78
+'bbyils', 'bbyilt', 'bbyilp', 'bbyilh', 'bbyim', 'bbyib', 'bbyibs', 'bbyis', # 0x80
+'bbyiss', 'bbying', 'bbyij', 'bbyic', 'bbyik', 'bbyit', 'bbyip', 'bbyih', # 0x88
+'bbi', 'bbig', 'bbigg', 'bbigs', 'bbin', 'bbinj', 'bbinh', 'bbid', # 0x90
+'bbil', 'bbilg', 'bbilm', 'bbilb', 'bbils', 'bbilt', 'bbilp', 'bbilh', # 0x98
+'bbim', 'bbib', 'bbibs', 'bbis', 'bbiss', 'bbing', 'bbij', 'bbic', # 0xA0
+'bbik', 'bbit', 'bbip', 'bbih', 'sa', 'sag', 'sagg', 'sags', # 0xA8
+'san', 'sanj', 'sanh', 'sad', 'sal', 'salg', 'salm', 'salb', # 0xB0
+'sals', 'salt', 'salp', 'salh', 'sam', 'sab', 'sabs', 'sas', # 0xB8
+'sass', 'sang', 'saj', 'sac', 'sak', 'sat', 'sap', 'sah', # 0xC0
+'sae', 'saeg', 'saegg', 'saegs', 'saen', 'saenj', 'saenh', 'saed', # 0xC8
+'sael', 'saelg', 'saelm', 'saelb', 'saels', 'saelt', 'saelp', 'saelh', # 0xD0
+'saem', 'saeb', 'saebs', 'saes', 'saess', 'saeng', 'saej', 'saec', # 0xD8
+'saek', 'saet', 'saep', 'saeh', 'sya', 'syag', 'syagg', 'syags', # 0xE0
+'syan', 'syanj', 'syanh', 'syad', 'syal', 'syalg', 'syalm', 'syalb', # 0xE8
+'syals', 'syalt', 'syalp', 'syalh', 'syam', 'syab', 'syabs', 'syas', # 0xF0
+'syass', 'syang', 'syaj', 'syac', 'syak', 'syat', 'syap', 'syah', # 0xF8
+];
+1;
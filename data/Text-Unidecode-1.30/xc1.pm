@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc1] = [
+'syae', 'syaeg', 'syaegg', 'syaegs', 'syaen', 'syaenj', 'syaenh', 'syaed', # 0x00
+'syael', 'syaelg', 'syaelm', 'syaelb', 'syaels', 'syaelt', 'syaelp', 'syaelh', # 0x08
+'syaem', 'syaeb', 'syaebs', 'syaes', 'syaess', 'syaeng', 'syaej', 'syaec', # 0x10
+'syaek', 'syaet', 'syaep', 'syaeh', 'seo', 'seog', 'seogg', 'seogs', # 0x18
+'seon', 'seonj', 'seonh', 'seod', 'seol', 'seolg', 'seolm', 'seolb', # 0x20
+'seols', 'seolt', 'seolp', 'seolh', 'seom', 'seob', 'seobs', 'seos', # 0x28
+'seoss', 'seong', 'seoj', 'seoc', 'seok', 'seot', 'seop', 'seoh', # 0x30
+'se', 'seg', 'segg', 'segs', 'sen', 'senj', 'senh', 'sed', # 0x38
+'sel', 'selg', 'selm', 'selb', 'sels', 'selt', 'selp', 'selh', # 0x40
+'sem', 'seb', 'sebs', 'ses', 'sess', 'seng', 'sej', 'sec', # 0x48
+'sek', 'set', 'sep', 'seh', 'syeo', 'syeog', 'syeogg', 'syeogs', # 0x50
+'syeon', 'syeonj', 'syeonh', 'syeod', 'syeol', 'syeolg', 'syeolm', 'syeolb', # 0x58
+'syeols', 'syeolt', 'syeolp', 'syeolh', 'syeom', 'syeob', 'syeobs', 'syeos', # 0x60
+'syeoss', 'syeong', 'syeoj', 'syeoc', 'syeok', 'syeot', 'syeop', 'syeoh', # 0x68
+'sye', 'syeg', 'syegg', 'syegs', 'syen', 'syenj', 'syenh', 'syed', # 0x70
+'syel', 'syelg', 'syelm', 'syelb', 'syels', 'syelt', 'syelp', 'syelh', # 0x78
+'syem', 'syeb', 'syebs', 'syes', 'syess', 'syeng', 'syej', 'syec', # 0x80
+'syek', 'syet', 'syep', 'syeh', 'so', 'sog', 'sogg', 'sogs', # 0x88
+'son', 'sonj', 'sonh', 'sod', 'sol', 'solg', 'solm', 'solb', # 0x90
+'sols', 'solt', 'solp', 'solh', 'som', 'sob', 'sobs', 'sos', # 0x98
+'soss', 'song', 'soj', 'soc', 'sok', 'sot', 'sop', 'soh', # 0xA0
+'swa', 'swag', 'swagg', 'swags', 'swan', 'swanj', 'swanh', 'swad', # 0xA8
+'swal', 'swalg', 'swalm', 'swalb', 'swals', 'swalt', 'swalp', 'swalh', # 0xB0
+'swam', 'swab', 'swabs', 'swas', 'swass', 'swang', 'swaj', 'swac', # 0xB8
+'swak', 'swat', 'swap', 'swah', 'swae', 'swaeg', 'swaegg', 'swaegs', # 0xC0
+'swaen', 'swaenj', 'swaenh', 'swaed', 'swael', 'swaelg', 'swaelm', 'swaelb', # 0xC8
+'swaels', 'swaelt', 'swaelp', 'swaelh', 'swaem', 'swaeb', 'swaebs', 'swaes', # 0xD0
+'swaess', 'swaeng', 'swaej', 'swaec', 'swaek', 'swaet', 'swaep', 'swaeh', # 0xD8
+'soe', 'soeg', 'soegg', 'soegs', 'soen', 'soenj', 'soenh', 'soed', # 0xE0
+'soel', 'soelg', 'soelm', 'soelb', 'soels', 'soelt', 'soelp', 'soelh', # 0xE8
+'soem', 'soeb', 'soebs', 'soes', 'soess', 'soeng', 'soej', 'soec', # 0xF0
+'soek', 'soet', 'soep', 'soeh', 'syo', 'syog', 'syogg', 'syogs', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc2] = [
+'syon', 'syonj', 'syonh', 'syod', 'syol', 'syolg', 'syolm', 'syolb', # 0x00
+'syols', 'syolt', 'syolp', 'syolh', 'syom', 'syob', 'syobs', 'syos', # 0x08
+'syoss', 'syong', 'syoj', 'syoc', 'syok', 'syot', 'syop', 'syoh', # 0x10
+'su', 'sug', 'sugg', 'sugs', 'sun', 'sunj', 'sunh', 'sud', # 0x18
+'sul', 'sulg', 'sulm', 'sulb', 'suls', 'sult', 'sulp', 'sulh', # 0x20
+'sum', 'sub', 'subs', 'sus', 'suss', 'sung', 'suj', 'suc', # 0x28
+'suk', 'sut', 'sup', 'suh', 'sweo', 'sweog', 'sweogg', 'sweogs', # 0x30
+'sweon', 'sweonj', 'sweonh', 'sweod', 'sweol', 'sweolg', 'sweolm', 'sweolb', # 0x38
+'sweols', 'sweolt', 'sweolp', 'sweolh', 'sweom', 'sweob', 'sweobs', 'sweos', # 0x40
+'sweoss', 'sweong', 'sweoj', 'sweoc', 'sweok', 'sweot', 'sweop', 'sweoh', # 0x48
+'swe', 'sweg', 'swegg', 'swegs', 'swen', 'swenj', 'swenh', 'swed', # 0x50
+'swel', 'swelg', 'swelm', 'swelb', 'swels', 'swelt', 'swelp', 'swelh', # 0x58
+'swem', 'sweb', 'swebs', 'swes', 'swess', 'sweng', 'swej', 'swec', # 0x60
+'swek', 'swet', 'swep', 'sweh', 'swi', 'swig', 'swigg', 'swigs', # 0x68
+'swin', 'swinj', 'swinh', 'swid', 'swil', 'swilg', 'swilm', 'swilb', # 0x70
+'swils', 'swilt', 'swilp', 'swilh', 'swim', 'swib', 'swibs', 'swis', # 0x78
+'swiss', 'swing', 'swij', 'swic', 'swik', 'swit', 'swip', 'swih', # 0x80
+'syu', 'syug', 'syugg', 'syugs', 'syun', 'syunj', 'syunh', 'syud', # 0x88
+'syul', 'syulg', 'syulm', 'syulb', 'syuls', 'syult', 'syulp', 'syulh', # 0x90
+'syum', 'syub', 'syubs', 'syus', 'syuss', 'syung', 'syuj', 'syuc', # 0x98
+'syuk', 'syut', 'syup', 'syuh', 'seu', 'seug', 'seugg', 'seugs', # 0xA0
+'seun', 'seunj', 'seunh', 'seud', 'seul', 'seulg', 'seulm', 'seulb', # 0xA8
+'seuls', 'seult', 'seulp', 'seulh', 'seum', 'seub', 'seubs', 'seus', # 0xB0
+'seuss', 'seung', 'seuj', 'seuc', 'seuk', 'seut', 'seup', 'seuh', # 0xB8
+'syi', 'syig', 'syigg', 'syigs', 'syin', 'syinj', 'syinh', 'syid', # 0xC0
+'syil', 'syilg', 'syilm', 'syilb', 'syils', 'syilt', 'syilp', 'syilh', # 0xC8
+'syim', 'syib', 'syibs', 'syis', 'syiss', 'sying', 'syij', 'syic', # 0xD0
+'syik', 'syit', 'syip', 'syih', 'si', 'sig', 'sigg', 'sigs', # 0xD8
+'sin', 'sinj', 'sinh', 'sid', 'sil', 'silg', 'silm', 'silb', # 0xE0
+'sils', 'silt', 'silp', 'silh', 'sim', 'sib', 'sibs', 'sis', # 0xE8
+'siss', 'sing', 'sij', 'sic', 'sik', 'sit', 'sip', 'sih', # 0xF0
+'ssa', 'ssag', 'ssagg', 'ssags', 'ssan', 'ssanj', 'ssanh', 'ssad', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc5] = [
+'sseum', 'sseub', 'sseubs', 'sseus', 'sseuss', 'sseung', 'sseuj', 'sseuc', # 0x00
+'sseuk', 'sseut', 'sseup', 'sseuh', 'ssyi', 'ssyig', 'ssyigg', 'ssyigs', # 0x08
+'ssyin', 'ssyinj', 'ssyinh', 'ssyid', 'ssyil', 'ssyilg', 'ssyilm', 'ssyilb', # 0x10
+'ssyils', 'ssyilt', 'ssyilp', 'ssyilh', 'ssyim', 'ssyib', 'ssyibs', 'ssyis', # 0x18
+'ssyiss', 'ssying', 'ssyij', 'ssyic', 'ssyik', 'ssyit', 'ssyip', 'ssyih', # 0x20
+'ssi', 'ssig', 'ssigg', 'ssigs', 'ssin', 'ssinj', 'ssinh', 'ssid', # 0x28
+'ssil', 'ssilg', 'ssilm', 'ssilb', 'ssils', 'ssilt', 'ssilp', 'ssilh', # 0x30
+'ssim', 'ssib', 'ssibs', 'ssis', 'ssiss', 'ssing', 'ssij', 'ssic', # 0x38
+'ssik', 'ssit', 'ssip', 'ssih', 'a', 'ag', 'agg', 'ags', # 0x40
+'an', 'anj', 'anh', 'ad', 'al', 'alg', 'alm', 'alb', # 0x48
+'als', 'alt', 'alp', 'alh', 'am', 'ab', 'abs', 'as', # 0x50
+'ass', 'ang', 'aj', 'ac', 'ak', 'at', 'ap', 'ah', # 0x58
+'ae', 'aeg', 'aegg', 'aegs', 'aen', 'aenj', 'aenh', 'aed', # 0x60
+'ael', 'aelg', 'aelm', 'aelb', 'aels', 'aelt', 'aelp', 'aelh', # 0x68
+'aem', 'aeb', 'aebs', 'aes', 'aess', 'aeng', 'aej', 'aec', # 0x70
+'aek', 'aet', 'aep', 'aeh', 'ya', 'yag', 'yagg', 'yags', # 0x78
+'yan', 'yanj', 'yanh', 'yad', 'yal', 'yalg', 'yalm', 'yalb', # 0x80
+'yals', 'yalt', 'yalp', 'yalh', 'yam', 'yab', 'yabs', 'yas', # 0x88
+'yass', 'yang', 'yaj', 'yac', 'yak', 'yat', 'yap', 'yah', # 0x90
+'yae', 'yaeg', 'yaegg', 'yaegs', 'yaen', 'yaenj', 'yaenh', 'yaed', # 0x98
+'yael', 'yaelg', 'yaelm', 'yaelb', 'yaels', 'yaelt', 'yaelp', 'yaelh', # 0xA0
+'yaem', 'yaeb', 'yaebs', 'yaes', 'yaess', 'yaeng', 'yaej', 'yaec', # 0xA8
+'yaek', 'yaet', 'yaep', 'yaeh', 'eo', 'eog', 'eogg', 'eogs', # 0xB0
+'eon', 'eonj', 'eonh', 'eod', 'eol', 'eolg', 'eolm', 'eolb', # 0xB8
+'eols', 'eolt', 'eolp', 'eolh', 'eom', 'eob', 'eobs', 'eos', # 0xC0
+'eoss', 'eong', 'eoj', 'eoc', 'eok', 'eot', 'eop', 'eoh', # 0xC8
+'e', 'eg', 'egg', 'egs', 'en', 'enj', 'enh', 'ed', # 0xD0
+'el', 'elg', 'elm', 'elb', 'els', 'elt', 'elp', 'elh', # 0xD8
+'em', 'eb', 'ebs', 'es', 'ess', 'eng', 'ej', 'ec', # 0xE0
+'ek', 'et', 'ep', 'eh', 'yeo', 'yeog', 'yeogg', 'yeogs', # 0xE8
+'yeon', 'yeonj', 'yeonh', 'yeod', 'yeol', 'yeolg', 'yeolm', 'yeolb', # 0xF0
+'yeols', 'yeolt', 'yeolp', 'yeolh', 'yeom', 'yeob', 'yeobs', 'yeos', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc6] = [
+'yeoss', 'yeong', 'yeoj', 'yeoc', 'yeok', 'yeot', 'yeop', 'yeoh', # 0x00
+'ye', 'yeg', 'yegg', 'yegs', 'yen', 'yenj', 'yenh', 'yed', # 0x08
+'yel', 'yelg', 'yelm', 'yelb', 'yels', 'yelt', 'yelp', 'yelh', # 0x10
+'yem', 'yeb', 'yebs', 'yes', 'yess', 'yeng', 'yej', 'yec', # 0x18
+'yek', 'yet', 'yep', 'yeh', 'o', 'og', 'ogg', 'ogs', # 0x20
+'on', 'onj', 'onh', 'od', 'ol', 'olg', 'olm', 'olb', # 0x28
+'ols', 'olt', 'olp', 'olh', 'om', 'ob', 'obs', 'os', # 0x30
+'oss', 'ong', 'oj', 'oc', 'ok', 'ot', 'op', 'oh', # 0x38
+'wa', 'wag', 'wagg', 'wags', 'wan', 'wanj', 'wanh', 'wad', # 0x40
+'wal', 'walg', 'walm', 'walb', 'wals', 'walt', 'walp', 'walh', # 0x48
+'wam', 'wab', 'wabs', 'was', 'wass', 'wang', 'waj', 'wac', # 0x50
+'wak', 'wat', 'wap', 'wah', 'wae', 'waeg', 'waegg', 'waegs', # 0x58
+'waen', 'waenj', 'waenh', 'waed', 'wael', 'waelg', 'waelm', 'waelb', # 0x60
+'waels', 'waelt', 'waelp', 'waelh', 'waem', 'waeb', 'waebs', 'waes', # 0x68
+'waess', 'waeng', 'waej', 'waec', 'waek', 'waet', 'waep', 'waeh', # 0x70
+'oe', 'oeg', 'oegg', 'oegs', 'oen', 'oenj', 'oenh', 'oed', # 0x78
+'oel', 'oelg', 'oelm', 'oelb', 'oels', 'oelt', 'oelp', 'oelh', # 0x80
+'oem', 'oeb', 'oebs', 'oes', 'oess', 'oeng', 'oej', 'oec', # 0x88
+'oek', 'oet', 'oep', 'oeh', 'yo', 'yog', 'yogg', 'yogs', # 0x90
+'yon', 'yonj', 'yonh', 'yod', 'yol', 'yolg', 'yolm', 'yolb', # 0x98
+'yols', 'yolt', 'yolp', 'yolh', 'yom', 'yob', 'yobs', 'yos', # 0xA0
+'yoss', 'yong', 'yoj', 'yoc', 'yok', 'yot', 'yop', 'yoh', # 0xA8
+'u', 'ug', 'ugg', 'ugs', 'un', 'unj', 'unh', 'ud', # 0xB0
+'ul', 'ulg', 'ulm', 'ulb', 'uls', 'ult', 'ulp', 'ulh', # 0xB8
+'um', 'ub', 'ubs', 'us', 'uss', 'ung', 'uj', 'uc', # 0xC0
+'uk', 'ut', 'up', 'uh', 'weo', 'weog', 'weogg', 'weogs', # 0xC8
+'weon', 'weonj', 'weonh', 'weod', 'weol', 'weolg', 'weolm', 'weolb', # 0xD0
+'weols', 'weolt', 'weolp', 'weolh', 'weom', 'weob', 'weobs', 'weos', # 0xD8
+'weoss', 'weong', 'weoj', 'weoc', 'weok', 'weot', 'weop', 'weoh', # 0xE0
+'we', 'weg', 'wegg', 'wegs', 'wen', 'wenj', 'wenh', 'wed', # 0xE8
+'wel', 'welg', 'welm', 'welb', 'wels', 'welt', 'welp', 'welh', # 0xF0
+'wem', 'web', 'webs', 'wes', 'wess', 'weng', 'wej', 'wec', # 0xF8
+];
+1;
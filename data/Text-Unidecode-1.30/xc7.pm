@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc7] = [
+'wek', 'wet', 'wep', 'weh', 'wi', 'wig', 'wigg', 'wigs', # 0x00
+'win', 'winj', 'winh', 'wid', 'wil', 'wilg', 'wilm', 'wilb', # 0x08
+'wils', 'wilt', 'wilp', 'wilh', 'wim', 'wib', 'wibs', 'wis', # 0x10
+'wiss', 'wing', 'wij', 'wic', 'wik', 'wit', 'wip', 'wih', # 0x18
+'yu', 'yug', 'yugg', 'yugs', 'yun', 'yunj', 'yunh', 'yud', # 0x20
+'yul', 'yulg', 'yulm', 'yulb', 'yuls', 'yult', 'yulp', 'yulh', # 0x28
+'yum', 'yub', 'yubs', 'yus', 'yuss', 'yung', 'yuj', 'yuc', # 0x30
+'yuk', 'yut', 'yup', 'yuh', 'eu', 'eug', 'eugg', 'eugs', # 0x38
+'eun', 'eunj', 'eunh', 'eud', 'eul', 'eulg', 'eulm', 'eulb', # 0x40
+'euls', 'eult', 'eulp', 'eulh', 'eum', 'eub', 'eubs', 'eus', # 0x48
+'euss', 'eung', 'euj', 'euc', 'euk', 'eut', 'eup', 'euh', # 0x50
+'yi', 'yig', 'yigg', 'yigs', 'yin', 'yinj', 'yinh', 'yid', # 0x58
+'yil', 'yilg', 'yilm', 'yilb', 'yils', 'yilt', 'yilp', 'yilh', # 0x60
+'yim', 'yib', 'yibs', 'yis', 'yiss', 'ying', 'yij', 'yic', # 0x68
+'yik', 'yit', 'yip', 'yih', 'i', 'ig', 'igg', 'igs', # 0x70
+'in', 'inj', 'inh', 'id', 'il', 'ilg', 'ilm', 'ilb', # 0x78
+'ils', 'ilt', 'ilp', 'ilh', 'im', 'ib', 'ibs', 'is', # 0x80
+'iss', 'ing', 'ij', 'ic', 'ik', 'it', 'ip', 'ih', # 0x88
+'ja', 'jag', 'jagg', 'jags', 'jan', 'janj', 'janh', 'jad', # 0x90
+'jal', 'jalg', 'jalm', 'jalb', 'jals', 'jalt', 'jalp', 'jalh', # 0x98
+'jam', 'jab', 'jabs', 'jas', 'jass', 'jang', 'jaj', 'jac', # 0xA0
+'jak', 'jat', 'jap', 'jah', 'jae', 'jaeg', 'jaegg', 'jaegs', # 0xA8
+'jaen', 'jaenj', 'jaenh', 'jaed', 'jael', 'jaelg', 'jaelm', 'jaelb', # 0xB0
+'jaels', 'jaelt', 'jaelp', 'jaelh', 'jaem', 'jaeb', 'jaebs', 'jaes', # 0xB8
+'jaess', 'jaeng', 'jaej', 'jaec', 'jaek', 'jaet', 'jaep', 'jaeh', # 0xC0
+'jya', 'jyag', 'jyagg', 'jyags', 'jyan', 'jyanj', 'jyanh', 'jyad', # 0xC8
+'jyal', 'jyalg', 'jyalm', 'jyalb', 'jyals', 'jyalt', 'jyalp', 'jyalh', # 0xD0
+'jyam', 'jyab', 'jyabs', 'jyas', 'jyass', 'jyang', 'jyaj', 'jyac', # 0xD8
+'jyak', 'jyat', 'jyap', 'jyah', 'jyae', 'jyaeg', 'jyaegg', 'jyaegs', # 0xE0
+'jyaen', 'jyaenj', 'jyaenh', 'jyaed', 'jyael', 'jyaelg', 'jyaelm', 'jyaelb', # 0xE8
+'jyaels', 'jyaelt', 'jyaelp', 'jyaelh', 'jyaem', 'jyaeb', 'jyaebs', 'jyaes', # 0xF0
+'jyaess', 'jyaeng', 'jyaej', 'jyaec', 'jyaek', 'jyaet', 'jyaep', 'jyaeh', # 0xF8
+];
+1;
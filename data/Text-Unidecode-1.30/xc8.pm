@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xc8] = [
+'jeo', 'jeog', 'jeogg', 'jeogs', 'jeon', 'jeonj', 'jeonh', 'jeod', # 0x00
+'jeol', 'jeolg', 'jeolm', 'jeolb', 'jeols', 'jeolt', 'jeolp', 'jeolh', # 0x08
+'jeom', 'jeob', 'jeobs', 'jeos', 'jeoss', 'jeong', 'jeoj', 'jeoc', # 0x10
+'jeok', 'jeot', 'jeop', 'jeoh', 'je', 'jeg', 'jegg', 'jegs', # 0x18
+'jen', 'jenj', 'jenh', 'jed', 'jel', 'jelg', 'jelm', 'jelb', # 0x20
+'jels', 'jelt', 'jelp', 'jelh', 'jem', 'jeb', 'jebs', 'jes', # 0x28
+'jess', 'jeng', 'jej', 'jec', 'jek', 'jet', 'jep', 'jeh', # 0x30
+'jyeo', 'jyeog', 'jyeogg', 'jyeogs', 'jyeon', 'jyeonj', 'jyeonh', 'jyeod', # 0x38
+'jyeol', 'jyeolg', 'jyeolm', 'jyeolb', 'jyeols', 'jyeolt', 'jyeolp', 'jyeolh', # 0x40
+'jyeom', 'jyeob', 'jyeobs', 'jyeos', 'jyeoss', 'jyeong', 'jyeoj', 'jyeoc', # 0x48
+'jyeok', 'jyeot', 'jyeop', 'jyeoh', 'jye', 'jyeg', 'jyegg', 'jyegs', # 0x50
+'jyen', 'jyenj', 'jyenh', 'jyed', 'jyel', 'jyelg', 'jyelm', 'jyelb', # 0x58
+'jyels', 'jyelt', 'jyelp', 'jyelh', 'jyem', 'jyeb', 'jyebs', 'jyes', # 0x60
+'jyess', 'jyeng', 'jyej', 'jyec', 'jyek', 'jyet', 'jyep', 'jyeh', # 0x68
+'jo', 'jog', 'jogg', 'jogs', 'jon', 'jonj', 'jonh', 'jod', # 0x70
+'jol', 'jolg', 'jolm', 'jolb', 'jols', 'jolt', 'jolp', 'jolh', # 0x78
+'jom', 'job', 'jobs', 'jos', 'joss', 'jong', 'joj', 'joc', # 0x80
+'jok', 'jot', 'jop', 'joh', 'jwa', 'jwag', 'jwagg', 'jwags', # 0x88
+'jwan', 'jwanj', 'jwanh', 'jwad', 'jwal', 'jwalg', 'jwalm', 'jwalb', # 0x90
+'jwals', 'jwalt', 'jwalp', 'jwalh', 'jwam', 'jwab', 'jwabs', 'jwas', # 0x98
+'jwass', 'jwang', 'jwaj', 'jwac', 'jwak', 'jwat', 'jwap', 'jwah', # 0xA0
+'jwae', 'jwaeg', 'jwaegg', 'jwaegs', 'jwaen', 'jwaenj', 'jwaenh', 'jwaed', # 0xA8
+'jwael', 'jwaelg', 'jwaelm', 'jwaelb', 'jwaels', 'jwaelt', 'jwaelp', 'jwaelh', # 0xB0
+'jwaem', 'jwaeb', 'jwaebs', 'jwaes', 'jwaess', 'jwaeng', 'jwaej', 'jwaec', # 0xB8
+'jwaek', 'jwaet', 'jwaep', 'jwaeh', 'joe', 'joeg', 'joegg', 'joegs', # 0xC0
+'joen', 'joenj', 'joenh', 'joed', 'joel', 'joelg', 'joelm', 'joelb', # 0xC8
+'joels', 'joelt', 'joelp', 'joelh', 'joem', 'joeb', 'joebs', 'joes', # 0xD0
+'joess', 'joeng', 'joej', 'joec', 'joek', 'joet', 'joep', 'joeh', # 0xD8
+'jyo', 'jyog', 'jyogg', 'jyogs', 'jyon', 'jyonj', 'jyonh', 'jyod', # 0xE0
+'jyol', 'jyolg', 'jyolm', 'jyolb', 'jyols', 'jyolt', 'jyolp', 'jyolh', # 0xE8
+'jyom', 'jyob', 'jyobs', 'jyos', 'jyoss', 'jyong', 'jyoj', 'jyoc', # 0xF0
+'jyok', 'jyot', 'jyop', 'jyoh', 'ju', 'jug', 'jugg', 'jugs', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xcc] = [
+'jjyim', 'jjyib', 'jjyibs', 'jjyis', 'jjyiss', 'jjying', 'jjyij', 'jjyic', # 0x00
+'jjyik', 'jjyit', 'jjyip', 'jjyih', 'jji', 'jjig', 'jjigg', 'jjigs', # 0x08
+'jjin', 'jjinj', 'jjinh', 'jjid', 'jjil', 'jjilg', 'jjilm', 'jjilb', # 0x10
+'jjils', 'jjilt', 'jjilp', 'jjilh', 'jjim', 'jjib', 'jjibs', 'jjis', # 0x18
+'jjiss', 'jjing', 'jjij', 'jjic', 'jjik', 'jjit', 'jjip', 'jjih', # 0x20
+'ca', 'cag', 'cagg', 'cags', 'can', 'canj', 'canh', 'cad', # 0x28
+'cal', 'calg', 'calm', 'calb', 'cals', 'calt', 'calp', 'calh', # 0x30
+'cam', 'cab', 'cabs', 'cas', 'cass', 'cang', 'caj', 'cac', # 0x38
+'cak', 'cat', 'cap', 'cah', 'cae', 'caeg', 'caegg', 'caegs', # 0x40
+'caen', 'caenj', 'caenh', 'caed', 'cael', 'caelg', 'caelm', 'caelb', # 0x48
+'caels', 'caelt', 'caelp', 'caelh', 'caem', 'caeb', 'caebs', 'caes', # 0x50
+'caess', 'caeng', 'caej', 'caec', 'caek', 'caet', 'caep', 'caeh', # 0x58
+'cya', 'cyag', 'cyagg', 'cyags', 'cyan', 'cyanj', 'cyanh', 'cyad', # 0x60
+'cyal', 'cyalg', 'cyalm', 'cyalb', 'cyals', 'cyalt', 'cyalp', 'cyalh', # 0x68
+'cyam', 'cyab', 'cyabs', 'cyas', 'cyass', 'cyang', 'cyaj', 'cyac', # 0x70
+'cyak', 'cyat', 'cyap', 'cyah', 'cyae', 'cyaeg', 'cyaegg', 'cyaegs', # 0x78
+'cyaen', 'cyaenj', 'cyaenh', 'cyaed', 'cyael', 'cyaelg', 'cyaelm', 'cyaelb', # 0x80
+'cyaels', 'cyaelt', 'cyaelp', 'cyaelh', 'cyaem', 'cyaeb', 'cyaebs', 'cyaes', # 0x88
+'cyaess', 'cyaeng', 'cyaej', 'cyaec', 'cyaek', 'cyaet', 'cyaep', 'cyaeh', # 0x90
+'ceo', 'ceog', 'ceogg', 'ceogs', 'ceon', 'ceonj', 'ceonh', 'ceod', # 0x98
+'ceol', 'ceolg', 'ceolm', 'ceolb', 'ceols', 'ceolt', 'ceolp', 'ceolh', # 0xA0
+'ceom', 'ceob', 'ceobs', 'ceos', 'ceoss', 'ceong', 'ceoj', 'ceoc', # 0xA8
+'ceok', 'ceot', 'ceop', 'ceoh', 'ce', 'ceg', 'cegg', 'cegs', # 0xB0
+'cen', 'cenj', 'cenh', 'ced', 'cel', 'celg', 'celm', 'celb', # 0xB8
+'cels', 'celt', 'celp', 'celh', 'cem', 'ceb', 'cebs', 'ces', # 0xC0
+'cess', 'ceng', 'cej', 'cec', 'cek', 'cet', 'cep', 'ceh', # 0xC8
+'cyeo', 'cyeog', 'cyeogg', 'cyeogs', 'cyeon', 'cyeonj', 'cyeonh', 'cyeod', # 0xD0
+'cyeol', 'cyeolg', 'cyeolm', 'cyeolb', 'cyeols', 'cyeolt', 'cyeolp', 'cyeolh', # 0xD8
+'cyeom', 'cyeob', 'cyeobs', 'cyeos', 'cyeoss', 'cyeong', 'cyeoj', 'cyeoc', # 0xE0
+'cyeok', 'cyeot', 'cyeop', 'cyeoh', 'cye', 'cyeg', 'cyegg', 'cyegs', # 0xE8
+'cyen', 'cyenj', 'cyenh', 'cyed', 'cyel', 'cyelg', 'cyelm', 'cyelb', # 0xF0
+'cyels', 'cyelt', 'cyelp', 'cyelh', 'cyem', 'cyeb', 'cyebs', 'cyes', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xcd] = [
+'cyess', 'cyeng', 'cyej', 'cyec', 'cyek', 'cyet', 'cyep', 'cyeh', # 0x00
+'co', 'cog', 'cogg', 'cogs', 'con', 'conj', 'conh', 'cod', # 0x08
+'col', 'colg', 'colm', 'colb', 'cols', 'colt', 'colp', 'colh', # 0x10
+'com', 'cob', 'cobs', 'cos', 'coss', 'cong', 'coj', 'coc', # 0x18
+'cok', 'cot', 'cop', 'coh', 'cwa', 'cwag', 'cwagg', 'cwags', # 0x20
+'cwan', 'cwanj', 'cwanh', 'cwad', 'cwal', 'cwalg', 'cwalm', 'cwalb', # 0x28
+'cwals', 'cwalt', 'cwalp', 'cwalh', 'cwam', 'cwab', 'cwabs', 'cwas', # 0x30
+'cwass', 'cwang', 'cwaj', 'cwac', 'cwak', 'cwat', 'cwap', 'cwah', # 0x38
+'cwae', 'cwaeg', 'cwaegg', 'cwaegs', 'cwaen', 'cwaenj', 'cwaenh', 'cwaed', # 0x40
+'cwael', 'cwaelg', 'cwaelm', 'cwaelb', 'cwaels', 'cwaelt', 'cwaelp', 'cwaelh', # 0x48
+'cwaem', 'cwaeb', 'cwaebs', 'cwaes', 'cwaess', 'cwaeng', 'cwaej', 'cwaec', # 0x50
+'cwaek', 'cwaet', 'cwaep', 'cwaeh', 'coe', 'coeg', 'coegg', 'coegs', # 0x58
+'coen', 'coenj', 'coenh', 'coed', 'coel', 'coelg', 'coelm', 'coelb', # 0x60
+'coels', 'coelt', 'coelp', 'coelh', 'coem', 'coeb', 'coebs', 'coes', # 0x68
+'coess', 'coeng', 'coej', 'coec', 'coek', 'coet', 'coep', 'coeh', # 0x70
+'cyo', 'cyog', 'cyogg', 'cyogs', 'cyon', 'cyonj', 'cyonh', 'cyod', # 0x78
+'cyol', 'cyolg', 'cyolm', 'cyolb', 'cyols', 'cyolt', 'cyolp', 'cyolh', # 0x80
+'cyom', 'cyob', 'cyobs', 'cyos', 'cyoss', 'cyong', 'cyoj', 'cyoc', # 0x88
+'cyok', 'cyot', 'cyop', 'cyoh', 'cu', 'cug', 'cugg', 'cugs', # 0x90
+'cun', 'cunj', 'cunh', 'cud', 'cul', 'culg', 'culm', 'culb', # 0x98
+'culs', 'cult', 'culp', 'culh', 'cum', 'cub', 'cubs', 'cus', # 0xA0
+'cuss', 'cung', 'cuj', 'cuc', 'cuk', 'cut', 'cup', 'cuh', # 0xA8
+'cweo', 'cweog', 'cweogg', 'cweogs', 'cweon', 'cweonj', 'cweonh', 'cweod', # 0xB0
+'cweol', 'cweolg', 'cweolm', 'cweolb', 'cweols', 'cweolt', 'cweolp', 'cweolh', # 0xB8
+'cweom', 'cweob', 'cweobs', 'cweos', 'cweoss', 'cweong', 'cweoj', 'cweoc', # 0xC0
+'cweok', 'cweot', 'cweop', 'cweoh', 'cwe', 'cweg', 'cwegg', 'cwegs', # 0xC8
+'cwen', 'cwenj', 'cwenh', 'cwed', 'cwel', 'cwelg', 'cwelm', 'cwelb', # 0xD0
+'cwels', 'cwelt', 'cwelp', 'cwelh', 'cwem', 'cweb', 'cwebs', 'cwes', # 0xD8
+'cwess', 'cweng', 'cwej', 'cwec', 'cwek', 'cwet', 'cwep', 'cweh', # 0xE0
+'cwi', 'cwig', 'cwigg', 'cwigs', 'cwin', 'cwinj', 'cwinh', 'cwid', # 0xE8
+'cwil', 'cwilg', 'cwilm', 'cwilb', 'cwils', 'cwilt', 'cwilp', 'cwilh', # 0xF0
+'cwim', 'cwib', 'cwibs', 'cwis', 'cwiss', 'cwing', 'cwij', 'cwic', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xce] = [
+'cwik', 'cwit', 'cwip', 'cwih', 'cyu', 'cyug', 'cyugg', 'cyugs', # 0x00
+'cyun', 'cyunj', 'cyunh', 'cyud', 'cyul', 'cyulg', 'cyulm', 'cyulb', # 0x08
+'cyuls', 'cyult', 'cyulp', 'cyulh', 'cyum', 'cyub', 'cyubs', 'cyus', # 0x10
+'cyuss', 'cyung', 'cyuj', 'cyuc', 'cyuk', 'cyut', 'cyup', 'cyuh', # 0x18
+'ceu', 'ceug', 'ceugg', 'ceugs', 'ceun', 'ceunj', 'ceunh', 'ceud', # 0x20
+'ceul', 'ceulg', 'ceulm', 'ceulb', 'ceuls', 'ceult', 'ceulp', 'ceulh', # 0x28
+'ceum', 'ceub', 'ceubs', 'ceus', 'ceuss', 'ceung', 'ceuj', 'ceuc', # 0x30
+'ceuk', 'ceut', 'ceup', 'ceuh', 'cyi', 'cyig', 'cyigg', 'cyigs', # 0x38
+'cyin', 'cyinj', 'cyinh', 'cyid', 'cyil', 'cyilg', 'cyilm', 'cyilb', # 0x40
+'cyils', 'cyilt', 'cyilp', 'cyilh', 'cyim', 'cyib', 'cyibs', 'cyis', # 0x48
+'cyiss', 'cying', 'cyij', 'cyic', 'cyik', 'cyit', 'cyip', 'cyih', # 0x50
+'ci', 'cig', 'cigg', 'cigs', 'cin', 'cinj', 'cinh', 'cid', # 0x58
+'cil', 'cilg', 'cilm', 'cilb', 'cils', 'cilt', 'cilp', 'cilh', # 0x60
+'cim', 'cib', 'cibs', 'cis', 'ciss', 'cing', 'cij', 'cic', # 0x68
+'cik', 'cit', 'cip', 'cih', 'ka', 'kag', 'kagg', 'kags', # 0x70
+'kan', 'kanj', 'kanh', 'kad', 'kal', 'kalg', 'kalm', 'kalb', # 0x78
+'kals', 'kalt', 'kalp', 'kalh', 'kam', 'kab', 'kabs', 'kas', # 0x80
+'kass', 'kang', 'kaj', 'kac', 'kak', 'kat', 'kap', 'kah', # 0x88
+'kae', 'kaeg', 'kaegg', 'kaegs', 'kaen', 'kaenj', 'kaenh', 'kaed', # 0x90
+'kael', 'kaelg', 'kaelm', 'kaelb', 'kaels', 'kaelt', 'kaelp', 'kaelh', # 0x98
+'kaem', 'kaeb', 'kaebs', 'kaes', 'kaess', 'kaeng', 'kaej', 'kaec', # 0xA0
+'kaek', 'kaet', 'kaep', 'kaeh', 'kya', 'kyag', 'kyagg', 'kyags', # 0xA8
+'kyan', 'kyanj', 'kyanh', 'kyad', 'kyal', 'kyalg', 'kyalm', 'kyalb', # 0xB0
+'kyals', 'kyalt', 'kyalp', 'kyalh', 'kyam', 'kyab', 'kyabs', 'kyas', # 0xB8
+'kyass', 'kyang', 'kyaj', 'kyac', 'kyak', 'kyat', 'kyap', 'kyah', # 0xC0
+'kyae', 'kyaeg', 'kyaegg', 'kyaegs', 'kyaen', 'kyaenj', 'kyaenh', 'kyaed', # 0xC8
+'kyael', 'kyaelg', 'kyaelm', 'kyaelb', 'kyaels', 'kyaelt', 'kyaelp', 'kyaelh', # 0xD0
+'kyaem', 'kyaeb', 'kyaebs', 'kyaes', 'kyaess', 'kyaeng', 'kyaej', 'kyaec', # 0xD8
+'kyaek', 'kyaet', 'kyaep', 'kyaeh', 'keo', 'keog', 'keogg', 'keogs', # 0xE0
+'keon', 'keonj', 'keonh', 'keod', 'keol', 'keolg', 'keolm', 'keolb', # 0xE8
+'keols', 'keolt', 'keolp', 'keolh', 'keom', 'keob', 'keobs', 'keos', # 0xF0
+'keoss', 'keong', 'keoj', 'keoc', 'keok', 'keot', 'keop', 'keoh', # 0xF8
+];
+1;
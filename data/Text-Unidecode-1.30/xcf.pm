@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xcf] = [
+'ke', 'keg', 'kegg', 'kegs', 'ken', 'kenj', 'kenh', 'ked', # 0x00
+'kel', 'kelg', 'kelm', 'kelb', 'kels', 'kelt', 'kelp', 'kelh', # 0x08
+'kem', 'keb', 'kebs', 'kes', 'kess', 'keng', 'kej', 'kec', # 0x10
+'kek', 'ket', 'kep', 'keh', 'kyeo', 'kyeog', 'kyeogg', 'kyeogs', # 0x18
+'kyeon', 'kyeonj', 'kyeonh', 'kyeod', 'kyeol', 'kyeolg', 'kyeolm', 'kyeolb', # 0x20
+'kyeols', 'kyeolt', 'kyeolp', 'kyeolh', 'kyeom', 'kyeob', 'kyeobs', 'kyeos', # 0x28
+'kyeoss', 'kyeong', 'kyeoj', 'kyeoc', 'kyeok', 'kyeot', 'kyeop', 'kyeoh', # 0x30
+'kye', 'kyeg', 'kyegg', 'kyegs', 'kyen', 'kyenj', 'kyenh', 'kyed', # 0x38
+'kyel', 'kyelg', 'kyelm', 'kyelb', 'kyels', 'kyelt', 'kyelp', 'kyelh', # 0x40
+'kyem', 'kyeb', 'kyebs', 'kyes', 'kyess', 'kyeng', 'kyej', 'kyec', # 0x48
+'kyek', 'kyet', 'kyep', 'kyeh', 'ko', 'kog', 'kogg', 'kogs', # 0x50
+'kon', 'konj', 'konh', 'kod', 'kol', 'kolg', 'kolm', 'kolb', # 0x58
+'kols', 'kolt', 'kolp', 'kolh', 'kom', 'kob', 'kobs', 'kos', # 0x60
+'koss', 'kong', 'koj', 'koc', 'kok', 'kot', 'kop', 'koh', # 0x68
+'kwa', 'kwag', 'kwagg', 'kwags', 'kwan', 'kwanj', 'kwanh', 'kwad', # 0x70
+'kwal', 'kwalg', 'kwalm', 'kwalb', 'kwals', 'kwalt', 'kwalp', 'kwalh', # 0x78
+'kwam', 'kwab', 'kwabs', 'kwas', 'kwass', 'kwang', 'kwaj', 'kwac', # 0x80
+'kwak', 'kwat', 'kwap', 'kwah', 'kwae', 'kwaeg', 'kwaegg', 'kwaegs', # 0x88
+'kwaen', 'kwaenj', 'kwaenh', 'kwaed', 'kwael', 'kwaelg', 'kwaelm', 'kwaelb', # 0x90
+'kwaels', 'kwaelt', 'kwaelp', 'kwaelh', 'kwaem', 'kwaeb', 'kwaebs', 'kwaes', # 0x98
+'kwaess', 'kwaeng', 'kwaej', 'kwaec', 'kwaek', 'kwaet', 'kwaep', 'kwaeh', # 0xA0
+'koe', 'koeg', 'koegg', 'koegs', 'koen', 'koenj', 'koenh', 'koed', # 0xA8
+'koel', 'koelg', 'koelm', 'koelb', 'koels', 'koelt', 'koelp', 'koelh', # 0xB0
+'koem', 'koeb', 'koebs', 'koes', 'koess', 'koeng', 'koej', 'koec', # 0xB8
+'koek', 'koet', 'koep', 'koeh', 'kyo', 'kyog', 'kyogg', 'kyogs', # 0xC0
+'kyon', 'kyonj', 'kyonh', 'kyod', 'kyol', 'kyolg', 'kyolm', 'kyolb', # 0xC8
+'kyols', 'kyolt', 'kyolp', 'kyolh', 'kyom', 'kyob', 'kyobs', 'kyos', # 0xD0
+'kyoss', 'kyong', 'kyoj', 'kyoc', 'kyok', 'kyot', 'kyop', 'kyoh', # 0xD8
+'ku', 'kug', 'kugg', 'kugs', 'kun', 'kunj', 'kunh', 'kud', # 0xE0
+'kul', 'kulg', 'kulm', 'kulb', 'kuls', 'kult', 'kulp', 'kulh', # 0xE8
+'kum', 'kub', 'kubs', 'kus', 'kuss', 'kung', 'kuj', 'kuc', # 0xF0
+'kuk', 'kut', 'kup', 'kuh', 'kweo', 'kweog', 'kweogg', 'kweogs', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xd0] = [
+'kweon', 'kweonj', 'kweonh', 'kweod', 'kweol', 'kweolg', 'kweolm', 'kweolb', # 0x00
+'kweols', 'kweolt', 'kweolp', 'kweolh', 'kweom', 'kweob', 'kweobs', 'kweos', # 0x08
+'kweoss', 'kweong', 'kweoj', 'kweoc', 'kweok', 'kweot', 'kweop', 'kweoh', # 0x10
+'kwe', 'kweg', 'kwegg', 'kwegs', 'kwen', 'kwenj', 'kwenh', 'kwed', # 0x18
+'kwel', 'kwelg', 'kwelm', 'kwelb', 'kwels', 'kwelt', 'kwelp', 'kwelh', # 0x20
+'kwem', 'kweb', 'kwebs', 'kwes', 'kwess', 'kweng', 'kwej', 'kwec', # 0x28
+'kwek', 'kwet', 'kwep', 'kweh', 'kwi', 'kwig', 'kwigg', 'kwigs', # 0x30
+'kwin', 'kwinj', 'kwinh', 'kwid', 'kwil', 'kwilg', 'kwilm', 'kwilb', # 0x38
+'kwils', 'kwilt', 'kwilp', 'kwilh', 'kwim', 'kwib', 'kwibs', 'kwis', # 0x40
+'kwiss', 'kwing', 'kwij', 'kwic', 'kwik', 'kwit', 'kwip', 'kwih', # 0x48
+'kyu', 'kyug', 'kyugg', 'kyugs', 'kyun', 'kyunj', 'kyunh', 'kyud', # 0x50
+'kyul', 'kyulg', 'kyulm', 'kyulb', 'kyuls', 'kyult', 'kyulp', 'kyulh', # 0x58
+'kyum', 'kyub', 'kyubs', 'kyus', 'kyuss', 'kyung', 'kyuj', 'kyuc', # 0x60
+'kyuk', 'kyut', 'kyup', 'kyuh', 'keu', 'keug', 'keugg', 'keugs', # 0x68
+'keun', 'keunj', 'keunh', 'keud', 'keul', 'keulg', 'keulm', 'keulb', # 0x70
+'keuls', 'keult', 'keulp', 'keulh', 'keum', 'keub', 'keubs', 'keus', # 0x78
+'keuss', 'keung', 'keuj', 'keuc', 'keuk', 'keut', 'keup', 'keuh', # 0x80
+'kyi', 'kyig', 'kyigg', 'kyigs', 'kyin', 'kyinj', 'kyinh', 'kyid', # 0x88
+'kyil', 'kyilg', 'kyilm', 'kyilb', 'kyils', 'kyilt', 'kyilp', 'kyilh', # 0x90
+'kyim', 'kyib', 'kyibs', 'kyis', 'kyiss', 'kying', 'kyij', 'kyic', # 0x98
+'kyik', 'kyit', 'kyip', 'kyih', 'ki', 'kig', 'kigg', 'kigs', # 0xA0
+'kin', 'kinj', 'kinh', 'kid', 'kil', 'kilg', 'kilm', 'kilb', # 0xA8
+'kils', 'kilt', 'kilp', 'kilh', 'kim', 'kib', 'kibs', 'kis', # 0xB0
+'kiss', 'king', 'kij', 'kic', 'kik', 'kit', 'kip', 'kih', # 0xB8
+'ta', 'tag', 'tagg', 'tags', 'tan', 'tanj', 'tanh', 'tad', # 0xC0
+'tal', 'talg', 'talm', 'talb', 'tals', 'talt', 'talp', 'talh', # 0xC8
+'tam', 'tab', 'tabs', 'tas', 'tass', 'tang', 'taj', 'tac', # 0xD0
+'tak', 'tat', 'tap', 'tah', 'tae', 'taeg', 'taegg', 'taegs', # 0xD8
+'taen', 'taenj', 'taenh', 'taed', 'tael', 'taelg', 'taelm', 'taelb', # 0xE0
+'taels', 'taelt', 'taelp', 'taelh', 'taem', 'taeb', 'taebs', 'taes', # 0xE8
+'taess', 'taeng', 'taej', 'taec', 'taek', 'taet', 'taep', 'taeh', # 0xF0
+'tya', 'tyag', 'tyagg', 'tyags', 'tyan', 'tyanj', 'tyanh', 'tyad', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xd1] = [
+'tyal', 'tyalg', 'tyalm', 'tyalb', 'tyals', 'tyalt', 'tyalp', 'tyalh', # 0x00
+'tyam', 'tyab', 'tyabs', 'tyas', 'tyass', 'tyang', 'tyaj', 'tyac', # 0x08
+'tyak', 'tyat', 'tyap', 'tyah', 'tyae', 'tyaeg', 'tyaegg', 'tyaegs', # 0x10
+'tyaen', 'tyaenj', 'tyaenh', 'tyaed', 'tyael', 'tyaelg', 'tyaelm', 'tyaelb', # 0x18
+'tyaels', 'tyaelt', 'tyaelp', 'tyaelh', 'tyaem', 'tyaeb', 'tyaebs', 'tyaes', # 0x20
+'tyaess', 'tyaeng', 'tyaej', 'tyaec', 'tyaek', 'tyaet', 'tyaep', 'tyaeh', # 0x28
+'teo', 'teog', 'teogg', 'teogs', 'teon', 'teonj', 'teonh', 'teod', # 0x30
+'teol', 'teolg', 'teolm', 'teolb', 'teols', 'teolt', 'teolp', 'teolh', # 0x38
+'teom', 'teob', 'teobs', 'teos', 'teoss', 'teong', 'teoj', 'teoc', # 0x40
+'teok', 'teot', 'teop', 'teoh', 'te', 'teg', 'tegg', 'tegs', # 0x48
+'ten', 'tenj', 'tenh', 'ted', 'tel', 'telg', 'telm', 'telb', # 0x50
+'tels', 'telt', 'telp', 'telh', 'tem', 'teb', 'tebs', 'tes', # 0x58
+'tess', 'teng', 'tej', 'tec', 'tek', 'tet', 'tep', 'teh', # 0x60
+'tyeo', 'tyeog', 'tyeogg', 'tyeogs', 'tyeon', 'tyeonj', 'tyeonh', 'tyeod', # 0x68
+'tyeol', 'tyeolg', 'tyeolm', 'tyeolb', 'tyeols', 'tyeolt', 'tyeolp', 'tyeolh', # 0x70
+'tyeom', 'tyeob', 'tyeobs', 'tyeos', 'tyeoss', 'tyeong', 'tyeoj', 'tyeoc', # 0x78
+'tyeok', 'tyeot', 'tyeop', 'tyeoh', 'tye', 'tyeg', 'tyegg', 'tyegs', # 0x80
+'tyen', 'tyenj', 'tyenh', 'tyed', 'tyel', 'tyelg', 'tyelm', 'tyelb', # 0x88
+'tyels', 'tyelt', 'tyelp', 'tyelh', 'tyem', 'tyeb', 'tyebs', 'tyes', # 0x90
+'tyess', 'tyeng', 'tyej', 'tyec', 'tyek', 'tyet', 'tyep', 'tyeh', # 0x98
+'to', 'tog', 'togg', 'togs', 'ton', 'tonj', 'tonh', 'tod', # 0xA0
+'tol', 'tolg', 'tolm', 'tolb', 'tols', 'tolt', 'tolp', 'tolh', # 0xA8
+'tom', 'tob', 'tobs', 'tos', 'toss', 'tong', 'toj', 'toc', # 0xB0
+'tok', 'tot', 'top', 'toh', 'twa', 'twag', 'twagg', 'twags', # 0xB8
+'twan', 'twanj', 'twanh', 'twad', 'twal', 'twalg', 'twalm', 'twalb', # 0xC0
+'twals', 'twalt', 'twalp', 'twalh', 'twam', 'twab', 'twabs', 'twas', # 0xC8
+'twass', 'twang', 'twaj', 'twac', 'twak', 'twat', 'twap', 'twah', # 0xD0
+'twae', 'twaeg', 'twaegg', 'twaegs', 'twaen', 'twaenj', 'twaenh', 'twaed', # 0xD8
+'twael', 'twaelg', 'twaelm', 'twaelb', 'twaels', 'twaelt', 'twaelp', 'twaelh', # 0xE0
+'twaem', 'twaeb', 'twaebs', 'twaes', 'twaess', 'twaeng', 'twaej', 'twaec', # 0xE8
+'twaek', 'twaet', 'twaep', 'twaeh', 'toe', 'toeg', 'toegg', 'toegs', # 0xF0
+'toen', 'toenj', 'toenh', 'toed', 'toel', 'toelg', 'toelm', 'toelb', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xd2] = [
+'toels', 'toelt', 'toelp', 'toelh', 'toem', 'toeb', 'toebs', 'toes', # 0x00
+'toess', 'toeng', 'toej', 'toec', 'toek', 'toet', 'toep', 'toeh', # 0x08
+'tyo', 'tyog', 'tyogg', 'tyogs', 'tyon', 'tyonj', 'tyonh', 'tyod', # 0x10
+'tyol', 'tyolg', 'tyolm', 'tyolb', 'tyols', 'tyolt', 'tyolp', 'tyolh', # 0x18
+'tyom', 'tyob', 'tyobs', 'tyos', 'tyoss', 'tyong', 'tyoj', 'tyoc', # 0x20
+'tyok', 'tyot', 'tyop', 'tyoh', 'tu', 'tug', 'tugg', 'tugs', # 0x28
+'tun', 'tunj', 'tunh', 'tud', 'tul', 'tulg', 'tulm', 'tulb', # 0x30
+'tuls', 'tult', 'tulp', 'tulh', 'tum', 'tub', 'tubs', 'tus', # 0x38
+'tuss', 'tung', 'tuj', 'tuc', 'tuk', 'tut', 'tup', 'tuh', # 0x40
+'tweo', 'tweog', 'tweogg', 'tweogs', 'tweon', 'tweonj', 'tweonh', 'tweod', # 0x48
+'tweol', 'tweolg', 'tweolm', 'tweolb', 'tweols', 'tweolt', 'tweolp', 'tweolh', # 0x50
+'tweom', 'tweob', 'tweobs', 'tweos', 'tweoss', 'tweong', 'tweoj', 'tweoc', # 0x58
+'tweok', 'tweot', 'tweop', 'tweoh', 'twe', 'tweg', 'twegg', 'twegs', # 0x60
+'twen', 'twenj', 'twenh', 'twed', 'twel', 'twelg', 'twelm', 'twelb', # 0x68
+'twels', 'twelt', 'twelp', 'twelh', 'twem', 'tweb', 'twebs', 'twes', # 0x70
+'twess', 'tweng', 'twej', 'twec', 'twek', 'twet', 'twep', 'tweh', # 0x78
+'twi', 'twig', 'twigg', 'twigs', 'twin', 'twinj', 'twinh', 'twid', # 0x80
+'twil', 'twilg', 'twilm', 'twilb', 'twils', 'twilt', 'twilp', 'twilh', # 0x88
+'twim', 'twib', 'twibs', 'twis', 'twiss', 'twing', 'twij', 'twic', # 0x90
+'twik', 'twit', 'twip', 'twih', 'tyu', 'tyug', 'tyugg', 'tyugs', # 0x98
+'tyun', 'tyunj', 'tyunh', 'tyud', 'tyul', 'tyulg', 'tyulm', 'tyulb', # 0xA0
+'tyuls', 'tyult', 'tyulp', 'tyulh', 'tyum', 'tyub', 'tyubs', 'tyus', # 0xA8
+'tyuss', 'tyung', 'tyuj', 'tyuc', 'tyuk', 'tyut', 'tyup', 'tyuh', # 0xB0
+'teu', 'teug', 'teugg', 'teugs', 'teun', 'teunj', 'teunh', 'teud', # 0xB8
+'teul', 'teulg', 'teulm', 'teulb', 'teuls', 'teult', 'teulp', 'teulh', # 0xC0
+'teum', 'teub', 'teubs', 'teus', 'teuss', 'teung', 'teuj', 'teuc', # 0xC8
+'teuk', 'teut', 'teup', 'teuh', 'tyi', 'tyig', 'tyigg', 'tyigs', # 0xD0
+'tyin', 'tyinj', 'tyinh', 'tyid', 'tyil', 'tyilg', 'tyilm', 'tyilb', # 0xD8
+'tyils', 'tyilt', 'tyilp', 'tyilh', 'tyim', 'tyib', 'tyibs', 'tyis', # 0xE0
+'tyiss', 'tying', 'tyij', 'tyic', 'tyik', 'tyit', 'tyip', 'tyih', # 0xE8
+'ti', 'tig', 'tigg', 'tigs', 'tin', 'tinj', 'tinh', 'tid', # 0xF0
+'til', 'tilg', 'tilm', 'tilb', 'tils', 'tilt', 'tilp', 'tilh', # 0xF8
+];
+1;
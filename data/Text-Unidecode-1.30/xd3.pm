@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xd3] = [
+'tim', 'tib', 'tibs', 'tis', 'tiss', 'ting', 'tij', 'tic', # 0x00
+'tik', 'tit', 'tip', 'tih', 'pa', 'pag', 'pagg', 'pags', # 0x08
+'pan', 'panj', 'panh', 'pad', 'pal', 'palg', 'palm', 'palb', # 0x10
+'pals', 'palt', 'palp', 'palh', 'pam', 'pab', 'pabs', 'pas', # 0x18
+'pass', 'pang', 'paj', 'pac', 'pak', 'pat', 'pap', 'pah', # 0x20
+'pae', 'paeg', 'paegg', 'paegs', 'paen', 'paenj', 'paenh', 'paed', # 0x28
+'pael', 'paelg', 'paelm', 'paelb', 'paels', 'paelt', 'paelp', 'paelh', # 0x30
+'paem', 'paeb', 'paebs', 'paes', 'paess', 'paeng', 'paej', 'paec', # 0x38
+'paek', 'paet', 'paep', 'paeh', 'pya', 'pyag', 'pyagg', 'pyags', # 0x40
+'pyan', 'pyanj', 'pyanh', 'pyad', 'pyal', 'pyalg', 'pyalm', 'pyalb', # 0x48
+'pyals', 'pyalt', 'pyalp', 'pyalh', 'pyam', 'pyab', 'pyabs', 'pyas', # 0x50
+'pyass', 'pyang', 'pyaj', 'pyac', 'pyak', 'pyat', 'pyap', 'pyah', # 0x58
+'pyae', 'pyaeg', 'pyaegg', 'pyaegs', 'pyaen', 'pyaenj', 'pyaenh', 'pyaed', # 0x60
+'pyael', 'pyaelg', 'pyaelm', 'pyaelb', 'pyaels', 'pyaelt', 'pyaelp', 'pyaelh', # 0x68
+'pyaem', 'pyaeb', 'pyaebs', 'pyaes', 'pyaess', 'pyaeng', 'pyaej', 'pyaec', # 0x70
+'pyaek', 'pyaet', 'pyaep', 'pyaeh', 'peo', 'peog', 'peogg', 'peogs', # 0x78
+'peon', 'peonj', 'peonh', 'peod', 'peol', 'peolg', 'peolm', 'peolb', # 0x80
+'peols', 'peolt', 'peolp', 'peolh', 'peom', 'peob', 'peobs', 'peos', # 0x88
+'peoss', 'peong', 'peoj', 'peoc', 'peok', 'peot', 'peop', 'peoh', # 0x90
+'pe', 'peg', 'pegg', 'pegs', 'pen', 'penj', 'penh', 'ped', # 0x98
+'pel', 'pelg', 'pelm', 'pelb', 'pels', 'pelt', 'pelp', 'pelh', # 0xA0
+'pem', 'peb', 'pebs', 'pes', 'pess', 'peng', 'pej', 'pec', # 0xA8
+'pek', 'pet', 'pep', 'peh', 'pyeo', 'pyeog', 'pyeogg', 'pyeogs', # 0xB0
+'pyeon', 'pyeonj', 'pyeonh', 'pyeod', 'pyeol', 'pyeolg', 'pyeolm', 'pyeolb', # 0xB8
+'pyeols', 'pyeolt', 'pyeolp', 'pyeolh', 'pyeom', 'pyeob', 'pyeobs', 'pyeos', # 0xC0
+'pyeoss', 'pyeong', 'pyeoj', 'pyeoc', 'pyeok', 'pyeot', 'pyeop', 'pyeoh', # 0xC8
+'pye', 'pyeg', 'pyegg', 'pyegs', 'pyen', 'pyenj', 'pyenh', 'pyed', # 0xD0
+'pyel', 'pyelg', 'pyelm', 'pyelb', 'pyels', 'pyelt', 'pyelp', 'pyelh', # 0xD8
+'pyem', 'pyeb', 'pyebs', 'pyes', 'pyess', 'pyeng', 'pyej', 'pyec', # 0xE0
+'pyek', 'pyet', 'pyep', 'pyeh', 'po', 'pog', 'pogg', 'pogs', # 0xE8
+'pon', 'ponj', 'ponh', 'pod', 'pol', 'polg', 'polm', 'polb', # 0xF0
+'pols', 'polt', 'polp', 'polh', 'pom', 'pob', 'pobs', 'pos', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xd4] = [
+'poss', 'pong', 'poj', 'poc', 'pok', 'pot', 'pop', 'poh', # 0x00
+'pwa', 'pwag', 'pwagg', 'pwags', 'pwan', 'pwanj', 'pwanh', 'pwad', # 0x08
+'pwal', 'pwalg', 'pwalm', 'pwalb', 'pwals', 'pwalt', 'pwalp', 'pwalh', # 0x10
+'pwam', 'pwab', 'pwabs', 'pwas', 'pwass', 'pwang', 'pwaj', 'pwac', # 0x18
+'pwak', 'pwat', 'pwap', 'pwah', 'pwae', 'pwaeg', 'pwaegg', 'pwaegs', # 0x20
+'pwaen', 'pwaenj', 'pwaenh', 'pwaed', 'pwael', 'pwaelg', 'pwaelm', 'pwaelb', # 0x28
+'pwaels', 'pwaelt', 'pwaelp', 'pwaelh', 'pwaem', 'pwaeb', 'pwaebs', 'pwaes', # 0x30
+'pwaess', 'pwaeng', 'pwaej', 'pwaec', 'pwaek', 'pwaet', 'pwaep', 'pwaeh', # 0x38
+'poe', 'poeg', 'poegg', 'poegs', 'poen', 'poenj', 'poenh', 'poed', # 0x40
+'poel', 'poelg', 'poelm', 'poelb', 'poels', 'poelt', 'poelp', 'poelh', # 0x48
+'poem', 'poeb', 'poebs', 'poes', 'poess', 'poeng', 'poej', 'poec', # 0x50
+'poek', 'poet', 'poep', 'poeh', 'pyo', 'pyog', 'pyogg', 'pyogs', # 0x58
+'pyon', 'pyonj', 'pyonh', 'pyod', 'pyol', 'pyolg', 'pyolm', 'pyolb', # 0x60
+'pyols', 'pyolt', 'pyolp', 'pyolh', 'pyom', 'pyob', 'pyobs', 'pyos', # 0x68
+'pyoss', 'pyong', 'pyoj', 'pyoc', 'pyok', 'pyot', 'pyop', 'pyoh', # 0x70
+'pu', 'pug', 'pugg', 'pugs', 'pun', 'punj', 'punh', 'pud', # 0x78
+'pul', 'pulg', 'pulm', 'pulb', 'puls', 'pult', 'pulp', 'pulh', # 0x80
+'pum', 'pub', 'pubs', 'pus', 'puss', 'pung', 'puj', 'puc', # 0x88
+'puk', 'put', 'pup', 'puh', 'pweo', 'pweog', 'pweogg', 'pweogs', # 0x90
+'pweon', 'pweonj', 'pweonh', 'pweod', 'pweol', 'pweolg', 'pweolm', 'pweolb', # 0x98
+'pweols', 'pweolt', 'pweolp', 'pweolh', 'pweom', 'pweob', 'pweobs', 'pweos', # 0xA0
+'pweoss', 'pweong', 'pweoj', 'pweoc', 'pweok', 'pweot', 'pweop', 'pweoh', # 0xA8
+'pwe', 'pweg', 'pwegg', 'pwegs', 'pwen', 'pwenj', 'pwenh', 'pwed', # 0xB0
+'pwel', 'pwelg', 'pwelm', 'pwelb', 'pwels', 'pwelt', 'pwelp', 'pwelh', # 0xB8
+'pwem', 'pweb', 'pwebs', 'pwes', 'pwess', 'pweng', 'pwej', 'pwec', # 0xC0
+'pwek', 'pwet', 'pwep', 'pweh', 'pwi', 'pwig', 'pwigg', 'pwigs', # 0xC8
+'pwin', 'pwinj', 'pwinh', 'pwid', 'pwil', 'pwilg', 'pwilm', 'pwilb', # 0xD0
+'pwils', 'pwilt', 'pwilp', 'pwilh', 'pwim', 'pwib', 'pwibs', 'pwis', # 0xD8
+'pwiss', 'pwing', 'pwij', 'pwic', 'pwik', 'pwit', 'pwip', 'pwih', # 0xE0
+'pyu', 'pyug', 'pyugg', 'pyugs', 'pyun', 'pyunj', 'pyunh', 'pyud', # 0xE8
+'pyul', 'pyulg', 'pyulm', 'pyulb', 'pyuls', 'pyult', 'pyulp', 'pyulh', # 0xF0
+'pyum', 'pyub', 'pyubs', 'pyus', 'pyuss', 'pyung', 'pyuj', 'pyuc', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xd5] = [
+'pyuk', 'pyut', 'pyup', 'pyuh', 'peu', 'peug', 'peugg', 'peugs', # 0x00
+'peun', 'peunj', 'peunh', 'peud', 'peul', 'peulg', 'peulm', 'peulb', # 0x08
+'peuls', 'peult', 'peulp', 'peulh', 'peum', 'peub', 'peubs', 'peus', # 0x10
+'peuss', 'peung', 'peuj', 'peuc', 'peuk', 'peut', 'peup', 'peuh', # 0x18
+'pyi', 'pyig', 'pyigg', 'pyigs', 'pyin', 'pyinj', 'pyinh', 'pyid', # 0x20
+'pyil', 'pyilg', 'pyilm', 'pyilb', 'pyils', 'pyilt', 'pyilp', 'pyilh', # 0x28
+'pyim', 'pyib', 'pyibs', 'pyis', 'pyiss', 'pying', 'pyij', 'pyic', # 0x30
+'pyik', 'pyit', 'pyip', 'pyih', 'pi', 'pig', 'pigg', 'pigs', # 0x38
+'pin', 'pinj', 'pinh', 'pid', 'pil', 'pilg', 'pilm', 'pilb', # 0x40
+'pils', 'pilt', 'pilp', 'pilh', 'pim', 'pib', 'pibs', 'pis', # 0x48
+'piss', 'ping', 'pij', 'pic', 'pik', 'pit', 'pip', 'pih', # 0x50
+'ha', 'hag', 'hagg', 'hags', 'han', 'hanj', 'hanh', 'had', # 0x58
+'hal', 'halg', 'halm', 'halb', 'hals', 'halt', 'halp', 'halh', # 0x60
+'ham', 'hab', 'habs', 'has', 'hass', 'hang', 'haj', 'hac', # 0x68
+'hak', 'hat', 'hap', 'hah', 'hae', 'haeg', 'haegg', 'haegs', # 0x70
+'haen', 'haenj', 'haenh', 'haed', 'hael', 'haelg', 'haelm', 'haelb', # 0x78
+'haels', 'haelt', 'haelp', 'haelh', 'haem', 'haeb', 'haebs', 'haes', # 0x80
+'haess', 'haeng', 'haej', 'haec', 'haek', 'haet', 'haep', 'haeh', # 0x88
+'hya', 'hyag', 'hyagg', 'hyags', 'hyan', 'hyanj', 'hyanh', 'hyad', # 0x90
+'hyal', 'hyalg', 'hyalm', 'hyalb', 'hyals', 'hyalt', 'hyalp', 'hyalh', # 0x98
+'hyam', 'hyab', 'hyabs', 'hyas', 'hyass', 'hyang', 'hyaj', 'hyac', # 0xA0
+'hyak', 'hyat', 'hyap', 'hyah', 'hyae', 'hyaeg', 'hyaegg', 'hyaegs', # 0xA8
+'hyaen', 'hyaenj', 'hyaenh', 'hyaed', 'hyael', 'hyaelg', 'hyaelm', 'hyaelb', # 0xB0
+'hyaels', 'hyaelt', 'hyaelp', 'hyaelh', 'hyaem', 'hyaeb', 'hyaebs', 'hyaes', # 0xB8
+'hyaess', 'hyaeng', 'hyaej', 'hyaec', 'hyaek', 'hyaet', 'hyaep', 'hyaeh', # 0xC0
+'heo', 'heog', 'heogg', 'heogs', 'heon', 'heonj', 'heonh', 'heod', # 0xC8
+'heol', 'heolg', 'heolm', 'heolb', 'heols', 'heolt', 'heolp', 'heolh', # 0xD0
+'heom', 'heob', 'heobs', 'heos', 'heoss', 'heong', 'heoj', 'heoc', # 0xD8
+'heok', 'heot', 'heop', 'heoh', 'he', 'heg', 'hegg', 'hegs', # 0xE0
+'hen', 'henj', 'henh', 'hed', 'hel', 'helg', 'helm', 'helb', # 0xE8
+'hels', 'helt', 'help', 'helh', 'hem', 'heb', 'hebs', 'hes', # 0xF0
+'hess', 'heng', 'hej', 'hec', 'hek', 'het', 'hep', 'heh', # 0xF8
+];
+1;
@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xfb] = [
+'ff', 'fi', 'fl', 'ffi', 'ffl', 'st', 'st', '[?]', # 0x00
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0x08
+'[?]', '[?]', '[?]', 'mn', 'me', 'mi', 'vn', 'mkh', # 0x10
+'[?]', '[?]', '[?]', '[?]', '[?]', 'yi', '', 'ay', # 0x18
+'`', '', 'd', 'h', 'k', 'l', 'm', 'm', # 0x20
+'t', '+', 'sh', 's', 'sh', 's', 'a', 'a', # 0x28
+'', 'b', 'g', 'd', 'h', 'v', 'z', '[?]', # 0x30
+'t', 'y', 'k', 'k', 'l', '[?]', 'l', '[?]', # 0x38
+'n', 'n', '[?]', 'p', 'p', '[?]', 'ts', 'ts', # 0x40
+'r', 'sh', 't', 'vo', 'b', 'k', 'p', 'l', # 0x48
+'', '', '', '', '', '', '', '', # 0x50
+'', '', '', '', '', '', '', '', # 0x58
+'', '', '', '', '', '', '', '', # 0x60
+'', '', '', '', '', '', '', '', # 0x68
+'', '', '', '', '', '', '', '', # 0x70
+'', '', '', '', '', '', '', '', # 0x78
+'', '', '', '', '', '', '', '', # 0x80
+'', '', '', '', '', '', '', '', # 0x88
+'', '', '', '', '', '', '', '', # 0x90
+'', '', '', '', '', '', '', '', # 0x98
+'', '', '', '', '', '', '', '', # 0xA0
+'', '', '', '', '', '', '', '', # 0xA8
+'', '', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xB8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC0
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xC8
+'[?]', '[?]', '[?]', '', '', '', '', '', # 0xD0
+'', '', '', '', '', '', '', '', # 0xD8
+'', '', '', '', '', '', '', '', # 0xE0
+'', '', '', '', '', '', '', '', # 0xE8
+'', '', '', '', '', '', '', '', # 0xF0
+'', '', '', '', '', '', '', '', # 0xF8
+];
+1;
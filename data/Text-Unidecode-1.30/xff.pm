@@ -0,0 +1,35 @@
+$Text::Unidecode::Char[0xff] = [
+'[?]', '!', '"', '#', '$', '%', '&', "'", # 0x00
+'(', ')', '*', '+', ',', '-', '.', '/', # 0x08
+'0', '1', '2', '3', '4', '5', '6', '7', # 0x10
+'8', '9', ':', ';', '<', '=', '>', '?', # 0x18
+'@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', # 0x20
+'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', # 0x28
+'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', # 0x30
+'X', 'Y', 'Z', '[', "\\", ']', '^', '_', # 0x38
+'`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', # 0x40
+'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', # 0x48
+'p', 'q', 'r', 's', 't', 'u', 'v', 'w', # 0x50
+'x', 'y', 'z', '{', '|', '}', '~', '[?]', # 0x58
+'[?]', '.', '[', ']', ',', '*', 'wo', 'a', # 0x60
+'i', 'u', 'e', 'o', 'ya', 'yu', 'yo', 'tu', # 0x68
+'+', 'a', 'i', 'u', 'e', 'o', 'ka', 'ki', # 0x70
+'ku', 'ke', 'ko', 'sa', 'si', 'su', 'se', 'so', # 0x78
+'ta', 'ti', 'tu', 'te', 'to', 'na', 'ni', 'nu', # 0x80
+'ne', 'no', 'ha', 'hi', 'hu', 'he', 'ho', 'ma', # 0x88
+'mi', 'mu', 'me', 'mo', 'ya', 'yu', 'yo', 'ra', # 0x90
+'ri', 'ru', 're', 'ro', 'wa', 'n', ':', ';', # 0x98
+'', 'g', 'gg', 'gs', 'n', 'nj', 'nh', 'd', # 0xA0
+'dd', 'r', 'lg', 'lm', 'lb', 'ls', 'lt', 'lp', # 0xA8
+'rh', 'm', 'b', 'bb', 'bs', 's', 'ss', '', # 0xB0
+'j', 'jj', 'c', 'k', 't', 'p', 'h', '[?]', # 0xB8
+'[?]', '[?]', 'a', 'ae', 'ya', 'yae', 'eo', 'e', # 0xC0
+'[?]', '[?]', 'yeo', 'ye', 'o', 'wa', 'wae', 'oe', # 0xC8
+'[?]', '[?]', 'yo', 'u', 'weo', 'we', 'wi', 'yu', # 0xD0
+'[?]', '[?]', 'eu', 'yi', 'i', '[?]', '[?]', '[?]', # 0xD8
+'/C', 'PS', '!', '-', '|', 'Y=', 'W=', '[?]', # 0xE0
+'|', '-', '|', '-', '|', '#', 'O', '[?]', # 0xE8
+'[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', '[?]', # 0xF0
+'[?]', '{', '|', '}', '', '', '', '', # 0xF8
+];
+1;
@@ -0,0 +1,126 @@
+use core::fmt;
+use core::str::Chars;
+
+use {unidecode_char, unidecode_to_writer};
+
+/// A wrapper around a string that transliterates it when formatted, without
+/// allocating an intermediate `String`.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// assert_eq!(format!("<{}>", Unidecoded("Æneid")), "<AEneid>");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unidecoded<'a>(pub &'a str);
+
+impl<'a> fmt::Display for Unidecoded<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        unidecode_to_writer(self.0, f)
+    }
+}
+
+/// An extension trait for iterators over `char`s, adding transliteration
+/// adapters.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let pieces: Vec<_> = "Æneid".chars().unidecode().collect();
+/// assert_eq!(pieces, ["AE", "n", "e", "i", "d"]);
+///
+/// let last: String = "北亰".chars().unidecode().chars().rev().take(4).collect();
+/// assert_eq!(last, " gni");
+/// ```
+pub trait UnidecodeExt: Iterator<Item = char> + Sized {
+    /// Transliterates every `char` of the iterator with `unidecode_char()`.
+    fn unidecode(self) -> Unidecode<Self> {
+        Unidecode { inner: self }
+    }
+}
+
+impl<I: Iterator<Item = char>> UnidecodeExt for I {}
+
+/// An iterator over the transliterations of the `char`s of another iterator.
+///
+/// This struct is created by the `unidecode()` method of `UnidecodeExt`.
+#[derive(Debug, Clone)]
+pub struct Unidecode<I> {
+    inner: I,
+}
+
+impl<I> Unidecode<I> {
+    /// Flattens the transliterations into an iterator over their `char`s.
+    pub fn chars(self) -> UnidecodeChars<I> {
+        UnidecodeChars {
+            inner: self.inner,
+            front: "".chars(),
+            back: "".chars(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Unidecode<I> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        self.inner.next().map(unidecode_char)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for Unidecode<I>
+where
+    I: DoubleEndedIterator<Item = char>,
+{
+    fn next_back(&mut self) -> Option<&'static str> {
+        self.inner.next_back().map(unidecode_char)
+    }
+}
+
+/// An iterator over the ASCII `char`s of the transliteration of another
+/// iterator.
+///
+/// This struct is created by the `chars()` method of `Unidecode`.
+#[derive(Debug, Clone)]
+pub struct UnidecodeChars<I> {
+    inner: I,
+    front: Chars<'static>,
+    back: Chars<'static>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for UnidecodeChars<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(ch) = self.front.next() {
+                return Some(ch);
+            }
+            match self.inner.next() {
+                Some(ch) => self.front = unidecode_char(ch).chars(),
+                None => return self.back.next(),
+            }
+        }
+    }
+}
+
+impl<I> DoubleEndedIterator for UnidecodeChars<I>
+where
+    I: DoubleEndedIterator<Item = char>,
+{
+    fn next_back(&mut self) -> Option<char> {
+        loop {
+            if let Some(ch) = self.back.next_back() {
+                return Some(ch);
+            }
+            match self.inner.next_back() {
+                Some(ch) => self.back = unidecode_char(ch).chars(),
+                None => return self.front.next_back(),
+            }
+        }
+    }
+}
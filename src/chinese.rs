@@ -0,0 +1,196 @@
+use alloc::string::String;
+
+use han::is_han;
+use han_data::{MANDARIN, MANDARIN_WORDS, MAX_WORD, PINYIN};
+use unidecode_char;
+
+/// A style of pinyin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PinyinStyle {
+    /// Without tones, such as `"beijing"`, with `ü` written `u` like in the
+    /// data set.
+    Plain,
+    /// With the tone number after every syllable, and the syllables separated
+    /// by spaces, such as `"bei3 jing1"`. The neutral tone is 5, and `ü` is
+    /// written `v`.
+    ToneNumbers,
+    /// With tone marks, such as `"běijīng"`. Unlike the other styles, the
+    /// output is not ASCII.
+    ToneMarks,
+}
+
+/// The vowels with a tone mark, for the first to the fourth tone.
+static MARKS: [(char, [char; 4]); 6] = [
+    ('a', ['ā', 'á', 'ǎ', 'à']),
+    ('e', ['ē', 'é', 'ě', 'è']),
+    ('i', ['ī', 'í', 'ǐ', 'ì']),
+    ('o', ['ō', 'ó', 'ǒ', 'ò']),
+    ('u', ['ū', 'ú', 'ǔ', 'ù']),
+    ('v', ['ǖ', 'ǘ', 'ǚ', 'ǜ']),
+];
+
+/// The syllabic nasals with a tone mark, for the first to the fourth tone.
+static NASALS: [(&str, [&str; 4]); 2] = [
+    ("m", ["m\u{304}", "ḿ", "m\u{30C}", "m\u{300}"]),
+    ("n", ["n\u{304}", "ń", "ň", "ǹ"]),
+];
+
+/// A transliterator for Chinese, which romanizes Han characters in pinyin.
+///
+/// The words of a short list, curated by hand, are found first, longest first,
+/// so that characters with several readings are read as they are in the word,
+/// such as `行` in `银行`. The list holds about a hundred common words and
+/// words with polyphonic characters only, so other words are read character
+/// by character, and a polyphonic character outside of them gets its most
+/// common reading, such as `le` for `乐` in `乐曲`. The syllables of a word are
+/// joined, and words are separated by spaces. Other Han characters are read
+/// with their most common reading, from the Unihan database or the Han-Latin
+/// transform of CLDR, which cover the ideographs of the Basic Multilingual
+/// Plane. The few without a reading in either are transliterated like
+/// `unidecode()` does, without a tone. Other characters are transliterated
+/// like `unidecode()` does.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = ChineseTransliterator::new();
+/// assert_eq!(t.transliterate("我在银行"), "wo zai yinhang");
+///
+/// let t = ChineseTransliterator::new().style(PinyinStyle::ToneNumbers);
+/// assert_eq!(t.transliterate("北京"), "bei3 jing1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChineseTransliterator {
+    style: PinyinStyle,
+}
+
+impl ChineseTransliterator {
+    /// Creates a transliterator with plain pinyin.
+    pub fn new() -> ChineseTransliterator {
+        ChineseTransliterator {
+            style: PinyinStyle::Plain,
+        }
+    }
+
+    /// Sets the style of pinyin.
+    pub fn style(mut self, style: PinyinStyle) -> ChineseTransliterator {
+        self.style = style;
+        self
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        // Whether the last character was Han, to separate it from letters
+        let mut han = false;
+        let mut rest = s;
+        while let Some(ch) = rest.chars().next() {
+            if let Some((reading, len)) = word(rest) {
+                self.push_word(&mut out, reading);
+                han = true;
+                rest = &rest[len..];
+                continue;
+            }
+            rest = &rest[ch.len_utf8()..];
+            if let Ok(i) = MANDARIN.binary_search_by_key(&ch, |&(c, _)| c) {
+                self.push_word(&mut out, PINYIN[MANDARIN[i].1 as usize]);
+                han = true;
+                continue;
+            }
+            let s = unidecode_char(ch);
+            if is_han(ch) && !s.trim().is_empty() {
+                let reading = s.trim().to_ascii_lowercase();
+                self.push_word(&mut out, &reading);
+                han = true;
+                continue;
+            }
+            if han && s.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+                out.push(' ');
+            }
+            han = false;
+            out.push_str(s);
+        }
+        out
+    }
+
+    /// Appends the syllables of a word, after a space if it follows a letter,
+    /// a digit or punctuation ending a clause.
+    fn push_word(&self, out: &mut String, syllables: &str) {
+        let separated = |c: char| {
+            c.is_ascii_alphanumeric()
+                || matches!(c, ',' | '.' | ';' | ':' | '!' | '?')
+        };
+        if out.ends_with(separated) {
+            out.push(' ');
+        }
+        for (i, syllable) in syllables.split(' ').enumerate() {
+            let vowel = syllable.starts_with(['a', 'e', 'o']);
+            match self.style {
+                PinyinStyle::ToneNumbers if i > 0 => out.push(' '),
+                _ if i > 0 && vowel => out.push('\''),
+                _ => {},
+            }
+            match self.style {
+                PinyinStyle::Plain => {
+                    let base = syllable.trim_end_matches(char::is_numeric);
+                    base.chars()
+                        .for_each(|c| out.push(if c == 'v' { 'u' } else { c }));
+                },
+                PinyinStyle::ToneNumbers => out.push_str(syllable),
+                PinyinStyle::ToneMarks => push_marked(out, syllable),
+            }
+        }
+    }
+}
+
+impl Default for ChineseTransliterator {
+    fn default() -> ChineseTransliterator {
+        ChineseTransliterator::new()
+    }
+}
+
+/// Finds the longest word of the dictionary at the start of `s`, returning its
+/// reading and its length in bytes.
+fn word(s: &str) -> Option<(&'static str, usize)> {
+    s.char_indices()
+        .map(|(i, ch)| i + ch.len_utf8())
+        .take(MAX_WORD)
+        .skip(1)
+        .filter_map(|end| {
+            MANDARIN_WORDS
+                .binary_search_by_key(&&s[..end], |&(word, _)| word)
+                .ok()
+                .map(|i| (MANDARIN_WORDS[i].1, end))
+        })
+        .last()
+}
+
+/// Appends a syllable with a tone number, such as `"lv4"`, with a tone mark
+/// instead, such as `"lǜ"`. The mark goes on `a` or `e`, on the `o` of `ou`,
+/// and otherwise on the last vowel, or on the syllabic nasals `m` and `n`.
+fn push_marked(out: &mut String, syllable: &str) {
+    let (base, tone) = match syllable.char_indices().last() {
+        Some((i, c @ '1'..='4')) => (&syllable[..i], c as usize - '1' as usize),
+        Some((i, '5')) => (&syllable[..i], 4),
+        _ => (syllable, 4),
+    };
+    let nasal = NASALS.iter().find(|&&(nasal, _)| nasal == base);
+    if let Some(&(_, marks)) = nasal.filter(|_| tone < 4) {
+        out.push_str(marks[tone]);
+        return;
+    }
+    let marked = base
+        .find(['a', 'e'])
+        .or_else(|| base.find("ou"))
+        .or_else(|| base.rfind(['i', 'o', 'u', 'v']));
+    for (i, c) in base.char_indices() {
+        let mark = MARKS.iter().find(|&&(vowel, _)| vowel == c);
+        match mark {
+            Some(&(_, marks)) if Some(i) == marked && tone < 4 => {
+                out.push(marks[tone])
+            },
+            _ if c == 'v' => out.push('ü'),
+            _ => out.push(c),
+        }
+    }
+}
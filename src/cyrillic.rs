@@ -0,0 +1,397 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use unidecode_char;
+
+/// A standard for the transliteration of the Cyrillic script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CyrillicScheme {
+    /// ISO 9:1995, which maps every letter to a single Latin letter, so that
+    /// it can be reversed, such as `"Ŝukin"` for `"Щукин"`. Unlike the other
+    /// schemes, the output is not ASCII.
+    Iso9,
+    /// GOST 7.79-2000 system B, the ASCII variant of ISO 9, such as
+    /// `"Shhukin"`.
+    Gost779B,
+    /// The romanizations of the BGN and the PCGN, which differ between
+    /// languages, such as `"Shchukin"`. The diacritics and apostrophes of the
+    /// standards, such as `"yë"` and `"’"` in Russian and the Latin alphabet
+    /// in Serbian, are replaced with ASCII unless
+    /// `CyrillicTransliterator::diacritics()` keeps them.
+    BgnPcgn,
+    /// The transliteration of Doc 9303 of the ICAO, used in the
+    /// machine-readable zone of passports, such as `"Shchukin"`.
+    Icao,
+}
+
+/// A language written in the Cyrillic script, which selects the rules of a
+/// `CyrillicScheme` that differ between languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CyrillicLanguage {
+    /// Russian.
+    Russian,
+    /// Ukrainian.
+    Ukrainian,
+    /// Belarusian.
+    Belarusian,
+    /// Bulgarian.
+    Bulgarian,
+    /// Serbian.
+    Serbian,
+}
+
+/// ISO 9:1995.
+static ISO_9: [(char, &str); 51] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"),
+    ('ґ', "g\u{300}"), ('д', "d"), ('ѓ', "ǵ"), ('ђ', "đ"),
+    ('е', "e"), ('ё', "ë"), ('є', "ê"), ('ж', "ž"),
+    ('з', "z"), ('ѕ', "ẑ"), ('и', "i"), ('і', "ì"),
+    ('ї', "ï"), ('й', "j"), ('ј', "ǰ"), ('к', "k"),
+    ('л', "l"), ('љ', "l\u{302}"), ('м', "m"), ('н', "n"),
+    ('њ', "n\u{302}"), ('о', "o"), ('п', "p"), ('р', "r"),
+    ('с', "s"), ('т', "t"), ('ќ', "ḱ"), ('ћ', "ć"),
+    ('у', "u"), ('ў', "ŭ"), ('ф', "f"), ('х', "h"),
+    ('ц', "c"), ('ч', "č"), ('џ', "d\u{302}"), ('ш', "š"),
+    ('щ', "ŝ"), ('ъ', "ʺ"), ('ы', "y"), ('ь', "ʹ"),
+    ('э', "è"), ('ю', "û"), ('я', "â"), ('ѣ', "ě"),
+    ('ѳ', "f\u{300}"), ('ѵ', "ỳ"), ('ѫ', "ǎ"),
+];
+
+/// GOST 7.79-2000 system B. `ц` is `"c"` before `i`, `e`, `y` and `j`.
+static GOST_7_79_B: [(char, &str); 49] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"),
+    ('ґ', "g`"), ('д', "d"), ('ѓ', "g`"), ('е', "e"),
+    ('ё', "yo"), ('є', "ye"), ('ж', "zh"), ('з', "z"),
+    ('ѕ', "z`"), ('и', "i"), ('і', "i"), ('ї', "yi"),
+    ('й', "j"), ('ј', "j"), ('к', "k"), ('л', "l"),
+    ('љ', "l`"), ('м', "m"), ('н', "n"), ('њ', "n`"),
+    ('о', "o"), ('п', "p"), ('р', "r"), ('с', "s"),
+    ('т', "t"), ('ќ', "k`"), ('у', "u"), ('ў', "u`"),
+    ('ф', "f"), ('х', "x"), ('ц', "cz"), ('ч', "ch"),
+    ('џ', "dh"), ('ш', "sh"), ('щ', "shh"), ('ъ', "``"),
+    ('ы', "y`"), ('ь', "`"), ('э', "e`"), ('ю', "yu"),
+    ('я', "ya"), ('ѣ', "ye"), ('ѳ', "fh"), ('ѵ', "yh"),
+    ('ѫ', "o`"),
+];
+
+/// The letters of GOST 7.79-2000 system B that differ in Ukrainian.
+static GOST_7_79_B_UKRAINIAN: [(char, &str); 1] = [('и', "y`")];
+
+/// The letters of GOST 7.79-2000 system B that differ in Bulgarian.
+static GOST_7_79_B_BULGARIAN: [(char, &str); 2] = [('щ', "sht"), ('ъ', "a`")];
+
+/// BGN/PCGN for Russian. `е` and `ё` are `"ye"` and `"yë"` at the start of a
+/// word and after vowels, `й`, `ъ` and `ь`.
+static BGN_PCGN_RUSSIAN: [(char, &str); 33] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"),
+    ('д', "d"), ('е', "e"), ('ё', "ë"), ('ж', "zh"),
+    ('з', "z"), ('и', "i"), ('й', "y"), ('к', "k"),
+    ('л', "l"), ('м', "m"), ('н', "n"), ('о', "o"),
+    ('п', "p"), ('р', "r"), ('с', "s"), ('т', "t"),
+    ('у', "u"), ('ф', "f"), ('х', "kh"), ('ц', "ts"),
+    ('ч', "ch"), ('ш', "sh"), ('щ', "shch"), ('ъ', "”"),
+    ('ы', "y"), ('ь', "’"), ('э', "e"), ('ю', "yu"),
+    ('я', "ya"),
+];
+
+/// BGN/PCGN for Ukrainian, which is the national system of 2010. `є`, `ї`,
+/// `й`, `ю` and `я` are `"ye"`, `"yi"`, `"y"`, `"yu"` and `"ya"` at the start
+/// of a word, and `зг` is `"zgh"`.
+static BGN_PCGN_UKRAINIAN: [(char, &str); 33] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "h"),
+    ('ґ', "g"), ('д', "d"), ('е', "e"), ('є', "ie"),
+    ('ж', "zh"), ('з', "z"), ('и', "y"), ('і', "i"),
+    ('ї', "i"), ('й', "i"), ('к', "k"), ('л', "l"),
+    ('м', "m"), ('н', "n"), ('о', "o"), ('п', "p"),
+    ('р', "r"), ('с', "s"), ('т', "t"), ('у', "u"),
+    ('ф', "f"), ('х', "kh"), ('ц', "ts"), ('ч', "ch"),
+    ('ш', "sh"), ('щ', "shch"), ('ь', ""), ('ю', "iu"),
+    ('я', "ia"),
+];
+
+/// BGN/PCGN for Belarusian.
+static BGN_PCGN_BELARUSIAN: [(char, &str); 32] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "h"),
+    ('д', "d"), ('е', "ye"), ('ё', "yo"), ('ж', "zh"),
+    ('з', "z"), ('і', "i"), ('й', "y"), ('к', "k"),
+    ('л', "l"), ('м', "m"), ('н', "n"), ('о', "o"),
+    ('п', "p"), ('р', "r"), ('с', "s"), ('т', "t"),
+    ('у', "u"), ('ў', "w"), ('ф', "f"), ('х', "kh"),
+    ('ц', "ts"), ('ч', "ch"), ('ш', "sh"), ('ы', "y"),
+    ('ь', "’"), ('э', "e"), ('ю', "yu"), ('я', "ya"),
+];
+
+/// BGN/PCGN for Bulgarian, which is the Streamlined System of 2009. `ия` is
+/// `"ia"` at the end of a word.
+static BGN_PCGN_BULGARIAN: [(char, &str); 30] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"),
+    ('д', "d"), ('е', "e"), ('ж', "zh"), ('з', "z"),
+    ('и', "i"), ('й', "y"), ('к', "k"), ('л', "l"),
+    ('м', "m"), ('н', "n"), ('о', "o"), ('п', "p"),
+    ('р', "r"), ('с', "s"), ('т', "t"), ('у', "u"),
+    ('ф', "f"), ('х', "h"), ('ц', "ts"), ('ч', "ch"),
+    ('ш', "sh"), ('щ', "sht"), ('ъ', "a"), ('ь', "y"),
+    ('ю', "yu"), ('я', "ya"),
+];
+
+/// BGN/PCGN for Serbian, which is the Latin alphabet of Serbian.
+static BGN_PCGN_SERBIAN: [(char, &str); 30] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"),
+    ('д', "d"), ('ђ', "đ"), ('е', "e"), ('ж', "ž"),
+    ('з', "z"), ('и', "i"), ('ј', "j"), ('к', "k"),
+    ('л', "l"), ('љ', "lj"), ('м', "m"), ('н', "n"),
+    ('њ', "nj"), ('о', "o"), ('п', "p"), ('р', "r"),
+    ('с', "s"), ('т', "t"), ('ћ', "ć"), ('у', "u"),
+    ('ф', "f"), ('х', "h"), ('ц', "c"), ('ч', "č"),
+    ('џ', "dž"), ('ш', "š"),
+];
+
+/// The ASCII spellings of the diacritics and apostrophes of BGN/PCGN, as in
+/// the Latin alphabet of Serbian without diacritics.
+static BGN_PCGN_ASCII: [(&str, &str); 10] = [
+    ("ë", "e"), ("yë", "ye"), ("’", "'"), ("”", "\""),
+    ("đ", "dj"), ("ž", "z"), ("ć", "c"), ("č", "c"),
+    ("dž", "dz"), ("š", "s"),
+];
+
+/// ICAO Doc 9303.
+static ICAO: [(char, &str); 47] = [
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"),
+    ('ґ', "g"), ('д', "d"), ('ѓ', "g"), ('ђ', "d"),
+    ('е', "e"), ('ё', "e"), ('є', "ie"), ('ж', "zh"),
+    ('з', "z"), ('ѕ', "dz"), ('и', "i"), ('і', "i"),
+    ('ї', "i"), ('й', "i"), ('ј', "j"), ('к', "k"),
+    ('л', "l"), ('љ', "lj"), ('м', "m"), ('н', "n"),
+    ('њ', "nj"), ('о', "o"), ('п', "p"), ('р', "r"),
+    ('с', "s"), ('т', "t"), ('ќ', "k"), ('ћ', "c"),
+    ('у', "u"), ('ў', "u"), ('ф', "f"), ('х', "kh"),
+    ('ц', "ts"), ('ч', "ch"), ('џ', "dz"), ('ш', "sh"),
+    ('щ', "shch"), ('ъ', "ie"), ('ы', "y"), ('ь', ""),
+    ('э', "e"), ('ю', "iu"), ('я', "ia"),
+];
+
+/// The letters of ICAO Doc 9303 that differ in Ukrainian.
+static ICAO_UKRAINIAN: [(char, &str); 2] = [('г', "h"), ('и', "y")];
+
+/// The letters of ICAO Doc 9303 that differ in Belarusian.
+static ICAO_BELARUSIAN: [(char, &str); 1] = [('г', "h")];
+
+/// A transliterator for the Cyrillic script, which follows one of the
+/// standards of `CyrillicScheme`, with the rules of a `CyrillicLanguage`.
+///
+/// Letters are spelled depending on their position in the word where the
+/// standard requires it, such as `е` at the start of a Russian word in
+/// BGN/PCGN. The spellings of capitals are capitalized, or in capitals if
+/// the letter next to them is a capital too. Other characters are
+/// transliterated like `unidecode()` does.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = CyrillicTransliterator::new();
+/// assert_eq!(t.transliterate("Ельцин"), "Eltsin");
+///
+/// let t = CyrillicTransliterator::new()
+///     .scheme(CyrillicScheme::Icao)
+///     .language(CyrillicLanguage::Ukrainian);
+/// assert_eq!(t.transliterate("Григорій"), "Hryhorii");
+///
+/// let t = CyrillicTransliterator::new().scheme(CyrillicScheme::BgnPcgn);
+/// assert_eq!(t.transliterate("Ельцин"), "Yel'tsin");
+/// assert_eq!(t.diacritics(true).transliterate("Ельцин"), "Yel’tsin");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CyrillicTransliterator {
+    scheme: CyrillicScheme,
+    language: CyrillicLanguage,
+    diacritics: bool,
+}
+
+impl CyrillicTransliterator {
+    /// Creates a transliterator with ICAO Doc 9303 for Russian, whose output
+    /// is ASCII.
+    pub fn new() -> CyrillicTransliterator {
+        CyrillicTransliterator {
+            scheme: CyrillicScheme::Icao,
+            language: CyrillicLanguage::Russian,
+            diacritics: false,
+        }
+    }
+
+    /// Sets the standard of transliteration.
+    pub fn scheme(mut self, scheme: CyrillicScheme) -> CyrillicTransliterator {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Sets the language of the text.
+    pub fn language(
+        mut self,
+        language: CyrillicLanguage,
+    ) -> CyrillicTransliterator {
+        self.language = language;
+        self
+    }
+
+    /// Sets whether BGN/PCGN keeps the diacritics and apostrophes of the
+    /// standards instead of replacing them with ASCII, which is off by
+    /// default. ISO 9 always keeps them.
+    pub fn diacritics(mut self, diacritics: bool) -> CyrillicTransliterator {
+        self.diacritics = diacritics;
+        self
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, s: &str) -> String {
+        let chars = s.chars().collect::<Vec<_>>();
+        let mut out = String::with_capacity(s.len());
+        for (i, &ch) in chars.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|j| chars[j]);
+            let next = chars.get(i + 1).cloned();
+            let lower = to_lower(ch);
+            let spelling = match self.spelling(lower) {
+                Some(s) => self.ascii(self.position(s, lower, prev, next)),
+                None if self.is_omitted(ch, prev, next) => continue,
+                None => {
+                    out.push_str(unidecode_char(ch));
+                    continue;
+                },
+            };
+            if lower == ch {
+                out.push_str(spelling);
+                continue;
+            }
+            let capitals = next.is_some_and(char::is_uppercase)
+                || (prev.is_some_and(char::is_uppercase)
+                    && !next.is_some_and(char::is_lowercase));
+            let mut letters = spelling.chars();
+            if let Some(first) = letters.next() {
+                out.extend(first.to_uppercase());
+                if capitals {
+                    letters.for_each(|c| out.extend(c.to_uppercase()));
+                } else {
+                    out.push_str(letters.as_str());
+                }
+            }
+        }
+        out
+    }
+
+    /// Returns the spelling of a lowercase letter, before the rules depending
+    /// on its position.
+    fn spelling(&self, lower: char) -> Option<&'static str> {
+        use self::CyrillicLanguage::*;
+        use self::CyrillicScheme::*;
+        let tables: [&[(char, &str)]; 2] = match (self.scheme, self.language) {
+            (Iso9, _) => [&ISO_9, &[]],
+            (Gost779B, Ukrainian) => [&GOST_7_79_B_UKRAINIAN, &GOST_7_79_B],
+            (Gost779B, Bulgarian) => [&GOST_7_79_B_BULGARIAN, &GOST_7_79_B],
+            (Gost779B, _) => [&GOST_7_79_B, &[]],
+            (BgnPcgn, Russian) => [&BGN_PCGN_RUSSIAN, &[]],
+            (BgnPcgn, Ukrainian) => [&BGN_PCGN_UKRAINIAN, &BGN_PCGN_RUSSIAN],
+            (BgnPcgn, Belarusian) => [&BGN_PCGN_BELARUSIAN, &BGN_PCGN_RUSSIAN],
+            (BgnPcgn, Bulgarian) => [&BGN_PCGN_BULGARIAN, &BGN_PCGN_RUSSIAN],
+            (BgnPcgn, Serbian) => [&BGN_PCGN_SERBIAN, &BGN_PCGN_RUSSIAN],
+            (Icao, Ukrainian) => [&ICAO_UKRAINIAN, &ICAO],
+            (Icao, Belarusian) => [&ICAO_BELARUSIAN, &ICAO],
+            (Icao, _) => [&ICAO, &[]],
+        };
+        tables
+            .iter()
+            .flat_map(|table| table.iter())
+            .find(|&&(c, _)| c == lower)
+            .map(|&(_, s)| s)
+    }
+
+    /// Applies the rules depending on the position of a letter in its word.
+    fn position(
+        &self,
+        spelling: &'static str,
+        lower: char,
+        prev: Option<char>,
+        next: Option<char>,
+    ) -> &'static str {
+        use self::CyrillicLanguage::*;
+        use self::CyrillicScheme::*;
+        // Apostrophes are part of Ukrainian words
+        let prev = prev.map(to_lower).filter(|&c| is_letter(c));
+        let next = next.map(to_lower).filter(|&c| is_letter(c));
+        let after = |letters: &str| prev.map_or(true, |c| letters.contains(c));
+        match (self.scheme, self.language, lower) {
+            (BgnPcgn, Russian, 'е') if after("аеёиоуыэюяйъь") => "ye",
+            (BgnPcgn, Russian, 'ё') if after("аеёиоуыэюяйъь") => "yë",
+            (BgnPcgn, Ukrainian, 'є' | 'ї' | 'й' | 'ю' | 'я')
+                if prev.is_none() =>
+            {
+                match lower {
+                    'є' => "ye",
+                    'ї' => "yi",
+                    'й' => "y",
+                    'ю' => "yu",
+                    _ => "ya",
+                }
+            },
+            (BgnPcgn, Ukrainian, 'г') if prev == Some('з') => "gh",
+            (BgnPcgn, Bulgarian, 'я')
+                if prev == Some('и') && next.is_none() =>
+            {
+                "a"
+            },
+            (Gost779B, _, 'ц') => {
+                let next = next.and_then(|c| self.spelling(c));
+                match next {
+                    Some(s) if s.starts_with(['i', 'e', 'y', 'j']) => "c",
+                    _ => spelling,
+                }
+            },
+            _ => spelling,
+        }
+    }
+
+    /// Replaces a spelling of BGN/PCGN with ASCII, unless the diacritics are
+    /// kept.
+    fn ascii(&self, spelling: &'static str) -> &'static str {
+        if self.scheme != CyrillicScheme::BgnPcgn || self.diacritics {
+            return spelling;
+        }
+        BGN_PCGN_ASCII
+            .iter()
+            .find(|&&(s, _)| s == spelling)
+            .map_or(spelling, |&(_, ascii)| ascii)
+    }
+
+    /// Returns whether a character is left out, such as the apostrophe of
+    /// Ukrainian words in the schemes that omit it.
+    fn is_omitted(
+        &self,
+        ch: char,
+        prev: Option<char>,
+        next: Option<char>,
+    ) -> bool {
+        use self::CyrillicScheme::*;
+        let cyrillic = |c: Option<char>| {
+            c.is_some_and(|c| self.spelling(to_lower(c)).is_some())
+        };
+        self.language == CyrillicLanguage::Ukrainian
+            && matches!(self.scheme, BgnPcgn | Icao)
+            && matches!(ch, '\'' | '’' | 'ʼ')
+            && cyrillic(prev)
+            && cyrillic(next)
+    }
+}
+
+impl Default for CyrillicTransliterator {
+    fn default() -> CyrillicTransliterator {
+        CyrillicTransliterator::new()
+    }
+}
+
+/// Returns whether a character is a letter or an apostrophe.
+fn is_letter(ch: char) -> bool {
+    ch.is_alphabetic() || matches!(ch, '\'' | '’' | 'ʼ')
+}
+
+/// Returns the lowercase of a Cyrillic letter.
+fn to_lower(ch: char) -> char {
+    ch.to_lowercase().next().unwrap_or(ch)
+}
@@ -0,0 +1,45 @@
+use emoji_data::EMOJI;
+
+/// Finds the longest emoji sequence at the start of `s`, returning its short
+/// name and the length in bytes of the matched input. Variation selectors
+/// (U+FE0F) in the input are ignored, as they are stripped from the table,
+/// except after a first character displayed as text by default, where the
+/// table keeps it and the input must have it.
+pub fn find(s: &str) -> Option<(&'static str, usize)> {
+    let first = s.chars().next()?;
+    let mut buf = [0; 4];
+    let prefix = &*first.encode_utf8(&mut buf);
+
+    let start = EMOJI.partition_point(|&(seq, _)| seq < prefix);
+    let mut best: Option<(&'static str, usize)> = None;
+    for &(seq, name) in EMOJI[start..].iter() {
+        if !seq.starts_with(prefix) {
+            break;
+        }
+        if let Some(len) = match_len(s, seq) {
+            if best.map_or(true, |(_, best_len)| len > best_len) {
+                best = Some((name, len));
+            }
+        }
+    }
+    best
+}
+
+/// Returns the length in bytes of the prefix of `s` that spells out `seq`,
+/// including any trailing variation selector.
+fn match_len(s: &str, seq: &str) -> Option<usize> {
+    let mut expected = seq.chars();
+    let mut next = expected.next();
+    for (i, ch) in s.char_indices() {
+        match next {
+            Some(e) if e == ch => next = expected.next(),
+            _ if ch == '\u{FE0F}' => {},
+            None => return Some(i),
+            Some(_) => return None,
+        }
+    }
+    match next {
+        None => Some(s.len()),
+        Some(_) => None,
+    }
+}
@@ -0,0 +1,72 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use blocks_data::BLOCKS;
+
+/// The error returned when a character cannot be transliterated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnidecodeError {
+    ch: char,
+    byte_index: usize,
+    char_index: usize,
+}
+
+impl UnidecodeError {
+    pub(crate) fn new(
+        ch: char,
+        byte_index: usize,
+        char_index: usize,
+    ) -> UnidecodeError {
+        UnidecodeError { ch, byte_index, char_index }
+    }
+
+    /// The character that could not be transliterated.
+    pub fn ch(&self) -> char {
+        self.ch
+    }
+
+    /// The byte offset of the character in the input string.
+    pub fn byte_index(&self) -> usize {
+        self.byte_index
+    }
+
+    /// The index of the character in the input string, counted in `char`s.
+    pub fn char_index(&self) -> usize {
+        self.char_index
+    }
+
+    /// The name of the Unicode block the character belongs to, such as
+    /// `"Limbu"`, or `"No_Block"` if it lies outside of any block. The blocks
+    /// are those of the Unicode version of the latest data set.
+    pub fn block(&self) -> &'static str {
+        let u = self.ch as u32;
+        match BLOCKS.binary_search_by(|&(start, end, _)| {
+            if end < u {
+                Ordering::Less
+            } else if start > u {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }) {
+            Ok(i) => BLOCKS[i].2,
+            Err(_) => "No_Block",
+        }
+    }
+}
+
+impl fmt::Display for UnidecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot transliterate {:?} (U+{:04X}, {}) at byte {}",
+            self.ch,
+            self.ch as u32,
+            self.block(),
+            self.byte_index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnidecodeError {}
@@ -0,0 +1,375 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use unidecode_char;
+
+/// A romanization of Greek.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GreekScheme {
+    /// ELOT 743, the Greek standard that ISO 843 is based on, such as
+    /// `"Evangelio"` for `"Ευαγγέλιο"`. A rough breathing is `"h"`.
+    Elot743,
+    /// Beta Code, which encodes every letter and diacritic of polytonic Greek
+    /// in ASCII, such as `"eu)agge/lion"` for `"εὐαγγέλιον"`, with `*` before
+    /// capitals.
+    BetaCode,
+}
+
+/// The Beta Code of the letters.
+static BETA_CODE: [(char, char); 25] = [
+    ('α', 'a'), ('β', 'b'), ('γ', 'g'), ('δ', 'd'),
+    ('ε', 'e'), ('ζ', 'z'), ('η', 'h'), ('θ', 'q'),
+    ('ι', 'i'), ('κ', 'k'), ('λ', 'l'), ('μ', 'm'),
+    ('ν', 'n'), ('ξ', 'c'), ('ο', 'o'), ('π', 'p'),
+    ('ρ', 'r'), ('σ', 's'), ('ς', 's'), ('τ', 't'),
+    ('υ', 'u'), ('φ', 'f'), ('χ', 'x'), ('ψ', 'y'),
+    ('ω', 'w'),
+];
+
+/// ELOT 743, letter by letter. The digraphs are handled separately.
+static ELOT_743: [(char, &str); 25] = [
+    ('α', "a"), ('β', "v"), ('γ', "g"), ('δ', "d"),
+    ('ε', "e"), ('ζ', "z"), ('η', "i"), ('θ', "th"),
+    ('ι', "i"), ('κ', "k"), ('λ', "l"), ('μ', "m"),
+    ('ν', "n"), ('ξ', "x"), ('ο', "o"), ('π', "p"),
+    ('ρ', "r"), ('σ', "s"), ('ς', "s"), ('τ', "t"),
+    ('υ', "y"), ('φ', "f"), ('χ', "ch"), ('ψ', "ps"),
+    ('ω', "o"),
+];
+
+/// The letters with diacritics outside of U+1F00 to U+1FAF, in Beta Code,
+/// sorted by character. The letters with an oxia are escaped, as they are
+/// canonically equivalent to the letters with a tonos.
+static PRECOMPOSED: [(char, &str); 80] = [
+    ('Ά', "*/a"), ('Έ', "*/e"), ('Ή', "*/h"), ('Ί', "*/i"), ('Ό', "*/o"),
+    ('Ύ', "*/u"), ('Ώ', "*/w"), ('ΐ', "i+/"), ('Ϊ', "*+i"), ('Ϋ', "*+u"),
+    ('ά', "a/"), ('έ', "e/"), ('ή', "h/"), ('ί', "i/"), ('ΰ', "u+/"),
+    ('ϊ', "i+"), ('ϋ', "u+"), ('ό', "o/"), ('ύ', "u/"), ('ώ', "w/"), ('ϐ', "b"),
+    ('ϑ', "q"), ('ϕ', "f"), ('ϲ', "s"), ('Ϲ', "*s"), ('ᾰ', "a"), ('ᾱ', "a"),
+    ('ᾲ', "a\\|"), ('ᾳ', "a|"), ('ᾴ', "a/|"), ('ᾶ', "a="), ('ᾷ', "a=|"),
+    ('Ᾰ', "*a"), ('Ᾱ', "*a"), ('Ὰ', "*\\a"), ('\u{1fbb}', "*/a"), ('ᾼ', "*a|"),
+    ('ῂ', "h\\|"), ('ῃ', "h|"), ('ῄ', "h/|"), ('ῆ', "h="), ('ῇ', "h=|"),
+    ('Ὲ', "*\\e"), ('\u{1fc9}', "*/e"), ('Ὴ', "*\\h"), ('\u{1fcb}', "*/h"),
+    ('ῌ', "*h|"), ('ῐ', "i"), ('ῑ', "i"), ('ῒ', "i+\\"), ('\u{1fd3}', "i+/"),
+    ('ῖ', "i="), ('ῗ', "i+="), ('Ῐ', "*i"), ('Ῑ', "*i"), ('Ὶ', "*\\i"),
+    ('\u{1fdb}', "*/i"), ('ῠ', "u"), ('ῡ', "u"), ('ῢ', "u+\\"),
+    ('\u{1fe3}', "u+/"), ('ῤ', "r)"), ('ῥ', "r("), ('ῦ', "u="), ('ῧ', "u+="),
+    ('Ῠ', "*u"), ('Ῡ', "*u"), ('Ὺ', "*\\u"), ('\u{1feb}', "*/u"), ('Ῥ', "*(r"),
+    ('ῲ', "w\\|"), ('ῳ', "w|"), ('ῴ', "w/|"), ('ῶ', "w="), ('ῷ', "w=|"),
+    ('Ὸ', "*\\o"), ('\u{1ff9}', "*/o"), ('Ὼ', "*\\w"), ('\u{1ffb}', "*/w"),
+    ('ῼ', "*w|"),
+];
+
+/// The vowels of U+1F00 to U+1F6F and U+1F80 to U+1FAF, in blocks of 8
+/// characters, lowercase then uppercase.
+static BREATHINGS: [char; 7] = ['α', 'ε', 'η', 'ι', 'ο', 'υ', 'ω'];
+static IOTA_SUBSCRIPTS: [char; 3] = ['α', 'η', 'ω'];
+
+/// A letter with its diacritics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Letter {
+    /// The lowercase letter, without diacritics.
+    base: char,
+    upper: bool,
+    /// `'('` for a rough breathing and `')'` for a smooth one.
+    breathing: Option<char>,
+    /// `'/'`, `'\\'` or `'='` for an acute, grave or circumflex accent.
+    accent: Option<char>,
+    diaeresis: bool,
+    iota_subscript: bool,
+}
+
+impl Letter {
+    fn new(base: char, upper: bool) -> Letter {
+        Letter {
+            base,
+            upper,
+            breathing: None,
+            accent: None,
+            diaeresis: false,
+            iota_subscript: false,
+        }
+    }
+
+    /// Parses a letter in Beta Code.
+    fn from_beta_code(s: &str) -> Letter {
+        let mut letter = Letter::new(' ', false);
+        for c in s.chars() {
+            match c {
+                '*' => letter.upper = true,
+                '(' | ')' => letter.breathing = Some(c),
+                '/' | '\\' | '=' => letter.accent = Some(c),
+                '+' => letter.diaeresis = true,
+                '|' => letter.iota_subscript = true,
+                _ => {
+                    letter.base = BETA_CODE
+                        .iter()
+                        .find(|&&(_, b)| b == c)
+                        .map_or(c, |&(g, _)| g);
+                },
+            }
+        }
+        letter
+    }
+
+    /// Decomposes a Greek letter.
+    fn decompose(ch: char) -> Option<Letter> {
+        let u = ch as u32;
+        let diacritics = |letter: &mut Letter, k: u32| {
+            letter.breathing = Some(if k & 1 == 0 { ')' } else { '(' });
+            letter.accent = [None, Some('\\'), Some('/'), Some('=')]
+                [(k / 2) as usize];
+        };
+        match u {
+            0x3B1..=0x3C9 => Some(Letter::new(ch, false)),
+            0x391..=0x3A9 if u != 0x3A2 => {
+                let base = char::from_u32(u + 0x20)?;
+                Some(Letter::new(base, true))
+            },
+            0x1F00..=0x1F6F => {
+                let block = (u - 0x1F00) / 8;
+                let base = BREATHINGS[(block / 2) as usize];
+                let mut letter = Letter::new(base, block % 2 == 1);
+                diacritics(&mut letter, u % 8);
+                Some(letter)
+            },
+            0x1F70..=0x1F7D => {
+                let i = u - 0x1F70;
+                let base = BREATHINGS[(i / 2) as usize];
+                let mut letter = Letter::new(base, false);
+                letter.accent = Some(if i & 1 == 0 { '\\' } else { '/' });
+                Some(letter)
+            },
+            0x1F80..=0x1FAF => {
+                let block = (u - 0x1F80) / 8;
+                let base = IOTA_SUBSCRIPTS[(block / 2) as usize];
+                let mut letter = Letter::new(base, block % 2 == 1);
+                diacritics(&mut letter, u % 8);
+                letter.iota_subscript = true;
+                Some(letter)
+            },
+            _ => PRECOMPOSED
+                .binary_search_by_key(&ch, |&(c, _)| c)
+                .ok()
+                .map(|i| Letter::from_beta_code(PRECOMPOSED[i].1)),
+        }
+    }
+
+    /// Adds a combining diacritic, returning whether it is one.
+    fn combine(&mut self, mark: char) -> bool {
+        match mark {
+            '\u{300}' => self.accent = Some('\\'),
+            '\u{301}' => self.accent = Some('/'),
+            '\u{342}' => self.accent = Some('='),
+            '\u{308}' => self.diaeresis = true,
+            '\u{313}' => self.breathing = Some(')'),
+            '\u{314}' => self.breathing = Some('('),
+            '\u{345}' => self.iota_subscript = true,
+            _ => return false,
+        }
+        true
+    }
+
+    fn is_vowel(&self) -> bool {
+        BREATHINGS.contains(&self.base)
+    }
+
+    fn is_rough(&self) -> bool {
+        self.breathing == Some('(')
+    }
+}
+
+/// A character of the text, which is a letter or something else.
+#[derive(Debug, Clone, Copy)]
+enum Token {
+    Letter(Letter),
+    Other(char),
+}
+
+/// A transliterator for Greek, monotonic or polytonic, which romanizes it with
+/// ELOT 743 or encodes it in Beta Code.
+///
+/// ELOT 743 spells the digraphs depending on the letters around them: `αυ`,
+/// `ευ` and `ηυ` are `"av"`, `"ev"` and `"iv"` before vowels and voiced
+/// consonants, and `"af"`, `"ef"` and `"if"` otherwise, `μπ` is `"b"` at the
+/// start and end of a word and `"mp"` elsewhere, and `γγ`, `γξ` and `γχ` are
+/// `"ng"`, `"nx"` and `"nch"`. A diaeresis separates the letters of a digraph.
+/// Accents are dropped. Other characters are transliterated like `unidecode()`
+/// does.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = GreekTransliterator::new();
+/// assert_eq!(t.transliterate("Ευαγγέλιο"), "Evangelio");
+/// assert_eq!(t.transliterate("αυτός"), "aftos");
+///
+/// let t = GreekTransliterator::new().scheme(GreekScheme::BetaCode);
+/// assert_eq!(t.transliterate("μῆνιν ἄειδε"), "mh=nin a)/eide");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GreekTransliterator {
+    scheme: GreekScheme,
+}
+
+impl GreekTransliterator {
+    /// Creates a transliterator with ELOT 743.
+    pub fn new() -> GreekTransliterator {
+        GreekTransliterator {
+            scheme: GreekScheme::Elot743,
+        }
+    }
+
+    /// Sets the romanization.
+    pub fn scheme(mut self, scheme: GreekScheme) -> GreekTransliterator {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, s: &str) -> String {
+        let mut tokens: Vec<Token> = Vec::with_capacity(s.len());
+        for ch in s.chars() {
+            if let Some(Token::Letter(letter)) = tokens.last_mut() {
+                if letter.combine(ch) {
+                    continue;
+                }
+            }
+            tokens.push(match Letter::decompose(ch) {
+                Some(letter) => Token::Letter(letter),
+                None => Token::Other(ch),
+            });
+        }
+        let mut out = String::with_capacity(s.len());
+        match self.scheme {
+            GreekScheme::Elot743 => elot_743(&tokens, &mut out),
+            GreekScheme::BetaCode => tokens.iter().for_each(|token| {
+                match *token {
+                    Token::Letter(letter) => beta_code(letter, &mut out),
+                    Token::Other(ch) => out.push_str(unidecode_char(ch)),
+                }
+            }),
+        }
+        out
+    }
+}
+
+impl Default for GreekTransliterator {
+    fn default() -> GreekTransliterator {
+        GreekTransliterator::new()
+    }
+}
+
+/// Appends a letter in Beta Code, with the diacritics of capitals before them.
+fn beta_code(letter: Letter, out: &mut String) {
+    let base = BETA_CODE
+        .iter()
+        .find(|&&(g, _)| g == letter.base)
+        .map_or('?', |&(_, b)| b);
+    if letter.upper {
+        out.push('*');
+    } else {
+        out.push(base);
+    }
+    out.extend(letter.breathing);
+    if letter.diaeresis {
+        out.push('+');
+    }
+    out.extend(letter.accent);
+    if letter.upper {
+        out.push(base);
+    }
+    if letter.iota_subscript {
+        out.push('|');
+    }
+}
+
+/// Appends the tokens romanized with ELOT 743.
+fn elot_743(tokens: &[Token], out: &mut String) {
+    let letter = |i: usize| match tokens.get(i) {
+        Some(&Token::Letter(letter)) => Some(letter),
+        _ => None,
+    };
+    let mut i = 0;
+    while i < tokens.len() {
+        let first = match tokens[i] {
+            Token::Letter(letter) => letter,
+            Token::Other(ch) => {
+                out.push_str(unidecode_char(ch));
+                i += 1;
+                continue;
+            },
+        };
+        let start = i == 0 || letter(i - 1).is_none();
+        let next = letter(i + 1);
+        let after = letter(i + 2);
+        // A digraph with `υ` that neither a diaeresis nor an accent on the
+        // first vowel separates, unlike `"άυλος"`
+        let upsilon = next.filter(|l| {
+            l.base == 'υ' && !l.diaeresis && first.accent.is_none()
+        });
+        let (spelling, len): (&str, usize) = match (first.base, next) {
+            ('α' | 'ε' | 'η', Some(_)) if upsilon.is_some() => {
+                let voiced = after.is_some_and(|l| {
+                    l.is_vowel() || "βγδζλμνρ".contains(l.base)
+                });
+                let spelling = match (first.base, voiced) {
+                    ('α', true) => "av",
+                    ('α', false) => "af",
+                    ('ε', true) => "ev",
+                    ('ε', false) => "ef",
+                    (_, true) => "iv",
+                    (_, false) => "if",
+                };
+                (spelling, 2)
+            },
+            ('ο', Some(_)) if upsilon.is_some() => ("ou", 2),
+            ('γ', Some(l)) if l.base == 'γ' => ("ng", 2),
+            ('γ', Some(l)) if l.base == 'ξ' => ("nx", 2),
+            ('γ', Some(l)) if l.base == 'χ' => ("nch", 2),
+            ('μ', Some(l)) if l.base == 'π' => {
+                if start || after.is_none() {
+                    ("b", 2)
+                } else {
+                    ("mp", 2)
+                }
+            },
+            ('ρ', _) if first.is_rough() => ("rh", 1),
+            (base, _) => {
+                let spelling = ELOT_743
+                    .iter()
+                    .find(|&&(c, _)| c == base)
+                    .map_or("", |&(_, s)| s);
+                (spelling, 1)
+            },
+        };
+        // The rough breathing of a word starting with a vowel, which is
+        // written on the second letter of a diphthong
+        let rough = start
+            && first.is_vowel()
+            && (first.is_rough()
+                || next.is_some_and(|l| {
+                    matches!(l.base, 'ι' | 'υ') && l.is_rough()
+                }));
+        let following = letter(i + len);
+        let preceding = i.checked_sub(1).and_then(letter);
+        // A digraph whose second letter is a capital, such as `"ΟΥ"`, is
+        // written in capitals even on its own
+        let capitals = following.is_some_and(|l| l.upper)
+            || (len == 2 && next.is_some_and(|l| l.upper))
+            || (preceding.is_some_and(|l| l.upper)
+                && following.map_or(true, |l| l.upper));
+        let mut chars = if rough { "h" } else { "" }
+            .chars()
+            .chain(spelling.chars());
+        if first.upper {
+            if let Some(c) = chars.next() {
+                out.push(c.to_ascii_uppercase());
+            }
+        }
+        for c in chars {
+            out.push(if capitals { c.to_ascii_uppercase() } else { c });
+        }
+        i += len;
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "cjk")]
+use han_data::{CANTONESE, KOREAN, VIETNAMESE};
+use unidecode_char;
+
+/// A reading of Han characters, in one of the languages that write or wrote
+/// them, for use in place of the Mandarin readings of the data set.
+///
+/// The readings are transliterated like the data set transliterates Han
+/// characters, capitalized, without tones and followed by a space. The Korean
+/// readings cover the Hanja of the Korean collation of CLDR, and the Cantonese
+/// and Vietnamese readings the common characters of the vendored subset of the
+/// Unihan database only. `reading()` returns `None` for the characters
+/// without a reading in the language, which `unidecode_char()` transliterates
+/// like the free function `unidecode_char()` does, in Mandarin. The readings
+/// require the `cjk` feature.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// assert_eq!(unidecode_char('北'), "Bei ");
+/// assert_eq!(HanReading::Cantonese.unidecode_char('北'), "Bak ");
+/// assert_eq!(HanReading::Vietnamese.unidecode_char('北'), "Bac ");
+/// assert_eq!(HanReading::Korean.unidecode_char('北'), "Buk ");
+///
+/// assert_eq!(HanReading::Korean.reading('龘'), None);
+/// assert_eq!(HanReading::Korean.unidecode_char('龘'), "Ta ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HanReading {
+    /// Mandarin, in pinyin, like the data set.
+    Mandarin,
+    /// Cantonese, in Jyutping.
+    Cantonese,
+    /// The Sino-Vietnamese (Hán-Việt) readings, in the Vietnamese alphabet
+    /// without diacritics.
+    Vietnamese,
+    /// The Sino-Korean readings of Hanja, in the Revised Romanization.
+    Korean,
+}
+
+impl HanReading {
+    /// Returns the reading of a Han character in this language, or `None` if
+    /// the crate has no reading of the character in the language, or if it is
+    /// not a Han character. The Mandarin readings are those of the data set.
+    pub fn reading(self, ch: char) -> Option<&'static str> {
+        if self == HanReading::Mandarin {
+            let reading = unidecode_char(ch);
+            return Some(reading).filter(|r| is_han(ch) && !r.is_empty());
+        }
+        let readings = self.readings();
+        match readings.binary_search_by_key(&ch, |&(c, _)| c) {
+            Ok(i) => Some(readings[i].1),
+            Err(_) => None,
+        }
+    }
+
+    /// Works like `unidecode_char()`, with the readings of this language.
+    /// Characters without a reading in the language fall back to the Mandarin
+    /// reading of the data set.
+    pub fn unidecode_char(self, ch: char) -> &'static str {
+        self.reading(ch).unwrap_or_else(|| unidecode_char(ch))
+    }
+
+    /// Works like `unidecode()`, with the readings of this language.
+    #[cfg(feature = "alloc")]
+    pub fn unidecode(self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        for ch in s.chars() {
+            out.push_str(self.unidecode_char(ch));
+        }
+        out
+    }
+
+    /// The readings of the language other than Mandarin, sorted by character.
+    fn readings(self) -> &'static [(char, &'static str)] {
+        match self {
+            HanReading::Mandarin => &[],
+            #[cfg(feature = "cjk")]
+            HanReading::Cantonese => &CANTONESE,
+            #[cfg(feature = "cjk")]
+            HanReading::Vietnamese => &VIETNAMESE,
+            #[cfg(feature = "cjk")]
+            HanReading::Korean => &KOREAN,
+            #[cfg(not(feature = "cjk"))]
+            _ => &[],
+        }
+    }
+}
+
+/// Returns whether a character is a CJK ideograph.
+pub(crate) fn is_han(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
@@ -0,0 +1,743 @@
+use alloc::string::String;
+use core::iter::Peekable;
+use core::str::Chars;
+
+use unidecode_char;
+
+/// The Brahmic scripts transliterated syllable by syllable, in the order of
+/// their blocks of 128 code points from U+0900.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndicScript {
+    /// Devanagari, used for Hindi, Marathi, Nepali and Sanskrit.
+    Devanagari,
+    /// Bengali, also used for Assamese.
+    Bengali,
+    /// Gurmukhi, used for Punjabi.
+    Gurmukhi,
+    /// Gujarati.
+    Gujarati,
+    /// Oriya, also known as Odia.
+    Oriya,
+    /// Tamil.
+    Tamil,
+    /// Telugu.
+    Telugu,
+    /// Kannada.
+    Kannada,
+    /// Malayalam.
+    Malayalam,
+}
+
+const SCRIPTS: [IndicScript; 9] = [
+    IndicScript::Devanagari,
+    IndicScript::Bengali,
+    IndicScript::Gurmukhi,
+    IndicScript::Gujarati,
+    IndicScript::Oriya,
+    IndicScript::Tamil,
+    IndicScript::Telugu,
+    IndicScript::Kannada,
+    IndicScript::Malayalam,
+];
+
+/// The offsets of the letters of each script that are spelled like the letter
+/// of Devanagari at the same offset, as bits. Other offsets are unassigned or
+/// hold letters of their own, such as the Malayalam fractions.
+static LETTERS: [u128; 9] = [
+    0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+    0x7ffb_ffcf_b080_799f_f3c5_fdff_fff9_9fef,
+    0x007b_ffc0_5e02_3987_d36d_fdff_fff9_87ee,
+    0xfe03_ffcf_0001_3bbf_f3ed_fdff_fffb_bfee,
+    0x00fb_ffcf_b0e0_399f_f3ed_fdff_fff9_9fee,
+    0x07fb_ffc0_0081_3dc7_c3ff_c718_d63d_c7ec,
+    0xff80_ffcf_0060_3ddf_f3ff_fdff_fffd_dfff,
+    0x000a_ffcf_4060_3ddf_f3ef_fdff_fffd_dfff,
+    0xfffb_ffcf_00f0_fddf_ffff_ffff_fffd_dfff,
+];
+
+impl IndicScript {
+    /// Returns the script of a character and its offset in the block.
+    fn of(ch: char) -> Option<(IndicScript, u32)> {
+        let u = ch as u32;
+        if (0x0900..0x0D80).contains(&u) {
+            Some((SCRIPTS[(u as usize - 0x0900) >> 7], u & 0x7F))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the character at an offset in the block of the script, if the
+    /// script has the letter spelled like the one of Devanagari.
+    fn char(self, offset: u32) -> Option<char> {
+        if LETTERS[self as usize] >> offset & 1 == 0 {
+            return None;
+        }
+        core::char::from_u32(0x0900 + ((self as u32) << 7) + offset)
+    }
+
+    /// Whether the inherent vowel of the last consonant of a word is silent,
+    /// as in the modern languages written in the script.
+    fn deletes_schwa(self) -> bool {
+        matches!(
+            self,
+            IndicScript::Devanagari
+                | IndicScript::Bengali
+                | IndicScript::Gurmukhi
+                | IndicScript::Gujarati
+        )
+    }
+
+    /// Whether the script distinguishes short and long `e` and `o`.
+    fn is_dravidian(self) -> bool {
+        matches!(
+            self,
+            IndicScript::Tamil
+                | IndicScript::Telugu
+                | IndicScript::Kannada
+                | IndicScript::Malayalam
+        )
+    }
+}
+
+/// The romanization produced by an `IndicTransliterator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndicScheme {
+    /// A readable romanization in the style of the data set, which does not
+    /// distinguish retroflex from dental consonants. It cannot be reversed.
+    Readable,
+    /// The Harvard-Kyoto convention, such as `"saMskRtam"`.
+    HarvardKyoto,
+    /// ITRANS, such as `"saMskRRitam"`.
+    Itrans,
+    /// The Velthuis convention, such as `"sa.msk.rtam"`.
+    Velthuis,
+}
+
+impl IndicScheme {
+    /// The spellings of the letters in the scheme, by their offset in the
+    /// block of a script. Vowel signs are spelled like independent vowels.
+    fn spellings(self) -> &'static [(u32, &'static str)] {
+        match self {
+            IndicScheme::Readable => &[],
+            IndicScheme::HarvardKyoto => &HARVARD_KYOTO,
+            IndicScheme::Itrans => &ITRANS,
+            IndicScheme::Velthuis => &VELTHUIS,
+        }
+    }
+
+    /// Returns the spelling of the letter at an offset, if the scheme has one.
+    fn spelling(self, offset: u32) -> Option<&'static str> {
+        let offset = SIGNS
+            .iter()
+            .find(|&&(_, sign)| sign == offset)
+            .map_or(offset, |&(vowel, _)| vowel);
+        let spellings = self.spellings();
+        spellings
+            .binary_search_by_key(&offset, |&(o, _)| o)
+            .ok()
+            .map(|i| spellings[i].1)
+    }
+
+    /// The string separating two spellings that would otherwise be read as a
+    /// single one, such as the `k` and `h` of `"k{}h"`.
+    fn separator(self) -> &'static str {
+        match self {
+            IndicScheme::Readable => "",
+            _ => "{}",
+        }
+    }
+
+    /// Whether `next`, following `last`, would be read as part of a longer
+    /// spelling.
+    fn joins(self, last: &str, next: &str) -> bool {
+        !last.is_empty()
+            && self.spellings().iter().any(|&(_, s)| {
+                s.len() > last.len()
+                    && s.starts_with(last)
+                    && next.starts_with(&s[last.len()..])
+            })
+    }
+}
+
+/// The offsets of the independent vowels and of their vowel signs.
+static SIGNS: [(u32, u32); 17] = [
+    (0x06, 0x3E), (0x07, 0x3F), (0x08, 0x40), (0x09, 0x41),
+    (0x0A, 0x42), (0x0B, 0x43), (0x60, 0x44), (0x0D, 0x45),
+    (0x0E, 0x46), (0x0F, 0x47), (0x10, 0x48), (0x11, 0x49),
+    (0x12, 0x4A), (0x13, 0x4B), (0x14, 0x4C), (0x0C, 0x62),
+    (0x61, 0x63),
+];
+
+/// The Harvard-Kyoto convention, with `E` and `O` for the short `e` and `o`
+/// of the Dravidian scripts.
+///
+/// The schemes are extended alike with `~e` and `~o` for the candra `e` and
+/// `o` of Devanagari and Gujarati, `~a` for the candra `a` of Devanagari,
+/// `_n` and `_r` for the alveolar `n` and `r` of Tamil and Malayalam, and `zh`
+/// for their `zh`.
+static HARVARD_KYOTO: [(u32, &str); 63] = [
+    (0x01, "~"), (0x02, "M"), (0x03, "H"), (0x05, "a"),
+    (0x06, "A"), (0x07, "i"), (0x08, "I"), (0x09, "u"),
+    (0x0A, "U"), (0x0B, "R"), (0x0C, "lR"), (0x0D, "~e"),
+    (0x0E, "E"), (0x0F, "e"), (0x10, "ai"), (0x11, "~o"),
+    (0x12, "O"), (0x13, "o"), (0x14, "au"), (0x15, "k"),
+    (0x16, "kh"), (0x17, "g"), (0x18, "gh"), (0x19, "G"),
+    (0x1A, "c"), (0x1B, "ch"), (0x1C, "j"), (0x1D, "jh"),
+    (0x1E, "J"), (0x1F, "T"), (0x20, "Th"), (0x21, "D"),
+    (0x22, "Dh"), (0x23, "N"), (0x24, "t"), (0x25, "th"),
+    (0x26, "d"), (0x27, "dh"), (0x28, "n"), (0x29, "_n"),
+    (0x2A, "p"), (0x2B, "ph"), (0x2C, "b"), (0x2D, "bh"),
+    (0x2E, "m"), (0x2F, "y"), (0x30, "r"), (0x31, "_r"),
+    (0x32, "l"), (0x33, "L"), (0x34, "zh"), (0x35, "v"),
+    (0x36, "z"), (0x37, "S"), (0x38, "s"), (0x39, "h"),
+    (0x3D, "'"), (0x50, "OM"), (0x60, "RR"), (0x61, "lRR"),
+    (0x64, "|"), (0x65, "||"), (0x72, "~a"),
+];
+
+/// ITRANS, with `.e` and `.o` for the short `e` and `o` of the Dravidian
+/// scripts.
+static ITRANS: [(u32, &str); 71] = [
+    (0x01, ".N"), (0x02, "M"), (0x03, "H"), (0x05, "a"),
+    (0x06, "aa"), (0x07, "i"), (0x08, "ii"), (0x09, "u"),
+    (0x0A, "uu"), (0x0B, "RRi"), (0x0C, "LLi"), (0x0D, "~e"),
+    (0x0E, ".e"), (0x0F, "e"), (0x10, "ai"), (0x11, "~o"),
+    (0x12, ".o"), (0x13, "o"), (0x14, "au"), (0x15, "k"),
+    (0x16, "kh"), (0x17, "g"), (0x18, "gh"), (0x19, "~N"),
+    (0x1A, "ch"), (0x1B, "Ch"), (0x1C, "j"), (0x1D, "jh"),
+    (0x1E, "~n"), (0x1F, "T"), (0x20, "Th"), (0x21, "D"),
+    (0x22, "Dh"), (0x23, "N"), (0x24, "t"), (0x25, "th"),
+    (0x26, "d"), (0x27, "dh"), (0x28, "n"), (0x29, "_n"),
+    (0x2A, "p"), (0x2B, "ph"), (0x2C, "b"), (0x2D, "bh"),
+    (0x2E, "m"), (0x2F, "y"), (0x30, "r"), (0x31, "_r"),
+    (0x32, "l"), (0x33, "L"), (0x34, "zh"), (0x35, "v"),
+    (0x36, "sh"), (0x37, "Sh"), (0x38, "s"), (0x39, "h"),
+    (0x3D, ".a"), (0x50, "OM"), (0x58, "q"), (0x59, "K"),
+    (0x5A, "G"), (0x5B, "z"), (0x5C, ".D"), (0x5D, ".Dh"),
+    (0x5E, "f"), (0x5F, "Y"), (0x60, "RRI"), (0x61, "LLI"),
+    (0x64, "|"), (0x65, "||"), (0x72, "~a"),
+];
+
+/// The Velthuis convention, with `.e` and `.o` for the short `e` and `o` of
+/// the Dravidian scripts.
+static VELTHUIS: [(u32, &str); 71] = [
+    (0x01, "/"), (0x02, ".m"), (0x03, ".h"), (0x05, "a"),
+    (0x06, "aa"), (0x07, "i"), (0x08, "ii"), (0x09, "u"),
+    (0x0A, "uu"), (0x0B, ".r"), (0x0C, ".l"), (0x0D, "~e"),
+    (0x0E, ".e"), (0x0F, "e"), (0x10, "ai"), (0x11, "~o"),
+    (0x12, ".o"), (0x13, "o"), (0x14, "au"), (0x15, "k"),
+    (0x16, "kh"), (0x17, "g"), (0x18, "gh"), (0x19, "\"n"),
+    (0x1A, "c"), (0x1B, "ch"), (0x1C, "j"), (0x1D, "jh"),
+    (0x1E, "~n"), (0x1F, ".t"), (0x20, ".th"), (0x21, ".d"),
+    (0x22, ".dh"), (0x23, ".n"), (0x24, "t"), (0x25, "th"),
+    (0x26, "d"), (0x27, "dh"), (0x28, "n"), (0x29, "_n"),
+    (0x2A, "p"), (0x2B, "ph"), (0x2C, "b"), (0x2D, "bh"),
+    (0x2E, "m"), (0x2F, "y"), (0x30, "r"), (0x31, "_r"),
+    (0x32, "l"), (0x33, "L"), (0x34, "zh"), (0x35, "v"),
+    (0x36, "\"s"), (0x37, ".s"), (0x38, "s"), (0x39, "h"),
+    (0x3D, ".a"), (0x50, "O"), (0x58, "q"), (0x59, ".kh"),
+    (0x5A, ".g"), (0x5B, "z"), (0x5C, "R"), (0x5D, "Rh"),
+    (0x5E, "f"), (0x5F, ".y"), (0x60, ".rr"), (0x61, ".ll"),
+    (0x64, "|"), (0x65, "||"), (0x72, "~a"),
+];
+
+/// The role of a character in a syllable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Letter {
+    /// A consonant, with an inherent vowel unless it is followed by a vowel
+    /// sign or a virama.
+    Consonant(&'static str),
+    /// A consonant without an inherent vowel, such as the Malayalam chillus.
+    Dead(&'static str),
+    /// An independent vowel.
+    Vowel(&'static str),
+    /// A dependent vowel sign, replacing the inherent vowel.
+    Sign(&'static str),
+    /// The virama, suppressing the inherent vowel.
+    Virama,
+    /// The nukta, modifying the preceding consonant.
+    Nukta,
+    /// The anusvara, a nasal assimilated to the following consonant.
+    Anusvara,
+    /// The candrabindu and other marks of nasal vowels.
+    Nasal(&'static str),
+    /// The visarga.
+    Visarga(&'static str),
+    /// The Gurmukhi addak, doubling the following consonant.
+    Addak,
+    /// A digit, a danda or another sign which ends a word.
+    Punctuation(&'static str),
+    /// Any other character of the block, which ends a word.
+    Other,
+}
+
+/// Returns the role and the transliteration of a letter of a script.
+fn letter(scheme: IndicScheme, script: IndicScript, offset: u32) -> Letter {
+    use self::IndicScript::*;
+    use self::Letter::*;
+
+    let t = |readable| scheme.spelling(offset).unwrap_or(readable);
+    let dravidian = script.is_dravidian();
+    match (script, offset) {
+        (Devanagari, 0x72) => Vowel(t("a")),
+        (Bengali, 0x4E) => Dead("t"),
+        (Bengali, 0x70) => Consonant("r"),
+        (Bengali, 0x71) => Consonant("w"),
+        (Gurmukhi, 0x70) => Nasal("n"),
+        (Gurmukhi, 0x71) => Addak,
+        (Oriya, 0x71) => Consonant("w"),
+        (Telugu, 0x58) => Consonant("ts"),
+        (Telugu, 0x59) => Consonant("dz"),
+        (Malayalam, 0x4E) => Dead("r"),
+        (Malayalam, 0x7A) | (Malayalam, 0x7B) => Dead("n"),
+        (Malayalam, 0x7C) => Dead("r"),
+        (Malayalam, 0x7D) | (Malayalam, 0x7E) => Dead("l"),
+        (Malayalam, 0x7F) => Dead("k"),
+        (_, 0x01) => Nasal(t("n")),
+        (_, 0x02) => Anusvara,
+        (_, 0x03) => Visarga(t("h")),
+        (_, 0x04) | (_, 0x05) => Vowel(t("a")),
+        (_, 0x06) => Vowel(t("aa")),
+        (_, 0x07) => Vowel(t("i")),
+        (_, 0x08) => Vowel(t("ii")),
+        (_, 0x09) => Vowel(t("u")),
+        (_, 0x0A) => Vowel(t("uu")),
+        (_, 0x0B) => Vowel(t("ri")),
+        (_, 0x0C) => Vowel(t("li")),
+        (_, 0x0D) | (_, 0x0E) => Vowel(t("e")),
+        (_, 0x0F) => Vowel(t(if dravidian { "ee" } else { "e" })),
+        (_, 0x10) => Vowel(t("ai")),
+        (_, 0x11) | (_, 0x12) => Vowel(t("o")),
+        (_, 0x13) => Vowel(t(if dravidian { "oo" } else { "o" })),
+        (_, 0x14) => Vowel(t("au")),
+        (_, 0x15) => Consonant(t("k")),
+        (_, 0x16) => Consonant(t("kh")),
+        (_, 0x17) => Consonant(t("g")),
+        (_, 0x18) => Consonant(t("gh")),
+        (_, 0x19) => Consonant(t("ng")),
+        (_, 0x1A) => Consonant(t("ch")),
+        (_, 0x1B) => Consonant(t("chh")),
+        (_, 0x1C) => Consonant(t("j")),
+        (_, 0x1D) => Consonant(t("jh")),
+        (_, 0x1E) => Consonant(t("ny")),
+        (_, 0x1F) | (_, 0x24) => Consonant(t("t")),
+        (_, 0x20) | (_, 0x25) => Consonant(t("th")),
+        (_, 0x21) | (_, 0x26) => Consonant(t("d")),
+        (_, 0x22) | (_, 0x27) => Consonant(t("dh")),
+        (_, 0x23) | (_, 0x28) | (_, 0x29) => Consonant(t("n")),
+        (_, 0x2A) => Consonant(t("p")),
+        (_, 0x2B) => Consonant(t("ph")),
+        (_, 0x2C) => Consonant(t("b")),
+        (_, 0x2D) => Consonant(t("bh")),
+        (_, 0x2E) => Consonant(t("m")),
+        (_, 0x2F) => Consonant(t("y")),
+        (_, 0x30) | (_, 0x31) => Consonant(t("r")),
+        (_, 0x32) | (_, 0x33) => Consonant(t("l")),
+        (_, 0x34) => Consonant(t("zh")),
+        (_, 0x35) => Consonant(t("v")),
+        (_, 0x36) | (_, 0x37) => Consonant(t("sh")),
+        (_, 0x38) => Consonant(t("s")),
+        (_, 0x39) => Consonant(t("h")),
+        (_, 0x3C) => Nukta,
+        (_, 0x3D) => Punctuation(t("'")),
+        (_, 0x3E) => Sign(t("aa")),
+        (_, 0x3F) => Sign(t("i")),
+        (_, 0x40) => Sign(t("ii")),
+        (_, 0x41) => Sign(t("u")),
+        (_, 0x42) => Sign(t("uu")),
+        (_, 0x43) | (_, 0x44) => Sign(t("ri")),
+        (_, 0x45) | (_, 0x46) => Sign(t("e")),
+        (_, 0x47) => Sign(t(if dravidian { "ee" } else { "e" })),
+        (_, 0x48) => Sign(t("ai")),
+        (_, 0x49) | (_, 0x4A) => Sign(t("o")),
+        (_, 0x4B) => Sign(t(if dravidian { "oo" } else { "o" })),
+        (_, 0x4C) => Sign(t("au")),
+        (_, 0x4D) => Virama,
+        (_, 0x50) => Punctuation(t("om")),
+        (_, 0x55) | (_, 0x56) | (_, 0x57) => Sign(""),
+        (_, 0x58) => Consonant(t("q")),
+        (_, 0x59) => Consonant(t("kh")),
+        (_, 0x5A) => Consonant(t("gh")),
+        (_, 0x5B) => Consonant(t("z")),
+        (_, 0x5C) => Consonant(t("r")),
+        (_, 0x5D) => Consonant(t("rh")),
+        (_, 0x5E) => Consonant(t("f")),
+        (_, 0x5F) => Consonant(t("y")),
+        (_, 0x60) => Vowel(t("rii")),
+        (_, 0x61) => Vowel(t("lii")),
+        (_, 0x62) => Sign(t("li")),
+        (_, 0x63) => Sign(t("lii")),
+        (_, 0x64) => Punctuation(t(".")),
+        (_, 0x65) => Punctuation(t("..")),
+        (_, 0x66..=0x6F) => {
+            let i = offset as usize - 0x66;
+            Punctuation(&"0123456789"[i..i + 1])
+        },
+        _ => Other,
+    }
+}
+
+/// Returns the offset of a consonant followed by a nukta, if it has a
+/// precomposed form.
+fn with_nukta(script: IndicScript, offset: u32) -> u32 {
+    match (script, offset) {
+        (IndicScript::Telugu, _) => offset,
+        (IndicScript::Gurmukhi, 0x32) => 0x33,
+        (IndicScript::Gurmukhi, 0x38) => 0x36,
+        (_, 0x15) => 0x58,
+        (_, 0x16) => 0x59,
+        (_, 0x17) => 0x5A,
+        (_, 0x1C) => 0x5B,
+        (_, 0x21) => 0x5C,
+        (_, 0x22) => 0x5D,
+        (_, 0x2B) => 0x5E,
+        (_, 0x2F) => 0x5F,
+        _ => offset,
+    }
+}
+
+/// A transliterator for the Brahmic scripts of India, which transliterates
+/// whole syllables rather than single characters.
+///
+/// Consonants carry an inherent `a`, which is replaced by vowel signs and
+/// suppressed by the virama. The nukta modifies the preceding consonant, and
+/// the anusvara is transliterated as `n` or `m` depending on the following
+/// consonant. Devanagari, Bengali, Gurmukhi, Gujarati, Oriya, Tamil, Telugu,
+/// Kannada and Malayalam are supported, whether or not the `indic` feature is
+/// enabled; other characters are transliterated like `unidecode()` does.
+///
+/// The scholarly schemes of `IndicScheme` can be converted back to a script
+/// with `to_script()`.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = IndicTransliterator::new();
+/// assert_eq!(t.transliterate("മലയാലമ്"), "malayaalam");
+/// assert_eq!(t.transliterate("नमस्ते"), "namaste");
+///
+/// let hk = IndicTransliterator::new().scheme(IndicScheme::HarvardKyoto);
+/// assert_eq!(hk.transliterate("संस्कृतम्"), "saMskRtam");
+/// assert_eq!(hk.to_script("saMskRtam", IndicScript::Devanagari), "संस्कृतम्");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndicTransliterator {
+    scheme: IndicScheme,
+    schwa_deletion: bool,
+}
+
+impl IndicTransliterator {
+    /// Creates a transliterator with the `Readable` scheme and schwa deletion
+    /// enabled.
+    pub fn new() -> IndicTransliterator {
+        IndicTransliterator {
+            scheme: IndicScheme::Readable,
+            schwa_deletion: true,
+        }
+    }
+
+    /// Sets the romanization scheme.
+    pub fn scheme(mut self, scheme: IndicScheme) -> IndicTransliterator {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Sets whether the inherent vowel of the last consonant of a word is
+    /// dropped in Devanagari, Bengali, Gurmukhi and Gujarati, as it is silent
+    /// in the modern languages written in these scripts: `"कमल"` is
+    /// transliterated as `"kamal"` with schwa deletion and as `"kamala"`
+    /// without. It is kept after a cluster of consonants and in words of a
+    /// single syllable. The scholarly schemes always keep it.
+    pub fn schwa_deletion(mut self, enabled: bool) -> IndicTransliterator {
+        self.schwa_deletion = enabled;
+        self
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, s: &str) -> String {
+        let mut syllables = Syllables {
+            out: String::with_capacity(s.len()),
+            scheme: self.scheme,
+            schwa_deletion: self.schwa_deletion
+                && self.scheme == IndicScheme::Readable,
+            last: "",
+            consonant: None,
+            cluster: false,
+            after_virama: false,
+            geminate: false,
+            count: 0,
+        };
+        let mut chars = s.chars().peekable();
+        while let Some(ch) = chars.next() {
+            syllables.push(ch, &mut chars);
+        }
+        syllables.end_word();
+        syllables.out
+    }
+
+    /// Converts text romanized with the scheme of the transliterator back to
+    /// a script. Characters that are not part of the scheme, such as spaces
+    /// and digits, are kept as they are, and so is all of the text in the
+    /// `Readable` scheme, which cannot be reversed.
+    ///
+    /// Spellings of letters that the script does not have, such as `"zh"` in
+    /// Bengali or the vocalic `r` in Tamil, are kept as they are rather than
+    /// converted to the character at the same offset of the block. Text
+    /// transliterated from the script with a scholarly scheme is converted
+    /// back to the original text. In Gurmukhi, doubled consonants are written
+    /// with the addak, like `"pakkA"` as `"ਪੱਕਾ"`.
+    ///
+    /// Some letters are not converted back, as they are spelled like other
+    /// letters:
+    ///
+    /// * Harvard-Kyoto has no spellings for the consonants with a nukta, which
+    ///   are transliterated like the `Readable` scheme does, such as `"qilA"`
+    ///   for `"क़िला"`, converted back to `"qइला"`. ITRANS and Velthuis spell
+    ///   them, and convert them back to the precomposed letters, such as
+    ///   U+0958 for `"क"` followed by a nukta.
+    /// * The Bengali khanda ta and the Malayalam chillus are spelled like
+    ///   their consonant with a virama, so `"ৎ"` and `"ൻ"` are converted back
+    ///   to `"ত্"` and `"ന്"`.
+    pub fn to_script(&self, s: &str, script: IndicScript) -> String {
+        let spellings = self.scheme.spellings();
+        let separator = self.scheme.separator();
+        let mut out = String::with_capacity(s.len() * 3);
+        let mut consonant = false;
+        // The spelling of the last consonant
+        let mut last = "";
+        let mut rest = s;
+        while let Some(ch) = rest.chars().next() {
+            if !separator.is_empty() && rest.starts_with(separator) {
+                rest = &rest[separator.len()..];
+                continue;
+            }
+            let spelling = spellings
+                .iter()
+                .filter(|&&(_, s)| rest.starts_with(s))
+                .max_by_key(|&&(_, s)| s.len());
+            let (offset, spelled) = match spelling {
+                Some(&(offset, s)) => (offset, s),
+                None => {
+                    if consonant {
+                        out.extend(script.char(0x4D));
+                        consonant = false;
+                    }
+                    out.push(ch);
+                    rest = &rest[ch.len_utf8()..];
+                    continue;
+                },
+            };
+            rest = &rest[spelled.len()..];
+            let letter = letter(self.scheme, script, offset);
+            if let (Letter::Vowel(_), true) = (letter, consonant) {
+                consonant = false;
+                // The inherent vowel has no sign
+                let sign = match SIGNS.iter().find(|&&(v, _)| v == offset) {
+                    Some(&(_, sign)) => sign,
+                    None => continue,
+                };
+                match script.char(sign) {
+                    Some(c) => {
+                        out.push(c);
+                        continue;
+                    },
+                    None => out.extend(script.char(0x4D)),
+                }
+            } else if consonant {
+                // A consonant doubled by the Gurmukhi addak, such as the `"kk"`
+                // and `"kkh"` of `"ਪੱਕਾ"` and `"ਮੱਖਣ"`, has no virama
+                let geminate = script == IndicScript::Gurmukhi
+                    && matches!(letter, Letter::Consonant(_))
+                    && last.len() == 1
+                    && spelled.starts_with(last);
+                if geminate {
+                    out.pop();
+                    out.extend(script.char(0x71));
+                } else {
+                    out.extend(script.char(0x4D));
+                }
+                consonant = false;
+            }
+            match script.char(offset) {
+                Some(c) => {
+                    out.push(c);
+                    consonant = matches!(letter, Letter::Consonant(_));
+                    last = spelled;
+                },
+                // Letters of other scripts are kept as they are spelled
+                None => out.push_str(spelled),
+            }
+        }
+        if consonant {
+            out.extend(script.char(0x4D));
+        }
+        out
+    }
+}
+
+impl Default for IndicTransliterator {
+    fn default() -> IndicTransliterator {
+        IndicTransliterator::new()
+    }
+}
+
+/// The state of the transliteration of a word.
+struct Syllables {
+    out: String,
+    scheme: IndicScheme,
+    schwa_deletion: bool,
+    /// The last non-empty string appended to `out`.
+    last: &'static str,
+    /// The offset and the script of the last consonant, whose vowel is not
+    /// known yet.
+    consonant: Option<(u32, IndicScript)>,
+    /// Whether the last consonant ends a cluster of consonants.
+    cluster: bool,
+    /// Whether the last character was a virama.
+    after_virama: bool,
+    /// Whether the next consonant is doubled.
+    geminate: bool,
+    /// The number of syllables of the word before the last consonant.
+    count: usize,
+}
+
+impl Syllables {
+    fn push(&mut self, ch: char, chars: &mut Peekable<Chars>) {
+        let (script, offset) = match IndicScript::of(ch) {
+            Some(letter) => letter,
+            // Joiners do not end words
+            None if ch == '\u{200C}' || ch == '\u{200D}' => return,
+            None => {
+                self.end_word();
+                self.emit(unidecode_char(ch));
+                return;
+            },
+        };
+        let after_virama = self.after_virama;
+        self.after_virama = false;
+        match letter(self.scheme, script, offset) {
+            Letter::Consonant(s) => {
+                self.vowel("a");
+                if self.geminate {
+                    self.emit(&s[..1]);
+                    self.geminate = false;
+                }
+                self.consonant = Some((offset, script));
+                self.cluster = after_virama;
+            },
+            Letter::Dead(s) => {
+                self.vowel("a");
+                self.emit(s);
+            },
+            Letter::Vowel(s) => {
+                self.vowel("a");
+                self.emit(s);
+                self.count += 1;
+            },
+            Letter::Sign(s) => {
+                if self.consonant.is_some() {
+                    self.vowel(s);
+                } else {
+                    self.emit(s);
+                }
+            },
+            Letter::Virama => {
+                if let Some(s) = self.take_consonant() {
+                    self.emit(s);
+                    self.after_virama = true;
+                }
+            },
+            Letter::Nukta => {
+                if let Some((ref mut offset, script)) = self.consonant {
+                    *offset = with_nukta(script, *offset);
+                }
+            },
+            Letter::Anusvara => {
+                self.vowel("a");
+                if let Some(s) = self.scheme.spelling(offset) {
+                    self.emit(s);
+                    return;
+                }
+                let next = chars.peek().and_then(|&c| IndicScript::of(c));
+                let next = next.map(|(s, o)| letter(self.scheme, s, o));
+                let nasal = match next {
+                    Some(Letter::Consonant(s))
+                        if !s.starts_with(['p', 'b', 'm']) =>
+                    {
+                        if script == IndicScript::Bengali {
+                            "ng"
+                        } else {
+                            "n"
+                        }
+                    },
+                    _ => "m",
+                };
+                self.emit(nasal);
+            },
+            Letter::Nasal(s) | Letter::Visarga(s) => {
+                self.vowel("a");
+                self.emit(s);
+            },
+            Letter::Addak => {
+                self.vowel("a");
+                self.geminate = true;
+            },
+            Letter::Punctuation(s) => {
+                self.end_word();
+                self.emit(s);
+            },
+            Letter::Other => {
+                self.end_word();
+                self.emit(unidecode_char(ch));
+            },
+        }
+    }
+
+    /// Appends a string, separated from the previous one if they would
+    /// otherwise be read as a single spelling of the scheme.
+    fn emit(&mut self, s: &'static str) {
+        if self.scheme.joins(self.last, s) {
+            self.out.push_str(self.scheme.separator());
+        }
+        self.out.push_str(s);
+        if !s.is_empty() {
+            self.last = s;
+        }
+    }
+
+    /// Takes the transliteration of the last consonant, if any.
+    fn take_consonant(&mut self) -> Option<&'static str> {
+        let (offset, script) = self.consonant.take()?;
+        match letter(self.scheme, script, offset) {
+            Letter::Consonant(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Appends the last consonant, if any, followed by the given vowel.
+    fn vowel(&mut self, vowel: &'static str) {
+        if let Some(s) = self.take_consonant() {
+            self.emit(s);
+            self.emit(vowel);
+            self.count += 1;
+        }
+    }
+
+    /// Appends the last consonant of a word, deleting its inherent vowel if
+    /// it is silent.
+    fn end_word(&mut self) {
+        let script = self.consonant.map(|(_, script)| script);
+        if let (Some(s), Some(script)) = (self.take_consonant(), script) {
+            self.emit(s);
+            let silent = self.schwa_deletion
+                && script.deletes_schwa()
+                && self.count > 0
+                && !self.cluster;
+            if !silent {
+                self.emit("a");
+            }
+        }
+        self.count = 0;
+        self.geminate = false;
+        self.after_virama = false;
+    }
+}
@@ -0,0 +1,302 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::fmt;
+
+use unidecode_char;
+
+/// A romanization system of Japanese.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JapaneseSystem {
+    /// Modern Hepburn, such as `"shinbun"` and `"matcha"`.
+    Hepburn,
+    /// Kunrei-shiki, such as `"sinbun"` and `"mattya"`.
+    Kunrei,
+    /// Nihon-shiki, which also distinguishes `"di"`, `"du"` and `"wo"`.
+    NihonShiki,
+}
+
+/// A dictionary of the readings of Japanese words, used by a
+/// `JapaneseTransliterator` to read kanji.
+pub trait KanjiReadings {
+    /// Returns the reading, in hiragana or katakana, of the longest word at the
+    /// start of `s`, with the length in bytes of the word.
+    fn reading(&self, s: &str) -> Option<(&str, usize)>;
+}
+
+impl KanjiReadings for BTreeMap<String, String> {
+    fn reading(&self, s: &str) -> Option<(&str, usize)> {
+        s.char_indices()
+            .skip(1)
+            .map(|(i, _)| i)
+            .chain(Some(s.len()))
+            .take(MAX_WORD)
+            .filter_map(|end| self.get(&s[..end]).map(|r| (&r[..], end)))
+            .last()
+    }
+}
+
+impl KanjiReadings for &'static [(&'static str, &'static str)] {
+    fn reading(&self, s: &str) -> Option<(&str, usize)> {
+        self.iter()
+            .filter(|&&(word, _)| !word.is_empty() && s.starts_with(word))
+            .max_by_key(|&&(word, _)| word.len())
+            .map(|&(word, reading)| (reading, word.len()))
+    }
+}
+
+/// The longest word, in characters, looked up in a `BTreeMap`.
+const MAX_WORD: usize = 16;
+
+/// The Hepburn spellings of the hiragana from U+3041 to U+3096. The small
+/// kana, the sokuon and the moraic nasal are handled separately.
+static KANA: [&str; 0x56] = [
+    "a", "a", "i", "i", "u", "u", "e", "e", "o", "o",
+    "ka", "ga", "ki", "gi", "ku", "gu", "ke", "ge", "ko", "go",
+    "sa", "za", "shi", "ji", "su", "zu", "se", "ze", "so", "zo",
+    "ta", "da", "chi", "ji", "", "tsu", "zu", "te", "de", "to", "do",
+    "na", "ni", "nu", "ne", "no",
+    "ha", "ba", "pa", "hi", "bi", "pi", "fu", "bu", "pu",
+    "he", "be", "pe", "ho", "bo", "po",
+    "ma", "mi", "mu", "me", "mo",
+    "ya", "ya", "yu", "yu", "yo", "yo",
+    "ra", "ri", "ru", "re", "ro",
+    "wa", "wa", "i", "e", "o", "n", "vu", "ka", "ke",
+];
+
+/// The spellings of Kunrei-shiki that differ from Hepburn.
+static KUNREI: [(char, &str); 7] = [
+    ('し', "si"), ('じ', "zi"), ('ち', "ti"), ('ぢ', "zi"),
+    ('つ', "tu"), ('ふ', "hu"), ('を', "o"),
+];
+
+/// The spellings of Nihon-shiki that differ from Hepburn.
+static NIHON_SHIKI: [(char, &str); 10] = [
+    ('し', "si"), ('じ', "zi"), ('ち', "ti"), ('ぢ', "di"),
+    ('つ', "tu"), ('づ', "du"), ('ふ', "hu"), ('ゐ', "wi"),
+    ('ゑ', "we"), ('を', "wo"),
+];
+
+/// The katakana without a hiragana counterpart, from U+30F7 to U+30FA.
+static KATAKANA_V: [&str; 4] = ["va", "vi", "ve", "vo"];
+
+/// A transliterator for Japanese, which romanizes kana syllable by syllable
+/// with one of the systems of `JapaneseSystem`.
+///
+/// The sokuon (`っ`) doubles the following consonant, the long vowel mark
+/// (`ー`) doubles the preceding vowel, and the small `ゃ`, `ゅ` and `ょ` form
+/// yōon with the preceding kana, such as `"kyo"` for `きょ`. Kanji are read
+/// with the `KanjiReadings` of the transliterator, if any, and are otherwise
+/// transliterated like `unidecode()` does, with their Chinese readings.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let readings: &'static [(&str, &str)] = &[("茶", "ちゃ")];
+/// let t = JapaneseTransliterator::new().readings(readings);
+/// assert_eq!(t.transliterate("げんまい茶"), "genmaicha");
+/// assert_eq!(t.transliterate("ラーメン"), "raamen");
+/// ```
+#[derive(Clone)]
+pub struct JapaneseTransliterator {
+    system: JapaneseSystem,
+    readings: Option<Arc<dyn KanjiReadings + Send + Sync>>,
+}
+
+impl JapaneseTransliterator {
+    /// Creates a transliterator with the Hepburn system and no readings of
+    /// kanji.
+    pub fn new() -> JapaneseTransliterator {
+        JapaneseTransliterator {
+            system: JapaneseSystem::Hepburn,
+            readings: None,
+        }
+    }
+
+    /// Sets the romanization system.
+    pub fn system(mut self, system: JapaneseSystem) -> JapaneseTransliterator {
+        self.system = system;
+        self
+    }
+
+    /// Sets the dictionary used to read kanji. Words are looked up at every
+    /// character that is not kana, and the longest word found is replaced by
+    /// its reading.
+    pub fn readings<R>(mut self, readings: R) -> JapaneseTransliterator
+    where
+        R: KanjiReadings + Send + Sync + 'static,
+    {
+        self.readings = Some(Arc::new(readings));
+        self
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, s: &str) -> String {
+        let mut kana = Kana {
+            out: String::with_capacity(s.len()),
+            system: self.system,
+            syllable: None,
+            sokuon: false,
+            moraic_n: false,
+        };
+        let mut rest = s;
+        while let Some(ch) = rest.chars().next() {
+            if kana_of(ch).is_none() {
+                let readings = self.readings.as_ref();
+                if let Some((reading, len)) =
+                    readings.and_then(|r| r.reading(rest))
+                {
+                    reading.chars().for_each(|ch| kana.push(ch));
+                    rest = &rest[len.max(ch.len_utf8())..];
+                    continue;
+                }
+            }
+            kana.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+        kana.out
+    }
+}
+
+impl Default for JapaneseTransliterator {
+    fn default() -> JapaneseTransliterator {
+        JapaneseTransliterator::new()
+    }
+}
+
+impl fmt::Debug for JapaneseTransliterator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("JapaneseTransliterator")
+            .field("system", &self.system)
+            .field("readings", &self.readings.is_some())
+            .finish()
+    }
+}
+
+/// Returns the hiragana of a kana, converting katakana, or `None` if the
+/// character is not kana.
+fn kana_of(ch: char) -> Option<char> {
+    match ch {
+        '\u{3041}'..='\u{3096}' | 'ー' => Some(ch),
+        '\u{30A1}'..='\u{30F6}' => core::char::from_u32(ch as u32 - 0x60),
+        '\u{30F7}'..='\u{30FA}' => Some(ch),
+        _ => None,
+    }
+}
+
+/// The state of the romanization of a run of kana.
+struct Kana {
+    out: String,
+    system: JapaneseSystem,
+    /// The start in `out` of the last syllable, if the last character was
+    /// kana.
+    syllable: Option<usize>,
+    /// Whether the last kana was a sokuon.
+    sokuon: bool,
+    /// Whether the last kana was the moraic nasal `ん`.
+    moraic_n: bool,
+}
+
+impl Kana {
+    fn push(&mut self, ch: char) {
+        let kana = match kana_of(ch) {
+            Some(kana) => kana,
+            None => {
+                self.syllable = None;
+                self.sokuon = false;
+                self.moraic_n = false;
+                self.out.push_str(unidecode_char(ch));
+                return;
+            },
+        };
+        match kana {
+            'っ' => self.sokuon = true,
+            'ー' => {
+                let vowel = match self.syllable {
+                    Some(_) => self.out.chars().last(),
+                    None => None,
+                };
+                if let Some(v @ ('a' | 'i' | 'u' | 'e' | 'o')) = vowel {
+                    self.out.push(v);
+                }
+            },
+            'ゃ' | 'ゅ' | 'ょ' => {
+                let vowel = KANA[kana as usize - 0x3041][1..].chars().next();
+                let stem = match self.syllable {
+                    Some(start) if self.out[start..].len() >= 2 => {
+                        self.out[start..].strip_suffix('i').map(|s| s.len())
+                    },
+                    _ => None,
+                };
+                match (stem, vowel) {
+                    (Some(len), Some(vowel)) => {
+                        self.out.pop();
+                        let stem = &self.out[self.out.len() - len..];
+                        if !(stem.ends_with('h') || stem == "j") {
+                            self.out.push('y');
+                        }
+                        self.out.push(vowel);
+                    },
+                    _ => self.syllable(KANA[kana as usize - 0x3041]),
+                }
+            },
+            'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' => {
+                let vowel = KANA[kana as usize - 0x3041];
+                match self.syllable {
+                    Some(start) if &self.out[start..] == "u" => {
+                        self.out.truncate(start);
+                        self.out.push('w');
+                        self.out.push_str(vowel);
+                    },
+                    Some(start) if self.out[start..].len() >= 2 => {
+                        self.out.pop();
+                        self.out.push_str(vowel);
+                    },
+                    _ => self.syllable(vowel),
+                }
+            },
+            'ん' => {
+                self.syllable("n");
+                self.moraic_n = true;
+            },
+            '\u{30F7}'..='\u{30FA}' => {
+                self.syllable(KATAKANA_V[kana as usize - 0x30F7]);
+            },
+            _ => {
+                let exceptions: &[(char, &str)] = match self.system {
+                    JapaneseSystem::Hepburn => &[],
+                    JapaneseSystem::Kunrei => &KUNREI,
+                    JapaneseSystem::NihonShiki => &NIHON_SHIKI,
+                };
+                let s = exceptions
+                    .iter()
+                    .find(|&&(k, _)| k == kana)
+                    .map_or(KANA[kana as usize - 0x3041], |&(_, s)| s);
+                self.syllable(s);
+            },
+        }
+    }
+
+    /// Appends a syllable, after the consonant doubled by a sokuon or the
+    /// apostrophe separating a moraic nasal from a vowel.
+    fn syllable(&mut self, s: &str) {
+        let first = s.chars().next();
+        let vowel = matches!(first, Some('a' | 'i' | 'u' | 'e' | 'o' | 'y'));
+        if self.moraic_n && vowel {
+            self.out.push('\'');
+        }
+        if self.sokuon {
+            match first {
+                Some('a' | 'i' | 'u' | 'e' | 'o') | None => {},
+                Some('c') if self.system == JapaneseSystem::Hepburn => {
+                    self.out.push('t');
+                },
+                Some(c) => self.out.push(c),
+            }
+        }
+        self.syllable = Some(self.out.len());
+        self.out.push_str(s);
+        self.sokuon = false;
+        self.moraic_n = false;
+    }
+}
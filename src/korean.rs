@@ -0,0 +1,226 @@
+use alloc::string::String;
+
+use unidecode_char;
+
+/// A romanization system of Korean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KoreanSystem {
+    /// The Revised Romanization of Korean, the official system of South Korea
+    /// since 2000, such as `"silla"` and `"hangeul"`.
+    RevisedRomanization,
+    /// McCune–Reischauer, with the apostrophes marking aspiration but without
+    /// the breves, which are not ASCII, such as `"hangul"` and `"ch'ongju"`.
+    McCuneReischauer,
+}
+
+/// The Revised Romanization of the initial consonants, from ㄱ to ㅎ.
+static INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s",
+    "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+
+/// The Revised Romanization of the vowels, from ㅏ to ㅣ.
+static VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa",
+    "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu", "ui",
+    "i",
+];
+
+/// The McCune–Reischauer romanization of the vowels, without breves.
+static VOWELS_MR: [&str; 21] = [
+    "a", "ae", "ya", "yae", "o", "e", "yo", "ye", "o", "wa",
+    "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "u", "ui",
+    "i",
+];
+
+/// The final consonants, from none to ㅎ, as pronounced at the end of a word.
+static CODAS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k",
+    "m", "l", "l", "l", "p", "l", "m", "p", "p", "t",
+    "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// The final consonants that move to the next syllable before a vowel, as
+/// the part kept and the initial consonant of the next syllable.
+static LIAISONS: [(&str, &str); 28] = [
+    ("", ""), ("", "g"), ("", "kk"), ("k", "s"), ("", "n"),
+    ("n", "j"), ("", "n"), ("", "d"), ("", "r"), ("l", "g"),
+    ("l", "m"), ("l", "b"), ("l", "s"), ("l", "t"), ("l", "p"),
+    ("", "r"), ("", "m"), ("", "b"), ("p", "s"), ("", "s"),
+    ("", "ss"), ("ng", ""), ("", "j"), ("", "ch"), ("", "k"),
+    ("", "t"), ("", "p"), ("", ""),
+];
+
+const G: usize = 0;
+const N: usize = 2;
+const D: usize = 3;
+const R: usize = 5;
+const M: usize = 6;
+const IEUNG: usize = 11;
+const J: usize = 12;
+const H: usize = 18;
+const I: usize = 20;
+
+/// Decomposes a Hangul syllable into the indices of its initial consonant,
+/// vowel and final consonant.
+fn decompose(ch: char) -> Option<(usize, usize, usize)> {
+    let s = (ch as u32).checked_sub(0xAC00)? as usize;
+    if s < 11172 {
+        Some((s / 588, s % 588 / 28, s % 28))
+    } else {
+        None
+    }
+}
+
+/// Applies the sound changes between the final consonant of a syllable and
+/// the initial consonant and vowel of the next one, returning the final and
+/// initial consonants as pronounced.
+fn boundary(t: usize, l: usize, v: usize) -> (&'static str, &'static str) {
+    let coda = CODAS[t];
+    match (t, l) {
+        (0, _) => ("", INITIALS[l]),
+        // Palatalization
+        (7, IEUNG) | (7, H) if v == I => ("", if l == H { "ch" } else { "j" }),
+        (25, IEUNG) if v == I => ("", "ch"),
+        (13, IEUNG) if v == I => ("l", "ch"),
+        // Liaison, with a silent ㅎ
+        (6, IEUNG) => ("", "n"),
+        (15, IEUNG) => ("", "r"),
+        (_, IEUNG) => LIAISONS[t],
+        // Aspiration
+        (27, G) | (27, D) | (27, J) => ("", aspirated(l)),
+        (6, G) | (6, D) | (6, J) => ("n", aspirated(l)),
+        (15, G) | (15, D) | (15, J) => ("l", aspirated(l)),
+        (27, N) => ("n", "n"),
+        (27, _) => ("", INITIALS[l]),
+        (22, H) | (23, H) => ("", "ch"),
+        (_, H) => match coda {
+            "k" | "t" | "p" => ("", coda),
+            _ => (coda, "h"),
+        },
+        // Lateralization of ㄴ and ㄹ
+        (4, R) => ("l", "l"),
+        (_, R) | (_, N) if coda == "l" => ("l", "l"),
+        // Nasalization
+        (_, R) | (_, N) | (_, M) => {
+            let coda = match coda {
+                "k" => "ng",
+                "t" => "n",
+                "p" => "m",
+                _ => coda,
+            };
+            (coda, if l == R { "n" } else { INITIALS[l] })
+        },
+        _ => (coda, INITIALS[l]),
+    }
+}
+
+/// Returns the aspirated counterpart of ㄱ, ㄷ or ㅈ.
+fn aspirated(l: usize) -> &'static str {
+    match l {
+        G => "k",
+        D => "t",
+        _ => "ch",
+    }
+}
+
+/// A transliterator for Korean, which romanizes Hangul syllables with the
+/// sound changes across syllables of a word, such as the assimilation of
+/// consonants and the liaison of final consonants with following vowels.
+///
+/// Syllables are decomposed into their letters algorithmically. Other
+/// characters are transliterated like `unidecode()` does, and end words.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = KoreanTransliterator::new();
+/// assert_eq!(t.transliterate("신라"), "silla");
+/// assert_eq!(t.transliterate("독립문"), "dongnimmun");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KoreanTransliterator {
+    system: KoreanSystem,
+}
+
+impl KoreanTransliterator {
+    /// Creates a transliterator with the Revised Romanization.
+    pub fn new() -> KoreanTransliterator {
+        KoreanTransliterator {
+            system: KoreanSystem::RevisedRomanization,
+        }
+    }
+
+    /// Sets the romanization system.
+    pub fn system(mut self, system: KoreanSystem) -> KoreanTransliterator {
+        self.system = system;
+        self
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut chars = s.chars().peekable();
+        // The initial consonant of the next syllable of the word, and whether
+        // it follows a voiced sound
+        let mut onset: Option<(&str, bool)> = None;
+        while let Some(ch) = chars.next() {
+            let (l, v, t) = match decompose(ch) {
+                Some(letters) => letters,
+                None => {
+                    onset = None;
+                    out.push_str(unidecode_char(ch));
+                    continue;
+                },
+            };
+            let (initial, voiced) = onset.unwrap_or((INITIALS[l], false));
+            let next = chars.peek().and_then(|&c| decompose(c));
+            let (coda, next_initial) = match next {
+                Some((l, v, _)) => boundary(t, l, v),
+                None => (CODAS[t], ""),
+            };
+            match self.system {
+                KoreanSystem::RevisedRomanization => {
+                    out.push_str(initial);
+                    out.push_str(VOWELS[v]);
+                },
+                KoreanSystem::McCuneReischauer => {
+                    out.push_str(mccune_reischauer(initial, voiced, v));
+                    out.push_str(VOWELS_MR[v]);
+                },
+            }
+            out.push_str(coda);
+            let voiced = matches!(coda, "" | "n" | "m" | "ng" | "l");
+            onset = next.map(|_| (next_initial, voiced));
+        }
+        out
+    }
+}
+
+impl Default for KoreanTransliterator {
+    fn default() -> KoreanTransliterator {
+        KoreanTransliterator::new()
+    }
+}
+
+/// Spells an initial consonant, given in the Revised Romanization, in
+/// McCune–Reischauer, where plain stops are voiced after voiced sounds.
+fn mccune_reischauer(
+    initial: &'static str,
+    voiced: bool,
+    v: usize,
+) -> &'static str {
+    match initial {
+        "g" if !voiced => "k",
+        "d" if !voiced => "t",
+        "b" if !voiced => "p",
+        "j" if !voiced => "ch",
+        "jj" => "tch",
+        "ch" => "ch'",
+        "k" => "k'",
+        "t" => "t'",
+        "p" => "p'",
+        "s" if v == 16 => "sh",
+        _ => initial,
+    }
+}
@@ -8,8 +8,11 @@
 //! written by Burke in 2001.
 //!
 //! The data set used to translate the Unicode was ported directly from the
-//! `Text::Unidecode` module using a Perl script, so `rust-unidecode` should
-//! produce identical output.
+//! `Text::Unidecode` module, whose data files are vendored in the repository,
+//! so `rust-unidecode` should produce identical output for the characters of
+//! the Basic Multilingual Plane. `Text::Unidecode` does not cover the
+//! characters outside of it, which `rust-unidecode` transliterates from other
+//! sources.
 //!
 //! Examples
 //! --------
@@ -23,9 +26,113 @@
 //! assert_eq!(unidecode("ᔕᓇᓇ"), "shanana");
 //! assert_eq!(unidecode("げんまい茶"), "genmaiCha ");
 //! ```
+//!
+//! Cargo features
+//! --------------
+//! The crate is `#![no_std]`. The lookup of single characters and the
+//! adapters that do not allocate are always available; the following features
+//! enable the rest of the API:
+//!
+//! * `alloc`: functions returning a `String`, such as `unidecode()`.
+//! * `std` (default): implies `alloc`, and adds the `io`-based functions and
+//!   types, such as `UnidecodeReader`.
+//!
+//! The transliterations of the following scripts are also behind features, all
+//! enabled by default, so that programs which do not need them can leave their
+//! data out of the binary:
+//!
+//! * `cjk`: CJK Unified Ideographs, their extensions and the compatibility
+//!   ideographs.
+//! * `hangul`: Hangul Jamo and Hangul Syllables.
+//! * `indic`: the scripts from Devanagari to Malayalam and Sinhala.
+//! * `yi`: Yi Syllables and Yi Radicals.
+//! * `syllabics`: Unified Canadian Aboriginal Syllabics.
+//!
+//! Characters of a script whose feature is disabled are unknown: they are
+//! transliterated to an empty string, and handled by the `Unknown` policy of a
+//! `Transliterator`.
+//!
+//! Finally, the `table-1-30` feature keeps an older version of the data set
+//! available as a `TableVersion`, so that transliterations stored by older
+//! versions of `rust-unidecode` can be reproduced.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod adapters;
+#[cfg(feature = "alloc")]
+mod blocks_data;
 mod data;
-use data::MAPPING;
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+mod chinese;
+#[cfg(feature = "alloc")]
+mod cyrillic;
+#[cfg(feature = "alloc")]
+mod emoji;
+#[cfg(feature = "alloc")]
+mod emoji_data;
+#[cfg(feature = "alloc")]
+mod error;
+#[cfg(feature = "alloc")]
+mod greek;
+mod han;
+mod han_data;
+#[cfg(feature = "alloc")]
+mod indic;
+#[cfg(feature = "alloc")]
+mod japanese;
+#[cfg(feature = "alloc")]
+mod korean;
+#[cfg(feature = "alloc")]
+mod overrides;
+#[cfg(feature = "alloc")]
+mod profiles;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "alloc")]
+mod transliterator;
+mod version;
+use data::{BLOCKS, TABLES};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::convert::Infallible;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+pub use adapters::{Unidecode, UnidecodeChars, UnidecodeExt, Unidecoded};
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub use chinese::{ChineseTransliterator, PinyinStyle};
+#[cfg(feature = "alloc")]
+pub use cyrillic::{CyrillicLanguage, CyrillicScheme, CyrillicTransliterator};
+#[cfg(feature = "alloc")]
+pub use error::UnidecodeError;
+#[cfg(feature = "alloc")]
+pub use greek::{GreekScheme, GreekTransliterator};
+pub use han::HanReading;
+#[cfg(feature = "alloc")]
+pub use indic::{IndicScheme, IndicScript, IndicTransliterator};
+#[cfg(feature = "alloc")]
+pub use japanese::{JapaneseSystem, JapaneseTransliterator, KanjiReadings};
+#[cfg(feature = "alloc")]
+pub use korean::{KoreanSystem, KoreanTransliterator};
+#[cfg(feature = "std")]
+pub use stream::{InvalidUtf8, UnidecodeReader, UnidecodeWriter};
+#[cfg(feature = "alloc")]
+pub use transliterator::{Escape, Transliterator, Unknown};
+#[cfg(feature = "alloc")]
+pub use transliterator::{Spacing, TransliteratorBuilder};
+pub use version::TableVersion;
 
 /// This function takes any Unicode string and returns an ASCII transliteration
 /// of that string.
@@ -44,16 +151,92 @@ use data::MAPPING;
 /// # Warnings
 ///
 /// * As stated, some transliterations do produce `\n` characters.
-/// * Some Unicode characters transliterate to an empty string, either on
-///   purpose or because `rust-unidecode` does not know about the character.
+/// * Some Unicode characters transliterate to an empty string on purpose, such
+///   as combining marks.
 /// * Some Unicode characters are unknown and transliterate to `"[?]"`.
 /// * Many Unicode characters transliterate to multi-character strings. For
 ///   example, 北 is transliterated as "Bei ".
 ///
 /// These guarantees/warnings are paraphrased from the original
 /// `Text::Unidecode` documentation.
+#[cfg(feature = "alloc")]
 pub fn unidecode(s: &str) -> String {
-    s.chars().map(|ch| unidecode_char(ch)).collect()
+    let mut out = String::with_capacity(s.len());
+    unidecode_into(s, &mut out);
+    out
+}
+
+/// This function works like `unidecode()`, except that the transliteration is
+/// appended to an existing `String`, so that its buffer can be reused.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let mut out = String::from("name: ");
+/// unidecode_into("Æneid", &mut out);
+/// assert_eq!(out, "name: AEneid");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn unidecode_into(s: &str, out: &mut String) {
+    let _ = for_each_piece(s, |piece| -> Result<(), Infallible> {
+        out.push_str(piece);
+        Ok(())
+    });
+}
+
+/// This function works like `unidecode()`, except that the transliteration is
+/// written to a `fmt::Write`, such as a `String` or a `fmt::Formatter`.
+pub fn unidecode_to_writer<W: fmt::Write>(s: &str, w: &mut W) -> fmt::Result {
+    for_each_piece(s, |piece| w.write_str(piece))
+}
+
+/// This function works like `unidecode()`, except that the transliteration is
+/// written to an `io::Write`, such as a file or a socket.
+///
+/// The transliteration is written in many small pieces, so wrapping unbuffered
+/// writers in an `io::BufWriter` is recommended.
+#[cfg(feature = "std")]
+pub fn unidecode_to_io<W: io::Write>(s: &str, w: &mut W) -> io::Result<()> {
+    for_each_piece(s, |piece| w.write_all(piece.as_bytes()))
+}
+
+/// This function works like `unidecode()`, except that a string which is
+/// already ASCII is borrowed rather than copied, as every ASCII character is
+/// mapped to itself.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// assert_eq!(unidecode_cow("Aeneid"), Cow::Borrowed("Aeneid"));
+/// assert_eq!(unidecode_cow("Æneid"), "AEneid");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn unidecode_cow(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(unidecode(s))
+    }
+}
+
+/// Calls `f` with the pieces of the transliteration of `s`. Runs of ASCII
+/// characters are passed through as a single slice of the input.
+fn for_each_piece<E, F>(s: &str, mut f: F) -> Result<(), E>
+where
+    F: FnMut(&str) -> Result<(), E>,
+{
+    let mut rest = s;
+    while let Some(ch) = rest.chars().next() {
+        let ascii = rest.bytes().position(|b| b >= 0x80).unwrap_or(rest.len());
+        if ascii > 0 {
+            f(&rest[..ascii])?;
+            rest = &rest[ascii..];
+        } else {
+            f(unidecode_char(ch))?;
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+    Ok(())
 }
 
 /// This function takes a single Unicode character and returns an ASCII
@@ -69,5 +252,124 @@ pub fn unidecode(s: &str) -> String {
 /// ```
 #[inline]
 pub fn unidecode_char(ch: char) -> &'static str {
-    MAPPING.get(ch as usize).map(|&s| s).unwrap_or("")
+    let u = ch as usize;
+    let block = BLOCKS[u >> 8] as usize;
+    let (data, pointers) = TABLES[block >> 12];
+    match pointers.get(((block & 0xfff) << 8) | (u & 0xff)) {
+        Some(&pointer) => {
+            let start = (pointer >> 8) as usize;
+            &data[start..start + (pointer & 0xff) as usize]
+        },
+        // The table of the block was left out with a Cargo feature
+        None => "",
+    }
+}
+
+/// This function works like `unidecode()`, except that it fails on the first
+/// character that `rust-unidecode` does not know about instead of silently
+/// transliterating it to `""` or `"[?]"`.
+///
+/// Characters that are deliberately transliterated to an empty string, such as
+/// combining marks, do not cause an error. See `Unknown` for details.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// assert_eq!(try_unidecode("Æneid").unwrap(), "AEneid");
+/// assert_eq!(try_unidecode("A\u{1900}").unwrap_err().block(), "Limbu");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn try_unidecode(s: &str) -> Result<String, UnidecodeError> {
+    Transliterator::new(Unknown::Error).transliterate(s)
+}
+
+/// This function works like `try_unidecode()`, except that it reports every
+/// character that could not be transliterated rather than only the first.
+#[cfg(feature = "alloc")]
+pub fn try_unidecode_all(s: &str) -> Result<String, Vec<UnidecodeError>> {
+    let mut out = String::with_capacity(s.len());
+    let mut errors = Vec::new();
+    for (char_index, (i, ch)) in s.char_indices().enumerate() {
+        match lookup(ch) {
+            Some(t) => out.push_str(t),
+            None => errors.push(UnidecodeError::new(ch, i, char_index)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(out)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Looks up a character in the data set, returning `None` if the character is
+/// unknown: either its script was left out with a Cargo feature, or it is
+/// mapped to `"[?]"`, like every code point that no source of the data set
+/// covers.
+#[cfg(feature = "alloc")]
+pub(crate) fn lookup(ch: char) -> Option<&'static str> {
+    let block = BLOCKS[ch as usize >> 8] as usize;
+    if TABLES[block >> 12].1.is_empty() {
+        return None;
+    }
+    match unidecode_char(ch) {
+        "[?]" | "[?] " => None,
+        s => Some(s),
+    }
+}
+
+/// This function works like `unidecode()`, except that emoji are transliterated
+/// to their short names surrounded by colons, such as `":grinning_face:"`.
+///
+/// Whole emoji sequences are recognized, including ZWJ sequences, skin tone
+/// modifiers, keycaps and flags. Flags are named after their region codes.
+/// Characters displayed as text by default, such as `©` and `❤`, are only
+/// emoji when followed by a variation selector (U+FE0F); otherwise they are
+/// transliterated like `unidecode()` does. Other variation selectors are
+/// ignored.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// assert_eq!(
+///     unidecode_emoji("I ❤\u{FE0F} 😀"),
+///     "I :red_heart: :grinning_face:"
+/// );
+/// assert_eq!(unidecode_emoji("© 2024 Foo™"), "(c) 2024 Footm");
+/// assert_eq!(unidecode_emoji("🇩🇪"), ":flag_de:");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn unidecode_emoji(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(ch) = rest.chars().next() {
+        match emoji::find(rest) {
+            Some((name, len)) => {
+                out.push(':');
+                out.push_str(name);
+                out.push(':');
+                rest = &rest[len..];
+            },
+            None => {
+                out.push_str(unidecode_char(ch));
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+/// This function works like `unidecode()`, except that the Brahmic scripts of
+/// India are transliterated syllable by syllable, with the inherent vowels of
+/// consonants, using the default options of `IndicTransliterator`.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// assert_eq!(unidecode_indic("മലയാലമ്"), "malayaalam");
+/// assert_eq!(unidecode_indic("हिंदी"), "hindii");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn unidecode_indic(s: &str) -> String {
+    IndicTransliterator::new().transliterate(s)
 }
@@ -0,0 +1,60 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Custom transliterations layered over the data set, stored as sorted,
+/// non-overlapping ranges of code points so that a character is looked up with
+/// a binary search. Characters below the first range, which includes all ASCII
+/// characters unless they are overridden, are rejected by a single comparison.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Overrides {
+    ranges: Vec<(u32, u32, usize)>,
+    strings: Vec<String>,
+}
+
+impl Overrides {
+    /// Maps the code points from `start` to `end`, inclusive, to `s`, taking
+    /// precedence over the earlier overrides of these code points.
+    pub(crate) fn insert(&mut self, start: u32, end: u32, s: &str) {
+        if start > end {
+            return;
+        }
+        let index = self.strings.len();
+        self.strings.push(String::from(s));
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 2);
+        for &(s, e, i) in &self.ranges {
+            if e < start || s > end {
+                ranges.push((s, e, i));
+                continue;
+            }
+            if s < start {
+                ranges.push((s, start - 1, i));
+            }
+            if e > end {
+                ranges.push((end + 1, e, i));
+            }
+        }
+        ranges.push((start, end, index));
+        ranges.sort_by_key(|&(s, _, _)| s);
+        self.ranges = ranges;
+    }
+
+    /// Returns the custom transliteration of a character, if any.
+    #[inline]
+    pub(crate) fn get(&self, ch: char) -> Option<&str> {
+        let u = ch as u32;
+        match self.ranges.first() {
+            Some(&(start, _, _)) if u >= start => {},
+            _ => return None,
+        }
+        let i = match self.ranges.binary_search_by_key(&u, |&(s, _, _)| s) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let (_, end, index) = self.ranges[i];
+        if u <= end {
+            Some(&self.strings[index])
+        } else {
+            None
+        }
+    }
+}
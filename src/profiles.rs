@@ -0,0 +1,136 @@
+/// The custom transliterations of a language.
+type Profile = [(char, &'static str)];
+
+/// The transliterations that differ from the data set in the conventions of a
+/// language, with the BCP 47 language subtags that select them.
+///
+/// Letters that expand to several letters are title-cased when uppercase, like
+/// `Þ` to `"Th"` in the data set, except `ẞ`, which only occurs in uppercase
+/// words. Languages whose conventions the data set already follows, such as
+/// Czech, Polish or Turkish, have no profile.
+static PROFILES: [(&[&str], &Profile); 11] = [
+    (&["az"], &AZERBAIJANI),
+    (&["be"], &BELARUSIAN),
+    (&["bg"], &BULGARIAN),
+    (&["da", "nb", "nn", "no"], &DANISH),
+    (&["de"], &GERMAN),
+    (&["el"], &GREEK),
+    (&["mk"], &MACEDONIAN),
+    (&["ru"], &RUSSIAN),
+    (&["sr"], &SERBIAN),
+    (&["sv"], &SWEDISH),
+    (&["uk"], &UKRAINIAN),
+];
+
+/// Finds the profile of a BCP 47 language tag, such as `"de-CH"`. Subtags are
+/// removed from the end of the tag until a profile matches, so `"de-CH"` falls
+/// back to `"de"`. Returns `None` if there is no profile for the language.
+pub(crate) fn find(tag: &str) -> Option<&'static Profile> {
+    let mut tag = tag;
+    loop {
+        for &(tags, profile) in PROFILES.iter() {
+            if tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                return Some(profile);
+            }
+        }
+        match tag.rfind(['-', '_']) {
+            Some(i) => tag = &tag[..i],
+            None => return None,
+        }
+    }
+}
+
+/// Azerbaijani, with the schwa written as in the English-language press.
+static AZERBAIJANI: [(char, &str); 2] = [('Ə', "A"), ('ə', "a")];
+
+/// Belarusian, in the national romanization without diacritics.
+static BELARUSIAN: [(char, &str); 18] = [
+    ('Г', "H"), ('г', "h"), ('Ё', "Io"), ('ё', "io"),
+    ('І', "I"), ('і', "i"), ('Й', "J"), ('й', "j"),
+    ('Ў', "U"), ('ў', "u"), ('Ы', "Y"), ('ы', "y"),
+    ('Ь', ""), ('ь', ""), ('Ю', "Iu"), ('ю', "iu"),
+    ('Я', "Ia"), ('я', "ia"),
+];
+
+/// Bulgarian, in the Streamlined System used since 2009.
+static BULGARIAN: [(char, &str); 22] = [
+    ('Ж', "Zh"), ('ж', "zh"), ('Й', "Y"), ('й', "y"),
+    ('Х', "H"), ('х', "h"), ('Ц', "Ts"), ('ц', "ts"),
+    ('Ч', "Ch"), ('ч', "ch"), ('Ш', "Sh"), ('ш', "sh"),
+    ('Щ', "Sht"), ('щ', "sht"), ('Ъ', "A"), ('ъ', "a"),
+    ('Ь', "Y"), ('ь', "y"), ('Ю', "Yu"), ('ю', "yu"),
+    ('Я', "Ya"), ('я', "ya"),
+];
+
+/// Danish and Norwegian, keeping the data set for `Æ`.
+static DANISH: [(char, &str); 4] =
+    [('Ø', "Oe"), ('ø', "oe"), ('Å', "Aa"), ('å', "aa")];
+
+/// German.
+static GERMAN: [(char, &str); 8] = [
+    ('Ä', "Ae"), ('ä', "ae"), ('Ö', "Oe"), ('ö', "oe"),
+    ('Ü', "Ue"), ('ü', "ue"), ('ß', "ss"), ('ẞ', "SS"),
+];
+
+/// Modern Greek, letter by letter in the conventions of ELOT 743.
+static GREEK: [(char, &str); 38] = [
+    ('Β', "V"), ('β', "v"), ('Η', "I"), ('η', "i"),
+    ('Ή', "I"), ('ή', "i"), ('Υ', "Y"), ('υ', "y"),
+    ('Ύ', "Y"), ('ύ', "y"), ('Ϋ', "Y"), ('ϋ', "y"),
+    ('ΰ', "y"), ('ΐ', "i"), ('Φ', "F"), ('φ', "f"),
+    ('Χ', "Ch"), ('χ', "ch"), ('Ψ', "Ps"), ('ψ', "ps"),
+    ('Θ', "Th"), ('θ', "th"), ('Ξ', "X"), ('ξ', "x"),
+    ('Ω', "O"), ('ω', "o"), ('Ώ', "O"), ('ώ', "o"),
+    ('Γ', "G"), ('γ', "g"), ('Δ', "D"), ('δ', "d"),
+    ('Ζ', "Z"), ('ζ', "z"), ('Σ', "S"), ('σ', "s"),
+    ('ς', "s"), ('Ί', "I"),
+];
+
+/// Macedonian, in the official romanization without diacritics.
+static MACEDONIAN: [(char, &str); 22] = [
+    ('Ѓ', "Gj"), ('ѓ', "gj"), ('Ж', "Zh"), ('ж', "zh"),
+    ('Ѕ', "Dz"), ('ѕ', "dz"), ('Ј', "J"), ('ј', "j"),
+    ('Љ', "Lj"), ('љ', "lj"), ('Њ', "Nj"), ('њ', "nj"),
+    ('Ќ', "Kj"), ('ќ', "kj"), ('Х', "H"), ('х', "h"),
+    ('Ц', "C"), ('ц', "c"), ('Ч', "Ch"), ('ч', "ch"),
+    ('Џ', "Dj"), ('џ', "dj"),
+];
+
+/// Russian, in the common English-language romanization.
+static RUSSIAN: [(char, &str); 16] = [
+    ('Ё', "Yo"), ('ё', "yo"), ('Й', "Y"), ('й', "y"),
+    ('Ъ', ""), ('ъ', ""), ('Ь', ""), ('ь', ""),
+    ('Э', "E"), ('э', "e"), ('Ю', "Yu"), ('ю', "yu"),
+    ('Я', "Ya"), ('я', "ya"), ('Х', "Kh"), ('х', "kh"),
+];
+
+/// Serbian, written in Cyrillic or in Latin, in the Latin alphabet without
+/// diacritics.
+static SERBIAN: [(char, &str); 34] = [
+    ('Ђ', "Dj"), ('ђ', "dj"), ('Ж', "Z"), ('ж', "z"),
+    ('Ј', "J"), ('ј', "j"), ('Љ', "Lj"), ('љ', "lj"),
+    ('Њ', "Nj"), ('њ', "nj"), ('Ћ', "C"), ('ћ', "c"),
+    ('Х', "H"), ('х', "h"), ('Ц', "C"), ('ц', "c"),
+    ('Ч', "C"), ('ч', "c"), ('Џ', "Dz"), ('џ', "dz"),
+    ('Ш', "S"), ('ш', "s"), ('Č', "C"), ('č', "c"),
+    ('Ć', "C"), ('ć', "c"), ('Đ', "Dj"), ('đ', "dj"),
+    ('Š', "S"), ('š', "s"), ('Ž', "Z"), ('ž', "z"),
+    ('Ǆ', "Dz"), ('ǆ', "dz"),
+];
+
+/// Swedish, in the conventions of machine-readable passports.
+static SWEDISH: [(char, &str); 6] = [
+    ('Ä', "Ae"), ('ä', "ae"), ('Ö', "Oe"), ('ö', "oe"),
+    ('Å', "Aa"), ('å', "aa"),
+];
+
+/// Ukrainian, in the national romanization of 2010, without the special cases
+/// at the start of words.
+static UKRAINIAN: [(char, &str); 21] = [
+    ('Г', "H"), ('г', "h"), ('Ґ', "G"), ('ґ', "g"),
+    ('Є', "Ie"), ('є', "ie"), ('И', "Y"), ('и', "y"),
+    ('І', "I"), ('і', "i"), ('Ї', "I"), ('ї', "i"),
+    ('Й', "I"), ('й', "i"), ('Ь', ""), ('ь', ""),
+    ('Ю', "Iu"), ('ю', "iu"), ('Я', "Ia"), ('я', "ia"),
+    ('ʼ', ""),
+];
@@ -0,0 +1,248 @@
+use std::boxed::Box;
+use std::fmt::Write as FmtWrite;
+use std::io::{self, Read, Write};
+use std::str;
+use std::string::String;
+use std::vec::Vec;
+
+use unidecode_into;
+
+/// Size of the buffer used by `UnidecodeReader` to read from its source.
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// How the streaming adapters handle bytes that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidUtf8 {
+    /// Fail with an error of kind `io::ErrorKind::InvalidData`.
+    Error,
+    /// Replace every invalid sequence with `"?"`.
+    Replace,
+    /// Drop invalid sequences from the output.
+    Skip,
+    /// Write every invalid byte as an escape sequence, such as `\xFF`.
+    Escape,
+}
+
+impl InvalidUtf8 {
+    fn handle(self, bytes: &[u8], out: &mut String) -> io::Result<()> {
+        match self {
+            InvalidUtf8::Error => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                ));
+            },
+            InvalidUtf8::Replace => out.push('?'),
+            InvalidUtf8::Skip => {},
+            InvalidUtf8::Escape => {
+                for b in bytes {
+                    let _ = write!(out, "\\x{:02X}", b);
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Transliterates as much of `bytes` as possible into `out`, returning the
+/// number of bytes consumed. An incomplete UTF-8 sequence at the end of
+/// `bytes` is left unconsumed, unless `eof` is set.
+fn decode(
+    bytes: &[u8],
+    eof: bool,
+    invalid: InvalidUtf8,
+    out: &mut String,
+) -> io::Result<usize> {
+    let mut pos = 0;
+    loop {
+        match str::from_utf8(&bytes[pos..]) {
+            Ok(s) => {
+                unidecode_into(s, out);
+                return Ok(bytes.len());
+            },
+            Err(e) => {
+                let valid = pos + e.valid_up_to();
+                let s = str::from_utf8(&bytes[pos..valid]).unwrap();
+                unidecode_into(s, out);
+                pos = valid;
+                let len = match e.error_len() {
+                    Some(len) => len,
+                    None if eof => bytes.len() - pos,
+                    None => return Ok(pos),
+                };
+                invalid.handle(&bytes[pos..pos + len], out)?;
+                pos += len;
+            },
+        }
+    }
+}
+
+/// A reader that transliterates the UTF-8 text read from another reader.
+///
+/// UTF-8 sequences split across reads of the underlying reader are handled
+/// correctly, so the text can be read in chunks of any size.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let mut reader = UnidecodeReader::new("Æneid".as_bytes());
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).unwrap();
+/// assert_eq!(out, "AEneid");
+/// ```
+#[derive(Debug)]
+pub struct UnidecodeReader<R> {
+    inner: R,
+    invalid: InvalidUtf8,
+    buf: Box<[u8]>,
+    buf_len: usize,
+    out: String,
+    out_pos: usize,
+    eof: bool,
+}
+
+impl<R: Read> UnidecodeReader<R> {
+    /// Creates a reader that fails on invalid UTF-8.
+    pub fn new(inner: R) -> UnidecodeReader<R> {
+        UnidecodeReader::with_invalid_utf8(inner, InvalidUtf8::Error)
+    }
+
+    /// Creates a reader that handles invalid UTF-8 according to the given
+    /// policy.
+    pub fn with_invalid_utf8(
+        inner: R,
+        invalid: InvalidUtf8,
+    ) -> UnidecodeReader<R> {
+        UnidecodeReader {
+            inner,
+            invalid,
+            buf: std::vec![0; BUFFER_SIZE].into_boxed_slice(),
+            buf_len: 0,
+            out: String::new(),
+            out_pos: 0,
+            eof: false,
+        }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads from the underlying reader until some output is available or the
+    /// end of the input is reached.
+    fn fill(&mut self) -> io::Result<()> {
+        self.out.clear();
+        self.out_pos = 0;
+        while self.out.is_empty() && !self.eof {
+            let n = self.inner.read(&mut self.buf[self.buf_len..])?;
+            self.eof = n == 0;
+            let len = self.buf_len + n;
+            let bytes = &self.buf[..len];
+            let consumed = decode(bytes, self.eof, self.invalid, &mut self.out)?;
+            self.buf.copy_within(consumed..len, 0);
+            self.buf_len = len - consumed;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for UnidecodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.out_pos == self.out.len() {
+            self.fill()?;
+        }
+        let available = &self.out.as_bytes()[self.out_pos..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.out_pos += n;
+        Ok(n)
+    }
+}
+
+/// A writer that transliterates UTF-8 text before writing it to another
+/// writer.
+///
+/// UTF-8 sequences split across calls to `write()` are handled correctly. An
+/// incomplete sequence at the end of the text is only reported by `finish()`,
+/// which should be called once all text has been written.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let mut writer = UnidecodeWriter::new(Vec::new());
+/// writer.write_all("Æneid".as_bytes()).unwrap();
+/// assert_eq!(writer.finish().unwrap(), b"AEneid");
+/// ```
+#[derive(Debug)]
+pub struct UnidecodeWriter<W: Write> {
+    inner: W,
+    invalid: InvalidUtf8,
+    pending: Vec<u8>,
+    out: String,
+}
+
+impl<W: Write> UnidecodeWriter<W> {
+    /// Creates a writer that fails on invalid UTF-8.
+    pub fn new(inner: W) -> UnidecodeWriter<W> {
+        UnidecodeWriter::with_invalid_utf8(inner, InvalidUtf8::Error)
+    }
+
+    /// Creates a writer that handles invalid UTF-8 according to the given
+    /// policy.
+    pub fn with_invalid_utf8(
+        inner: W,
+        invalid: InvalidUtf8,
+    ) -> UnidecodeWriter<W> {
+        UnidecodeWriter {
+            inner,
+            invalid,
+            pending: Vec::with_capacity(4),
+            out: String::new(),
+        }
+    }
+
+    /// Handles any incomplete UTF-8 sequence left at the end of the text,
+    /// flushes the underlying writer and returns it.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.clear();
+        decode(&self.pending, true, self.invalid, &mut self.out)?;
+        self.pending.clear();
+        self.inner.write_all(self.out.as_bytes())?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for UnidecodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.clear();
+        let mut rest = buf;
+
+        // Complete the sequence left over from the previous write using the
+        // first few bytes of this one.
+        if !self.pending.is_empty() {
+            let pending_len = self.pending.len();
+            let head = &buf[..buf.len().min(4)];
+            self.pending.extend_from_slice(head);
+            let consumed =
+                decode(&self.pending, false, self.invalid, &mut self.out)?;
+            if consumed == 0 {
+                // The sequence is still incomplete, so `buf` is too short to
+                // contain anything else.
+                return Ok(buf.len());
+            }
+            rest = &buf[consumed - pending_len..];
+            self.pending.clear();
+        }
+
+        let consumed = decode(rest, false, self.invalid, &mut self.out)?;
+        self.pending.extend_from_slice(&rest[consumed..]);
+        self.inner.write_all(self.out.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,341 @@
+use alloc::string::String;
+use core::fmt::Write;
+use core::ops::RangeInclusive;
+
+use error::UnidecodeError;
+use overrides::Overrides;
+use profiles;
+use version::TableVersion;
+
+/// How a `Transliterator` handles characters that `rust-unidecode` does not
+/// know about.
+///
+/// A character is unknown if the data set maps it to `"[?]"`, as it does for
+/// every code point that none of its sources cover, or if its script was left
+/// out with a Cargo feature. Characters that are deliberately
+/// transliterated to an empty string, such as combining marks, are not unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unknown {
+    /// Drop unknown characters from the output.
+    Ignore,
+    /// Replace every unknown character with the given string.
+    Replace(String),
+    /// Copy unknown characters to the output unchanged. The output will not be
+    /// pure ASCII.
+    Keep,
+    /// Write unknown characters as escape sequences.
+    Escape(Escape),
+    /// Fail with an error naming the first unknown character.
+    Error,
+}
+
+/// The escape sequence written for unknown characters by `Unknown::Escape`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    /// A Rust escape, such as `\u{1900}`.
+    Rust,
+    /// A code point notation, such as `U+1900`.
+    CodePoint,
+    /// A hexadecimal HTML character reference, such as `&#x1900;`.
+    Html,
+}
+
+impl Escape {
+    fn write(self, out: &mut String, ch: char) {
+        let u = ch as u32;
+        let _ = match self {
+            Escape::Rust => write!(out, "\\u{{{:04X}}}", u),
+            Escape::CodePoint => write!(out, "U+{:04X}", u),
+            Escape::Html => write!(out, "&#x{:04X};", u),
+        };
+    }
+}
+
+/// How a `Transliterator` separates the transliterations of characters that
+/// are words on their own, such as CJK ideographs.
+///
+/// The data set transliterates these characters with a trailing space, so
+/// `"北亰"` becomes `"Bei Jing "` and `"げんまい茶"` becomes `"genmaiCha "`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spacing {
+    /// Keep the spaces of the data set.
+    Table,
+    /// Separate words with exactly one space, without spaces at the end of the
+    /// input or before whitespace and ASCII punctuation: `"Bei Jing"` and
+    /// `"genmai Cha"`.
+    Words,
+    /// Join the words without spaces, for identifiers: `"BeiJing"` and
+    /// `"genmaiCha"`.
+    Joined,
+}
+
+/// Inserts the separators between words for a `Spacing`.
+struct Spacer {
+    spacing: Spacing,
+    /// Whether the last transliteration appended was a whole word, which needs
+    /// a separator before the next one.
+    pending: bool,
+}
+
+impl Spacer {
+    /// Appends the separator needed before a transliteration, if any, and
+    /// returns the part of the transliteration to append.
+    fn before<'a>(&mut self, out: &mut String, t: &'a str) -> &'a str {
+        if self.spacing == Spacing::Table {
+            return t;
+        }
+        let word = t.len() > 1 && t.ends_with(' ');
+        let t = if word { t.trim_end() } else { t };
+        if let Some(c) = t.chars().next() {
+            let last = out.chars().next_back();
+            let separate = if self.pending {
+                !c.is_whitespace() && !c.is_ascii_punctuation()
+            } else {
+                word && last.is_some_and(char::is_alphanumeric)
+            };
+            if separate && self.spacing == Spacing::Words {
+                out.push(' ');
+            }
+            self.pending = word;
+        }
+        t
+    }
+}
+
+/// A transliterator with a configurable policy for unknown characters, and
+/// optionally custom transliterations of some characters.
+///
+/// A transliterator is `Send` and `Sync`, so it can be built once and shared
+/// between threads.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = Transliterator::new(Unknown::Escape(Escape::CodePoint));
+/// assert_eq!(t.transliterate("Æ\u{1900}").unwrap(), "AEU+1900");
+///
+/// let t = Transliterator::builder().override_char('ä', "ae").build();
+/// assert_eq!(t.transliterate("Käse").unwrap(), "Kaese");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transliterator {
+    unknown: Unknown,
+    table: TableVersion,
+    overrides: Overrides,
+    context_case: bool,
+    spacing: Spacing,
+}
+
+impl Transliterator {
+    /// Creates a transliterator that handles unknown characters according to
+    /// the given policy.
+    pub fn new(unknown: Unknown) -> Transliterator {
+        Transliterator::with_table(unknown, TableVersion::LATEST)
+    }
+
+    /// Creates a transliterator that uses the given version of the data set,
+    /// rather than the latest one.
+    pub fn with_table(unknown: Unknown, table: TableVersion) -> Transliterator {
+        Transliterator {
+            unknown,
+            table,
+            overrides: Overrides::default(),
+            context_case: false,
+            spacing: Spacing::Table,
+        }
+    }
+
+    /// Creates a transliterator following the conventions of a language, given
+    /// as a BCP 47 language tag such as `"de"` or `"de-CH"`. See
+    /// `TransliteratorBuilder::language()` for details.
+    pub fn for_language(tag: &str) -> Transliterator {
+        Transliterator::builder().language(tag).build()
+    }
+
+    /// Creates a builder for a transliterator with custom transliterations.
+    pub fn builder() -> TransliteratorBuilder {
+        TransliteratorBuilder {
+            transliterator: Transliterator::new(Unknown::Ignore),
+        }
+    }
+
+    /// Transliterates a string, applying the custom transliterations and the
+    /// unknown character policy.
+    ///
+    /// An error is only returned if the policy is `Unknown::Error`.
+    pub fn transliterate(&self, s: &str) -> Result<String, UnidecodeError> {
+        let mut out = String::with_capacity(s.len());
+        let mut spacer = Spacer { spacing: self.spacing, pending: false };
+        let mut prev = None;
+        for (char_index, (i, ch)) in s.char_indices().enumerate() {
+            let t = match self.overrides.get(ch) {
+                Some(t) => Some(t),
+                None => self.table.lookup(ch),
+            };
+            match t {
+                Some(t) if self.context_case && ch.is_uppercase() => {
+                    let t = spacer.before(&mut out, t);
+                    let next = s[i + ch.len_utf8()..].chars().next();
+                    push_cased(&mut out, t, in_caps(prev, next));
+                },
+                Some(t) => {
+                    let t = spacer.before(&mut out, t);
+                    out.push_str(t);
+                },
+                None => match self.unknown {
+                    Unknown::Ignore => {},
+                    Unknown::Replace(ref r) => {
+                        let r = spacer.before(&mut out, r);
+                        out.push_str(r);
+                    },
+                    Unknown::Keep => {
+                        spacer.before(&mut out, ch.encode_utf8(&mut [0; 4]));
+                        out.push(ch);
+                    },
+                    Unknown::Escape(escape) => {
+                        let mut e = String::new();
+                        escape.write(&mut e, ch);
+                        let e = spacer.before(&mut out, &e);
+                        out.push_str(e);
+                    },
+                    Unknown::Error => {
+                        return Err(UnidecodeError::new(ch, i, char_index));
+                    }
+                },
+            }
+            prev = Some(ch);
+        }
+        Ok(out)
+    }
+}
+
+/// Returns whether an uppercase character is part of a run of capitals, given
+/// the characters around it. A character followed by a lowercase letter is
+/// not; otherwise, the following letter or else the preceding one decides.
+fn in_caps(prev: Option<char>, next: Option<char>) -> bool {
+    match next {
+        Some(c) if c.is_lowercase() => false,
+        Some(c) if c.is_uppercase() => true,
+        _ => prev.is_some_and(char::is_uppercase),
+    }
+}
+
+/// Appends the transliteration of an uppercase character, in uppercase if it
+/// is part of a run of capitals and in title case otherwise. Transliterations
+/// with fewer than two letters are appended as is.
+fn push_cased(out: &mut String, t: &str, caps: bool) {
+    if t.bytes().filter(u8::is_ascii_alphabetic).count() < 2 {
+        out.push_str(t);
+        return;
+    }
+    let mut first = true;
+    for c in t.chars() {
+        if !c.is_ascii_alphabetic() {
+            out.push(c);
+        } else if caps || first {
+            out.push(c.to_ascii_uppercase());
+            first = false;
+        } else {
+            out.push(c.to_ascii_lowercase());
+        }
+    }
+}
+
+/// A builder for a `Transliterator`, created with `Transliterator::builder()`.
+///
+/// Unless configured otherwise, unknown characters are ignored and the latest
+/// version of the data set is used. Custom transliterations take precedence
+/// over the data set, and later ones over earlier ones.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let t = Transliterator::builder()
+///     .override_char('ä', "ae")
+///     .override_char('ß', "ss")
+///     .override_range('\u{391}'..='\u{3A9}', "?")
+///     .build();
+/// assert_eq!(t.transliterate("Straße").unwrap(), "Strasse");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransliteratorBuilder {
+    transliterator: Transliterator,
+}
+
+impl TransliteratorBuilder {
+    /// Sets the policy for unknown characters.
+    pub fn unknown(mut self, unknown: Unknown) -> TransliteratorBuilder {
+        self.transliterator.unknown = unknown;
+        self
+    }
+
+    /// Sets the version of the data set.
+    pub fn table(mut self, table: TableVersion) -> TransliteratorBuilder {
+        self.transliterator.table = table;
+        self
+    }
+
+    /// Follows the conventions of a language, given as a BCP 47 language tag,
+    /// for the characters whose transliteration depends on the language. For
+    /// example, `ä` is transliterated as `"ae"` in German and `ø` as `"oe"` in
+    /// Danish, where the data set gives `"a"` and `"o"`.
+    ///
+    /// If there is no profile for the tag, its subtags are removed from the
+    /// end until one matches, so `"de-CH"` falls back to `"de"`. If none
+    /// matches, the data set is used as is. Profiles are available for `az`,
+    /// `be`, `bg`, `da`, `de`, `el`, `mk`, `nb`, `nn`, `no`, `ru`, `sr`, `sv`
+    /// and `uk`.
+    ///
+    /// The profile is applied as custom transliterations, so it takes
+    /// precedence over the earlier ones and is overridden by the later ones.
+    pub fn language(mut self, tag: &str) -> TransliteratorBuilder {
+        if let Some(profile) = profiles::find(tag) {
+            for &(ch, s) in profile {
+                self = self.override_char(ch, s);
+            }
+        }
+        self
+    }
+
+    /// Adapts the case of the transliterations of uppercase characters that
+    /// expand to several letters to the surrounding text, which the data set
+    /// cannot do for single characters. With this option, `Æ` is
+    /// transliterated as `"Ae"` in `"Æneid"` but as `"AE"` in `"ÆNEID"`.
+    ///
+    /// The transliteration is uppercased if the character is followed by an
+    /// uppercase letter, or if it is not followed by a letter and preceded by
+    /// an uppercase letter. It is title-cased otherwise.
+    pub fn context_case(mut self, enabled: bool) -> TransliteratorBuilder {
+        self.transliterator.context_case = enabled;
+        self
+    }
+
+    /// Sets how the transliterations of characters that are words on their
+    /// own, such as CJK ideographs, are separated.
+    pub fn spacing(mut self, spacing: Spacing) -> TransliteratorBuilder {
+        self.transliterator.spacing = spacing;
+        self
+    }
+
+    /// Transliterates a character to the given string.
+    pub fn override_char(mut self, ch: char, s: &str) -> TransliteratorBuilder {
+        self.transliterator.overrides.insert(ch as u32, ch as u32, s);
+        self
+    }
+
+    /// Transliterates every character of a range to the given string.
+    pub fn override_range(
+        mut self,
+        range: RangeInclusive<char>,
+        s: &str,
+    ) -> TransliteratorBuilder {
+        let (start, end) = range.into_inner();
+        self.transliterator.overrides.insert(start as u32, end as u32, s);
+        self
+    }
+
+    /// Builds the transliterator.
+    pub fn build(self) -> Transliterator {
+        self.transliterator
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "table-1-30")]
+use data::TABLE_1_30;
+use unidecode_char;
+
+/// A version of the data set, so that transliterations made with an older
+/// version of `rust-unidecode` can be reproduced exactly.
+///
+/// The latest version is always available. Each older version is kept behind
+/// a Cargo feature, as listed below, and is stored as its differences from the
+/// latest version. The code points whose transliteration changed between two
+/// versions are listed in the `data/changes/` directory of the repository.
+///
+/// Examples
+/// --------
+/// ```ignore
+/// let pinned = TableVersion::V1_30;
+/// assert_eq!(pinned.unidecode_char('\u{1CCD6}'), "");
+/// assert_eq!(TableVersion::LATEST.unidecode_char('\u{1CCD6}'), "A");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableVersion {
+    /// The data set of `Text::Unidecode` 1.30, as shipped by `rust-unidecode`
+    /// 0.3.0. It only covers the Basic Multilingual Plane, so every other
+    /// character, like the blocks missing from `Text::Unidecode`, is
+    /// transliterated to an empty string. Requires the `table-1-30` feature.
+    #[cfg(feature = "table-1-30")]
+    V1_30,
+    /// The data set of `Text::Unidecode` 1.30, with the decompositions of the
+    /// Unicode Character Database 17.0.0 for characters outside the Basic
+    /// Multilingual Plane.
+    V1_30Unicode17,
+}
+
+impl TableVersion {
+    /// The latest version, used by `unidecode()` and the other functions.
+    pub const LATEST: TableVersion = TableVersion::V1_30Unicode17;
+
+    /// Works like `unidecode_char()`, using this version of the data set.
+    pub fn unidecode_char(self, ch: char) -> &'static str {
+        if ch > self.last() {
+            return "";
+        }
+        let changes = self.changes();
+        match changes.binary_search_by_key(&(ch as u32), |&(u, _)| u) {
+            Ok(i) => changes[i].1,
+            Err(_) => unidecode_char(ch),
+        }
+    }
+
+    /// Works like `unidecode()`, using this version of the data set.
+    #[cfg(feature = "alloc")]
+    pub fn unidecode(self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        for ch in s.chars() {
+            out.push_str(self.unidecode_char(ch));
+        }
+        out
+    }
+
+    /// Looks up a character like `lookup()`, using this version of the data
+    /// set.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn lookup(self, ch: char) -> Option<&'static str> {
+        if ch > self.last() {
+            return None;
+        }
+        let changes = self.changes();
+        match changes.binary_search_by_key(&(ch as u32), |&(u, _)| u) {
+            Ok(i) => match changes[i].1 {
+                "" | "[?]" | "[?] " => None,
+                s => Some(s),
+            },
+            Err(_) => ::lookup(ch),
+        }
+    }
+
+    /// The last character covered by this version.
+    fn last(self) -> char {
+        match self {
+            #[cfg(feature = "table-1-30")]
+            TableVersion::V1_30 => '\u{FFFF}',
+            TableVersion::V1_30Unicode17 => char::MAX,
+        }
+    }
+
+    /// The code points whose transliteration differs from the latest version,
+    /// with their transliteration in this version.
+    fn changes(self) -> &'static [(u32, &'static str)] {
+        match self {
+            #[cfg(feature = "table-1-30")]
+            TableVersion::V1_30 => &TABLE_1_30,
+            TableVersion::V1_30Unicode17 => &[],
+        }
+    }
+}
+
+impl Default for TableVersion {
+    fn default() -> TableVersion {
+        TableVersion::LATEST
+    }
+}
@@ -0,0 +1,62 @@
+extern crate unidecode;
+use unidecode::{UnidecodeExt, Unidecoded};
+
+#[test]
+fn test_unidecoded_display() {
+    assert_eq!(format!("<{}>", Unidecoded("Æneid")), "<AEneid>");
+    #[cfg(feature = "cjk")]
+    assert_eq!(Unidecoded("北亰").to_string(), "Bei Jing ");
+}
+
+#[test]
+fn test_unidecode_iter() {
+    let pieces: Vec<_> = "Æneid".chars().unidecode().collect();
+    assert_eq!(pieces, ["AE", "n", "e", "i", "d"]);
+
+    let pieces: Vec<_> = "Æ½".chars().unidecode().rev().collect();
+    assert_eq!(pieces, ["1/2", "AE"]);
+    #[cfg(feature = "cjk")]
+    {
+        let pieces: Vec<_> = "北亰".chars().unidecode().rev().collect();
+        assert_eq!(pieces, ["Jing ", "Bei "]);
+    }
+}
+
+#[test]
+fn test_unidecode_chars() {
+    let s: String = "Æneid ½".chars().unidecode().chars().collect();
+    assert_eq!(s, "AEneid 1/2");
+
+    let s: String = "Æneid ½".chars().unidecode().chars().rev().collect();
+    assert_eq!(s, "2/1 dienEA");
+
+    // Consuming from both ends must yield every char exactly once
+    let mut chars = "Æ½".chars().unidecode().chars();
+    assert_eq!(chars.next(), Some('A'));
+    assert_eq!(chars.next_back(), Some('2'));
+    assert_eq!(chars.next_back(), Some('/'));
+    assert_eq!(chars.next(), Some('E'));
+    assert_eq!(chars.next(), Some('1'));
+    assert_eq!(chars.next(), None);
+    assert_eq!(chars.next_back(), None);
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_unidecode_chars_cjk() {
+    let s: String = "Æneid 北亰".chars().unidecode().chars().collect();
+    assert_eq!(s, "AEneid Bei Jing ");
+
+    let s: String = "Æneid 北亰".chars().unidecode().chars().rev().collect();
+    assert_eq!(s, " gniJ ieB dienEA");
+
+    let mut chars = "Æ北".chars().unidecode().chars();
+    assert_eq!(chars.next(), Some('A'));
+    assert_eq!(chars.next_back(), Some(' '));
+    assert_eq!(chars.next_back(), Some('i'));
+    assert_eq!(chars.next(), Some('E'));
+    assert_eq!(chars.next(), Some('B'));
+    assert_eq!(chars.next(), Some('e'));
+    assert_eq!(chars.next(), None);
+    assert_eq!(chars.next_back(), None);
+}
@@ -0,0 +1,94 @@
+#![cfg(all(feature = "alloc", feature = "cjk"))]
+
+extern crate unidecode;
+use unidecode::{ChineseTransliterator, PinyinStyle};
+
+fn plain(s: &str) -> String {
+    ChineseTransliterator::new().transliterate(s)
+}
+
+fn numbers(s: &str) -> String {
+    ChineseTransliterator::new()
+        .style(PinyinStyle::ToneNumbers)
+        .transliterate(s)
+}
+
+fn marks(s: &str) -> String {
+    ChineseTransliterator::new()
+        .style(PinyinStyle::ToneMarks)
+        .transliterate(s)
+}
+
+#[test]
+fn test_plain() {
+    assert_eq!(plain("北京"), "beijing");
+    assert_eq!(plain("我是中国人"), "wo shi zhongguo ren");
+    assert_eq!(plain("绿"), "lu");
+    assert_eq!(plain("西安"), "xi'an");
+    assert_eq!(plain("天安门"), "tian'anmen");
+}
+
+#[test]
+fn test_tone_numbers() {
+    assert_eq!(numbers("北京"), "bei3 jing1");
+    assert_eq!(numbers("我是中国人"), "wo3 shi4 zhong1 guo2 ren2");
+    assert_eq!(numbers("绿"), "lv4");
+    assert_eq!(numbers("西安"), "xi1 an1");
+    assert_eq!(numbers("谢谢"), "xie4 xie5");
+}
+
+#[test]
+fn test_tone_marks() {
+    assert_eq!(marks("北京"), "běijīng");
+    assert_eq!(marks("绿"), "lǜ");
+    assert_eq!(marks("女"), "nǚ");
+    assert_eq!(marks("西安"), "xī'ān");
+    assert_eq!(marks("谢谢"), "xièxie");
+    assert_eq!(marks("走"), "zǒu");
+    assert_eq!(marks("对"), "duì");
+    assert_eq!(marks("六"), "liù");
+    // Syllabic nasals
+    assert_eq!(marks("嗯"), "ń");
+    assert_eq!(numbers("嗯"), "n2");
+}
+
+#[test]
+fn test_polyphones() {
+    assert_eq!(plain("银行"), "yinhang");
+    assert_eq!(plain("行"), "xing");
+    assert_eq!(plain("我在银行工作"), "wo zai yinhang gong zuo");
+    assert_eq!(plain("长大"), "zhangda");
+    assert_eq!(plain("长"), "chang");
+    assert_eq!(plain("音乐"), "yinyue");
+    assert_eq!(plain("快乐"), "kuai le");
+    assert_eq!(plain("重庆"), "chongqing");
+    assert_eq!(plain("秘鲁"), "bilu");
+    assert_eq!(numbers("觉得"), "jue2 de5");
+    assert_eq!(numbers("了解"), "liao3 jie3");
+}
+
+#[test]
+fn test_traditional() {
+    assert_eq!(plain("銀行"), "yinhang");
+    assert_eq!(plain("臺灣"), "tai wan");
+}
+
+#[test]
+fn test_mixed_text() {
+    assert_eq!(plain("北京, 上海"), "beijing, shanghai");
+    assert_eq!(plain("北京，上海。天津"), "beijing, shanghai. tian jin");
+    assert_eq!(plain("我爱Rust"), "wo ai Rust");
+    assert_eq!(plain("3个人"), "3 ge ren");
+    assert_eq!(plain("“北京”"), "\"beijing\"");
+}
+
+#[test]
+fn test_fallback() {
+    // Rare characters have a reading with a tone
+    assert_eq!(plain("龘"), "da");
+    assert_eq!(numbers("龘"), "da2");
+    // Not in the readings of the crate, read like `unidecode()` does
+    assert_eq!(plain("兙"), "shi");
+    assert_eq!(numbers("兙"), "shi");
+    assert_eq!(plain("Æneid"), "AEneid");
+}
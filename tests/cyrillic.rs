@@ -0,0 +1,173 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::{CyrillicLanguage, CyrillicScheme, CyrillicTransliterator};
+
+fn t(scheme: CyrillicScheme, language: CyrillicLanguage, s: &str) -> String {
+    CyrillicTransliterator::new()
+        .scheme(scheme)
+        .language(language)
+        .transliterate(s)
+}
+
+fn bgn(language: CyrillicLanguage, s: &str) -> String {
+    t(CyrillicScheme::BgnPcgn, language, s)
+}
+
+#[test]
+fn test_iso_9() {
+    let iso = |s| t(CyrillicScheme::Iso9, CyrillicLanguage::Russian, s);
+    assert_eq!(iso("Щукин"), "Ŝukin");
+    assert_eq!(iso("Съешь"), "Sʺešʹ");
+    assert_eq!(iso("Ёжик"), "Ëžik");
+    assert_eq!(iso("Ґудзь"), "G\u{300}udzʹ");
+    assert_eq!(iso("Їжак"), "Ïžak");
+    assert_eq!(iso("Љубљана"), "L\u{302}ubl\u{302}ana");
+}
+
+#[test]
+fn test_gost_7_79_b() {
+    use unidecode::CyrillicLanguage::*;
+    let gost = |language, s| t(CyrillicScheme::Gost779B, language, s);
+    assert_eq!(gost(Russian, "Щукин"), "Shhukin");
+    assert_eq!(gost(Russian, "Съешь"), "S``esh`");
+    assert_eq!(gost(Russian, "Ёж"), "Yozh");
+    assert_eq!(gost(Russian, "Цукор"), "Czukor");
+    assert_eq!(gost(Russian, "Цена"), "Cena");
+    assert_eq!(gost(Russian, "Цыбулька"), "Cy`bul`ka");
+    assert_eq!(gost(Ukrainian, "Київ"), "Ky`yiv");
+    assert_eq!(gost(Ukrainian, "Ґанок"), "G`anok");
+    assert_eq!(gost(Belarusian, "Магілёў"), "Magilyou`");
+    assert_eq!(gost(Bulgarian, "Щастие"), "Shtastie");
+    assert_eq!(gost(Bulgarian, "Ъгъл"), "A`ga`l");
+}
+
+#[test]
+fn test_bgn_pcgn_russian() {
+    use unidecode::CyrillicLanguage::Russian;
+    assert_eq!(bgn(Russian, "Ельцин"), "Yel'tsin");
+    assert_eq!(bgn(Russian, "Щукин"), "Shchukin");
+    assert_eq!(bgn(Russian, "Горбачёв"), "Gorbachev");
+    assert_eq!(bgn(Russian, "Воробьёв"), "Vorob'yev");
+    assert_eq!(bgn(Russian, "Подъезд"), "Pod\"yezd");
+    assert_eq!(bgn(Russian, "Юрий Ефремов"), "Yuriy Yefremov");
+    assert_eq!(bgn(Russian, "Чайковский"), "Chaykovskiy");
+}
+
+#[test]
+fn test_bgn_pcgn_diacritics() {
+    use unidecode::CyrillicLanguage::*;
+    let bgn = |language, s| {
+        CyrillicTransliterator::new()
+            .scheme(CyrillicScheme::BgnPcgn)
+            .language(language)
+            .diacritics(true)
+            .transliterate(s)
+    };
+    assert_eq!(bgn(Russian, "Ельцин"), "Yel’tsin");
+    assert_eq!(bgn(Russian, "Горбачёв"), "Gorbachëv");
+    assert_eq!(bgn(Russian, "Воробьёв"), "Vorob’yëv");
+    assert_eq!(bgn(Russian, "Подъезд"), "Pod”yezd");
+    assert_eq!(bgn(Belarusian, "Гомель"), "Homyel’");
+    assert_eq!(bgn(Serbian, "Ђорђевић"), "Đorđević");
+    assert_eq!(bgn(Serbian, "Џаковица"), "Džakovica");
+}
+
+#[test]
+fn test_bgn_pcgn_ukrainian() {
+    use unidecode::CyrillicLanguage::Ukrainian;
+    assert_eq!(bgn(Ukrainian, "Київ"), "Kyiv");
+    assert_eq!(bgn(Ukrainian, "Євген"), "Yevhen");
+    assert_eq!(bgn(Ukrainian, "Юрій"), "Yurii");
+    assert_eq!(bgn(Ukrainian, "Їжакевич"), "Yizhakevych");
+    assert_eq!(bgn(Ukrainian, "Яготин"), "Yahotyn");
+    assert_eq!(bgn(Ukrainian, "Згорани"), "Zghorany");
+    assert_eq!(bgn(Ukrainian, "Знам’янка"), "Znamianka");
+    assert_eq!(bgn(Ukrainian, "Ґалаґан"), "Galagan");
+    assert_eq!(bgn(Ukrainian, "Запоріжжя"), "Zaporizhzhia");
+}
+
+#[test]
+fn test_bgn_pcgn_belarusian() {
+    use unidecode::CyrillicLanguage::Belarusian;
+    assert_eq!(bgn(Belarusian, "Магілёў"), "Mahilyow");
+    assert_eq!(bgn(Belarusian, "Віцебск"), "Vitsyebsk");
+    assert_eq!(bgn(Belarusian, "Гомель"), "Homyel'");
+    assert_eq!(bgn(Belarusian, "Гродна"), "Hrodna");
+}
+
+#[test]
+fn test_bgn_pcgn_bulgarian() {
+    use unidecode::CyrillicLanguage::Bulgarian;
+    assert_eq!(bgn(Bulgarian, "София"), "Sofia");
+    assert_eq!(bgn(Bulgarian, "България"), "Balgaria");
+    assert_eq!(bgn(Bulgarian, "Пловдив"), "Plovdiv");
+    assert_eq!(bgn(Bulgarian, "Щастливия ден"), "Shtastlivia den");
+    assert_eq!(bgn(Bulgarian, "Ямбол"), "Yambol");
+}
+
+#[test]
+fn test_bgn_pcgn_serbian() {
+    use unidecode::CyrillicLanguage::Serbian;
+    assert_eq!(bgn(Serbian, "Београд"), "Beograd");
+    assert_eq!(bgn(Serbian, "Ђорђевић"), "Djordjevic");
+    assert_eq!(bgn(Serbian, "Љубљана"), "Ljubljana");
+    assert_eq!(bgn(Serbian, "Џаковица"), "Dzakovica");
+}
+
+#[test]
+fn test_default() {
+    // ICAO for Russian, whose output is ASCII
+    let t = CyrillicTransliterator::new();
+    assert_eq!(t.transliterate("Ельцин"), "Eltsin");
+    assert_eq!(t.transliterate("Москва"), "Moskva");
+    assert_eq!(t.transliterate("Подъезд"), "Podieezd");
+}
+
+#[test]
+fn test_icao() {
+    use unidecode::CyrillicLanguage::*;
+    let icao = |language, s| t(CyrillicScheme::Icao, language, s);
+    assert_eq!(icao(Russian, "Щукин"), "Shchukin");
+    assert_eq!(icao(Russian, "Юлия"), "Iuliia");
+    assert_eq!(icao(Russian, "Объект"), "Obieekt");
+    assert_eq!(icao(Russian, "Ильич"), "Ilich");
+    assert_eq!(icao(Russian, "Ёлка"), "Elka");
+    assert_eq!(icao(Ukrainian, "Григорій"), "Hryhorii");
+    assert_eq!(icao(Ukrainian, "Знам’янка"), "Znamianka");
+    assert_eq!(icao(Belarusian, "Гродна"), "Hrodna");
+    assert_eq!(icao(Serbian, "Ђорђе"), "Dorde");
+}
+
+#[test]
+fn test_capitals() {
+    use unidecode::CyrillicLanguage::Russian;
+    assert_eq!(bgn(Russian, "ЩУКИН"), "SHCHUKIN");
+    assert_eq!(bgn(Russian, "ЕЛЬЦИН"), "YEL'TSIN");
+    assert_eq!(bgn(Russian, "Щи"), "Shchi");
+    assert_eq!(bgn(Russian, "ЦСКА"), "TSSKA");
+    assert_eq!(bgn(Russian, "Я"), "Ya");
+}
+
+#[test]
+fn test_other_characters() {
+    use unidecode::CyrillicLanguage::Russian;
+    assert_eq!(bgn(Russian, "Москва 2024!"), "Moskva 2024!");
+    assert_eq!(bgn(Russian, "Æneid"), "AEneid");
+}
+
+#[test]
+fn test_ascii() {
+    use unidecode::CyrillicLanguage::*;
+    use unidecode::CyrillicScheme::*;
+    let text = (0x400..0x480)
+        .filter_map(char::from_u32)
+        .chain("Ельцин Знам’янка".chars())
+        .collect::<String>();
+    for &scheme in &[Gost779B, BgnPcgn, Icao] {
+        for &language in &[Russian, Ukrainian, Belarusian, Bulgarian, Serbian] {
+            let romanized = t(scheme, language, &text);
+            assert!(romanized.is_ascii(), "{:?}: {}", scheme, romanized);
+        }
+    }
+}
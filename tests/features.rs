@@ -0,0 +1,88 @@
+// Tests the features that leave scripts out of the table. Run with, for
+// example, `cargo test --no-default-features --features std,hangul` to test a
+// build without the other scripts.
+
+extern crate unidecode;
+use unidecode::unidecode_char;
+
+// The features of the scripts, with a character of each.
+const SCRIPTS: [(&str, char, bool); 5] = [
+    ("cjk", '北', cfg!(feature = "cjk")),
+    ("hangul", '한', cfg!(feature = "hangul")),
+    ("indic", 'अ', cfg!(feature = "indic")),
+    ("yi", 'ꀀ', cfg!(feature = "yi")),
+    ("syllabics", 'ᔕ', cfg!(feature = "syllabics")),
+];
+
+#[test]
+fn test_script_features() {
+    for &(name, ch, enabled) in SCRIPTS.iter() {
+        assert_eq!(!unidecode_char(ch).is_empty(), enabled, "{}", name);
+    }
+    // Neighbouring scripts are not affected
+    assert_eq!(unidecode_char('げ'), "ge");
+    assert_eq!(unidecode_char('Æ'), "AE");
+}
+
+#[cfg(all(feature = "alloc", not(feature = "cjk")))]
+#[test]
+fn test_excluded_script_unknown() {
+    use unidecode::{try_unidecode, Transliterator, Unknown};
+
+    let t = Transliterator::new(Unknown::Replace("?".to_string()));
+    assert_eq!(t.transliterate("Bei 北").unwrap(), "Bei ?");
+    let err = try_unidecode("Bei 北").unwrap_err();
+    assert_eq!(err.block(), "CJK Unified Ideographs");
+}
+
+// Reports the number of bytes of data that each feature adds, as computed from
+// the generated table. Run with `--nocapture` to see the report.
+#[test]
+fn test_feature_sizes() {
+    let source = include_str!("../src/data.rs");
+    let mut total = 0;
+    for &(name, _, _) in SCRIPTS.iter() {
+        let prefix = name.to_uppercase();
+        let data = string_len(source, &format!("{}_DATA", prefix));
+        let pointers = array_len(source, &format!("{}_POINTERS", prefix)) * 4;
+        assert!(data > 0 && pointers > 0, "{}", name);
+        println!("{:>10}: {:>7} bytes", name, data + pointers);
+        total += data + pointers;
+    }
+    let core = string_len(source, "DATA") + array_len(source, "POINTERS") * 4;
+    println!("{:>10}: {:>7} bytes", "(always)", core);
+    println!("{:>10}: {:>7} bytes", "(total)", core + total);
+}
+
+// The length of the string in `pub static NAME: &str = concat!(...);`.
+fn string_len(source: &str, name: &str) -> usize {
+    let start = source.find(&format!("pub static {}: &str", name)).unwrap();
+    let body = &source[start..];
+    let body = &body[..body.find(");").unwrap()];
+    let mut len = 0;
+    for line in body.lines().skip(1) {
+        let piece = line.trim().trim_end_matches(',');
+        let piece = &piece[1..piece.len() - 1];
+        let mut chars = piece.chars();
+        while let Some(ch) = chars.next() {
+            if ch == '\\' && chars.next() == Some('u') {
+                // An escape such as `\u{a}`
+                for ch in chars.by_ref() {
+                    if ch == '}' {
+                        break;
+                    }
+                }
+            }
+            len += 1;
+        }
+    }
+    len
+}
+
+// The length of the array in `pub static NAME: [u32; N] = [...];`.
+fn array_len(source: &str, name: &str) -> usize {
+    let decl = format!("pub static {}: [u32; ", name);
+    let start = source.find(&decl).unwrap() + decl.len();
+    let len = &source[start..];
+    len[..len.find(']').unwrap()].parse().unwrap()
+}
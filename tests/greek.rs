@@ -0,0 +1,102 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::{GreekScheme, GreekTransliterator};
+
+fn elot(s: &str) -> String {
+    GreekTransliterator::new().transliterate(s)
+}
+
+fn beta(s: &str) -> String {
+    GreekTransliterator::new()
+        .scheme(GreekScheme::BetaCode)
+        .transliterate(s)
+}
+
+#[test]
+fn test_elot_743() {
+    assert_eq!(elot("Αθήνα"), "Athina");
+    assert_eq!(elot("Θεσσαλονίκη"), "Thessaloniki");
+    assert_eq!(elot("Χανιά"), "Chania");
+    assert_eq!(elot("ψυχή"), "psychi");
+    assert_eq!(elot("οικονομία"), "oikonomia");
+    assert_eq!(elot("ουρανός"), "ouranos");
+}
+
+#[test]
+fn test_upsilon_digraphs() {
+    assert_eq!(elot("αυτός"), "aftos");
+    assert_eq!(elot("Ευρώπη"), "Evropi");
+    assert_eq!(elot("αύριο"), "avrio");
+    assert_eq!(elot("ευχαριστώ"), "efcharisto");
+    assert_eq!(elot("ηύρα"), "ivra");
+    assert_eq!(elot("ηυξημένος"), "ifximenos");
+    // Voiceless at the end of a word
+    assert_eq!(elot("Ζεύς"), "Zefs");
+    assert_eq!(elot("εὖ"), "ef");
+    // An accent on the first vowel separates the letters
+    assert_eq!(elot("άυλος"), "aylos");
+}
+
+#[test]
+fn test_consonant_digraphs() {
+    assert_eq!(elot("Μπαμπάς"), "Bampas");
+    assert_eq!(elot("λάμπ"), "lab");
+    assert_eq!(elot("Ευαγγέλιο"), "Evangelio");
+    assert_eq!(elot("Σφίγξ"), "Sfinx");
+    assert_eq!(elot("μελαγχολία"), "melancholia");
+    assert_eq!(elot("άγκυρα"), "agkyra");
+    assert_eq!(elot("ντομάτα"), "ntomata");
+}
+
+#[test]
+fn test_diaeresis() {
+    assert_eq!(elot("Ταΰγετος"), "Taygetos");
+    assert_eq!(elot("προϋπόθεση"), "proypothesi");
+    assert_eq!(elot("Λαϊκός"), "Laikos");
+}
+
+#[test]
+fn test_capitals() {
+    assert_eq!(elot("ΑΘΗΝΑ"), "ATHINA");
+    assert_eq!(elot("ΜΠΑΜΠΑΣ"), "BAMPAS");
+    assert_eq!(elot("ΕΥΡΩΠΗ"), "EVROPI");
+    assert_eq!(elot("ΧΑΝΙΑ και Θήβα"), "CHANIA kai Thiva");
+    // Digraphs on their own
+    assert_eq!(elot("ΟΥ"), "OU");
+    assert_eq!(elot("ΕΥ ΖΗΝ"), "EF ZIN");
+    assert_eq!(elot("ΟΙ ΑΥ"), "OI AF");
+    assert_eq!(elot("Ου"), "Ou");
+}
+
+#[test]
+fn test_polytonic() {
+    assert_eq!(elot("Ἀθῆναι"), "Athinai");
+    assert_eq!(elot("εὐαγγέλιον"), "evangelion");
+    assert_eq!(elot("Ὅμηρος"), "Homiros");
+    assert_eq!(elot("οὗτος"), "houtos");
+    assert_eq!(elot("Ῥόδος"), "Rhodos");
+    assert_eq!(elot("τῷ θεῷ"), "to theo");
+    // Combining diacritics
+    assert_eq!(elot("ο\u{314}\u{301}δος"), "hodos");
+}
+
+#[test]
+fn test_beta_code() {
+    assert_eq!(beta("μῆνιν ἄειδε θεὰ"), "mh=nin a)/eide qea\\");
+    assert_eq!(beta("Ἀθῆναι"), "*)aqh=nai");
+    assert_eq!(beta("εὐαγγέλιον"), "eu)agge/lion");
+    assert_eq!(beta("τῷ Ὁμήρῳ"), "tw=| *(omh/rw|");
+    assert_eq!(beta("ᾍδης"), "*(/a|dhs");
+    assert_eq!(beta("ῥήτωρ"), "r(h/twr");
+    assert_eq!(beta("Ψυχή"), "*yuxh/");
+    assert_eq!(beta("ΐ"), "i+/");
+    assert_eq!(beta("ο\u{314}\u{301}δος"), "o(/dos");
+}
+
+#[test]
+fn test_other_characters() {
+    assert_eq!(elot("Αθήνα 2004!"), "Athina 2004!");
+    assert_eq!(beta("Αθήνα, 2004"), "*aqh/na, 2004");
+    assert_eq!(elot("Ω café"), "O cafe");
+}
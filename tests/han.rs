@@ -0,0 +1,72 @@
+#![cfg(feature = "cjk")]
+
+extern crate unidecode;
+use unidecode::{unidecode_char, HanReading};
+
+#[test]
+fn test_han_reading_unidecode_char() {
+    assert_eq!(HanReading::Mandarin.unidecode_char('北'), "Bei ");
+    assert_eq!(HanReading::Cantonese.unidecode_char('北'), "Bak ");
+    assert_eq!(HanReading::Vietnamese.unidecode_char('北'), "Bac ");
+    assert_eq!(HanReading::Korean.unidecode_char('北'), "Buk ");
+}
+
+#[test]
+fn test_cantonese() {
+    assert_eq!(HanReading::Cantonese.unidecode_char('香'), "Hoeng ");
+    assert_eq!(HanReading::Cantonese.unidecode_char('港'), "Gong ");
+    assert_eq!(HanReading::Cantonese.unidecode_char('五'), "Ng ");
+    assert_eq!(HanReading::Cantonese.unidecode_char('陳'), "Can ");
+}
+
+#[test]
+fn test_vietnamese() {
+    assert_eq!(HanReading::Vietnamese.unidecode_char('越'), "Viet ");
+    assert_eq!(HanReading::Vietnamese.unidecode_char('阮'), "Nguyen ");
+    assert_eq!(HanReading::Vietnamese.unidecode_char('道'), "Dao ");
+    assert_eq!(HanReading::Vietnamese.unidecode_char('水'), "Thuy ");
+}
+
+#[test]
+fn test_korean() {
+    assert_eq!(HanReading::Korean.unidecode_char('韓'), "Han ");
+    assert_eq!(HanReading::Korean.unidecode_char('金'), "Geum ");
+    assert_eq!(HanReading::Korean.unidecode_char('黃'), "Hwang ");
+    assert_eq!(HanReading::Korean.unidecode_char('六'), "Ryuk ");
+    // Characters read from the Korean collation of CLDR
+    assert_eq!(HanReading::Korean.unidecode_char('鬱'), "Ul ");
+    assert_eq!(HanReading::Korean.unidecode_char('蝶'), "Jeop ");
+    assert_eq!(HanReading::Korean.reading('澹'), Some("Dam "));
+}
+
+#[test]
+fn test_fallback() {
+    // Characters without a reading in the language, and other characters
+    let vietnamese = HanReading::Vietnamese;
+    assert_eq!(vietnamese.unidecode_char('你'), unidecode_char('你'));
+    assert_eq!(HanReading::Korean.unidecode_char('龘'), unidecode_char('龘'));
+    assert_eq!(HanReading::Cantonese.unidecode_char('Æ'), "AE");
+    assert_eq!(HanReading::Korean.unidecode_char('ß'), "ss");
+}
+
+#[test]
+fn test_reading() {
+    assert_eq!(HanReading::Cantonese.reading('港'), Some("Gong "));
+    assert_eq!(HanReading::Mandarin.reading('北'), Some("Bei "));
+    // Characters without a reading in the language, and other characters
+    assert_eq!(HanReading::Vietnamese.reading('你'), None);
+    assert_eq!(HanReading::Korean.reading('龘'), None);
+    assert_eq!(HanReading::Cantonese.reading('Æ'), None);
+    assert_eq!(HanReading::Mandarin.reading('a'), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_han_reading_unidecode() {
+    assert_eq!(HanReading::Cantonese.unidecode("香港"), "Hoeng Gong ");
+    assert_eq!(HanReading::Vietnamese.unidecode("越南"), "Viet Nam ");
+    let korean = HanReading::Korean;
+    assert_eq!(korean.unidecode("大韓民國"), "Dae Han Min Guk ");
+    let mandarin = HanReading::Mandarin;
+    assert_eq!(mandarin.unidecode("北京"), unidecode::unidecode("北京"));
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::{
+    unidecode_indic, IndicScheme, IndicScript, IndicTransliterator,
+};
+
+#[test]
+fn test_inherent_vowel() {
+    assert_eq!(unidecode_indic("മലയാലമ്"), "malayaalam");
+    assert_eq!(unidecode_indic("नमस्ते"), "namaste");
+    assert_eq!(unidecode_indic("தமிழ்"), "tamizh");
+    assert_eq!(unidecode_indic("తెలుగు"), "telugu");
+    assert_eq!(unidecode_indic("ಕನ್ನಡ"), "kannada");
+    assert_eq!(unidecode_indic("ଓଡ଼ିଆ"), "oriaa");
+    assert_eq!(unidecode_indic("ગુજરાતી"), "gujaraatii");
+}
+
+#[test]
+fn test_schwa_deletion() {
+    assert_eq!(unidecode_indic("कमल"), "kamal");
+    assert_eq!(unidecode_indic("अभिजीत"), "abhijiit");
+    assert_eq!(unidecode_indic("বাংলা"), "baanglaa");
+    assert_eq!(unidecode_indic("ਪੰਜਾਬ"), "panjaab");
+    // Kept after clusters and in words of a single syllable
+    assert_eq!(unidecode_indic("मित्र"), "mitra");
+    assert_eq!(unidecode_indic("न"), "na");
+    // Dravidian scripts keep the inherent vowel
+    assert_eq!(unidecode_indic("ಕಮಲ"), "kamala");
+
+    let t = IndicTransliterator::new().schwa_deletion(false);
+    assert_eq!(t.transliterate("कमल नयन"), "kamala nayana");
+}
+
+#[test]
+fn test_signs() {
+    // Nukta
+    assert_eq!(unidecode_indic("क़िला"), "qilaa");
+    assert_eq!(unidecode_indic("ज़रा"), "zaraa");
+    assert_eq!(unidecode_indic("ਸ\u{A3C}ਹਿਰ"), "shahir");
+    assert_eq!(unidecode_indic("\u{A36}ਹਿਰ"), "shahir");
+    // Anusvara before labials, other consonants and at the end of words
+    assert_eq!(unidecode_indic("हिंदी"), "hindii");
+    assert_eq!(unidecode_indic("संबंध"), "sambandh");
+    assert_eq!(unidecode_indic("एवं"), "evam");
+    // Candrabindu and visarga
+    assert_eq!(unidecode_indic("माँ"), "maan");
+    assert_eq!(unidecode_indic("दुःख"), "duhkh");
+    // Candra a
+    assert_eq!(unidecode_indic("ॲक्शन"), "akshan");
+    // Gurmukhi addak
+    assert_eq!(unidecode_indic("ਪੱਕਾ"), "pakkaa");
+    // Malayalam chillus
+    assert_eq!(unidecode_indic("അവൻ"), "avan");
+}
+
+#[test]
+fn test_mixed_text() {
+    assert_eq!(unidecode_indic("भारत। India ১২৩"), "bhaarat. India 123");
+    assert_eq!(unidecode_indic("Æneid"), "AEneid");
+}
+
+fn scheme(scheme: IndicScheme) -> IndicTransliterator {
+    IndicTransliterator::new().scheme(scheme)
+}
+
+#[test]
+fn test_schemes() {
+    let hk = scheme(IndicScheme::HarvardKyoto);
+    let itrans = scheme(IndicScheme::Itrans);
+    let velthuis = scheme(IndicScheme::Velthuis);
+    assert_eq!(hk.transliterate("संस्कृतम्"), "saMskRtam");
+    assert_eq!(itrans.transliterate("संस्कृतम्"), "saMskRRitam");
+    assert_eq!(velthuis.transliterate("संस्कृतम्"), "sa.msk.rtam");
+    assert_eq!(hk.transliterate("कृष्णः"), "kRSNaH");
+    assert_eq!(itrans.transliterate("कृष्णः"), "kRRiShNaH");
+    assert_eq!(velthuis.transliterate("कृष्णः"), "k.r.s.na.h");
+    // Schwa deletion does not apply
+    assert_eq!(hk.transliterate("कमल"), "kamala");
+    // Separators between spellings that would be read as one
+    assert_eq!(hk.transliterate("अइ"), "a{}i");
+    assert_eq!(itrans.transliterate("क्ह"), "k{}ha");
+    assert_eq!(velthuis.transliterate("ड्ह"), ".d{}ha");
+}
+
+#[test]
+fn test_round_trip() {
+    let texts = [
+        (IndicScript::Devanagari, "धर्मक्षेत्रे कुरुक्षेत्रे समवेता युयुत्सवः।"),
+        (IndicScript::Devanagari, "ॐ भूर्भुवः स्वः तत्सवितुर्वरेण्यं भर्गो देवस्य धीमहि॥"),
+        (IndicScript::Devanagari, "अइ क्ह ऋॠ ऌॡ सोऽहम् ञ्च ङ्ग"),
+        (IndicScript::Devanagari, "बैंक डॅडी ऑपरेशन कॉलेज"),
+        (IndicScript::Devanagari, "ॲक्शन अँअ"),
+        (IndicScript::Bengali, "আমার সোনার বাংলা"),
+        (IndicScript::Gurmukhi, "ਪੱਕਾ ਮੱਖਣ \u{A36}ਹਿਰ ਗੁਰਮੁਖੀ"),
+        (IndicScript::Kannada, "ಕನ್ನಡ ನಾಡು ಒಳ್ಳೆಯದು"),
+        (IndicScript::Malayalam, "മലയാളം കൊച്ചി"),
+        (IndicScript::Malayalam, "കോഴിക്കോട് അറബി"),
+        (IndicScript::Tamil, "தமிழ் நாடு"),
+        (IndicScript::Tamil, "ரன் அறம் பழம் ஒன்று"),
+        (IndicScript::Telugu, "తెలుగు భాష"),
+        (IndicScript::Telugu, "ఱ కొండ ఏడు"),
+    ];
+    for &s in &[
+        IndicScheme::HarvardKyoto,
+        IndicScheme::Itrans,
+        IndicScheme::Velthuis,
+    ] {
+        let t = scheme(s);
+        for &(script, text) in &texts {
+            let romanized = t.transliterate(text);
+            assert!(romanized.is_ascii(), "{:?}: {}", s, romanized);
+            assert_eq!(t.to_script(&romanized, script), text, "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_to_script_missing_letters() {
+    let hk = scheme(IndicScheme::HarvardKyoto);
+    // Tamil has no vocalic r, and Bengali no zh
+    assert_eq!(hk.to_script("kR", IndicScript::Tamil), "க்R");
+    assert_eq!(hk.to_script("zhal", IndicScript::Bengali), "zhঅল্");
+    // Offsets holding other letters, such as the Malayalam fractions
+    let itrans = scheme(IndicScheme::Itrans);
+    assert_eq!(itrans.to_script("qa", IndicScript::Malayalam), "qഅ");
+    assert_eq!(itrans.to_script("qa", IndicScript::Devanagari), "\u{958}");
+}
+
+#[test]
+fn test_to_script_lossy() {
+    let hk = scheme(IndicScheme::HarvardKyoto);
+    let itrans = scheme(IndicScheme::Itrans);
+    // Harvard-Kyoto has no spellings for the consonants with a nukta
+    assert_eq!(hk.transliterate("क\u{93C}िला"), "qilA");
+    assert_eq!(hk.to_script("qilA", IndicScript::Devanagari), "qइला");
+    let romanized = itrans.transliterate("क\u{93C}िला");
+    let devanagari = IndicScript::Devanagari;
+    assert_eq!(itrans.to_script(&romanized, devanagari), "\u{958}िला");
+    // The khanda ta and the chillus are spelled like a consonant and virama
+    assert_eq!(hk.transliterate("সৎ"), "sat");
+    assert_eq!(hk.to_script("sat", IndicScript::Bengali), "সত্");
+    assert_eq!(hk.transliterate("അവൻ"), "avan");
+    assert_eq!(hk.to_script("avan", IndicScript::Malayalam), "അവന്");
+}
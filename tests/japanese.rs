@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::{JapaneseSystem, JapaneseTransliterator};
+
+fn hepburn(s: &str) -> String {
+    JapaneseTransliterator::new().transliterate(s)
+}
+
+#[test]
+fn test_kana() {
+    assert_eq!(hepburn("ひらがな"), "hiragana");
+    assert_eq!(hepburn("カタカナ"), "katakana");
+    assert_eq!(hepburn("ふじさん"), "fujisan");
+    assert_eq!(hepburn("つなみ"), "tsunami");
+}
+
+#[test]
+fn test_sokuon() {
+    assert_eq!(hepburn("がっこう"), "gakkou");
+    assert_eq!(hepburn("きって"), "kitte");
+    assert_eq!(hepburn("まっちゃ"), "matcha");
+    assert_eq!(hepburn("ざっし"), "zasshi");
+}
+
+#[test]
+fn test_long_vowel_mark() {
+    assert_eq!(hepburn("ラーメン"), "raamen");
+    assert_eq!(hepburn("コーヒー"), "koohii");
+    assert_eq!(hepburn("コンピューター"), "konpyuutaa");
+}
+
+#[test]
+fn test_yoon() {
+    assert_eq!(hepburn("きょうと"), "kyouto");
+    assert_eq!(hepburn("しゃしん"), "shashin");
+    assert_eq!(hepburn("ちゅうい"), "chuui");
+    assert_eq!(hepburn("じょうず"), "jouzu");
+    assert_eq!(hepburn("りょかん"), "ryokan");
+    // Small vowels of katakana loanwords
+    assert_eq!(hepburn("ファイル"), "fairu");
+    assert_eq!(hepburn("パーティー"), "paatii");
+    assert_eq!(hepburn("ウィキ"), "wiki");
+    assert_eq!(hepburn("ヴァイオリン"), "vaiorin");
+}
+
+#[test]
+fn test_moraic_nasal() {
+    assert_eq!(hepburn("しんいち"), "shin'ichi");
+    assert_eq!(hepburn("きんようび"), "kin'youbi");
+    assert_eq!(hepburn("しんぶん"), "shinbun");
+}
+
+#[test]
+fn test_systems() {
+    let kunrei = JapaneseTransliterator::new().system(JapaneseSystem::Kunrei);
+    let nihon =
+        JapaneseTransliterator::new().system(JapaneseSystem::NihonShiki);
+    let s = "しんぶん ちゃ つづき ふじ まっちゃ を";
+    assert_eq!(hepburn(s), "shinbun cha tsuzuki fuji matcha o");
+    assert_eq!(kunrei.transliterate(s), "sinbun tya tuzuki huzi mattya o");
+    assert_eq!(nihon.transliterate(s), "sinbun tya tuduki huzi mattya wo");
+    assert_eq!(hepburn("じゃ ぢゃ"), "ja ja");
+    assert_eq!(kunrei.transliterate("じゃ ぢゃ"), "zya zya");
+    assert_eq!(nihon.transliterate("じゃ ぢゃ"), "zya dya");
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_kanji_readings() {
+    use std::collections::BTreeMap;
+
+    assert_eq!(hepburn("げんまい茶"), "genmaiCha ");
+
+    let readings: &'static [(&str, &str)] =
+        &[("茶", "ちゃ"), ("東京", "とうきょう"), ("東", "ひがし")];
+    let t = JapaneseTransliterator::new().readings(readings);
+    assert_eq!(t.transliterate("げんまい茶"), "genmaicha");
+    assert_eq!(t.transliterate("東京へ"), "toukyouhe");
+    assert_eq!(t.transliterate("東口"), "higashiKou ");
+
+    let mut readings = BTreeMap::new();
+    readings.insert(String::from("抹茶"), String::from("まっちゃ"));
+    let t = JapaneseTransliterator::new().readings(readings);
+    assert_eq!(t.transliterate("抹茶アイス"), "matchaaisu");
+
+    // Transliterators with readings can be shared across threads
+    let t = std::sync::Arc::new(t);
+    let handle = {
+        let t = t.clone();
+        std::thread::spawn(move || t.transliterate("抹茶"))
+    };
+    assert_eq!(handle.join().unwrap(), "matcha");
+}
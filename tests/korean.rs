@@ -0,0 +1,65 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::{KoreanSystem, KoreanTransliterator};
+
+fn rr(s: &str) -> String {
+    KoreanTransliterator::new().transliterate(s)
+}
+
+fn mr(s: &str) -> String {
+    KoreanTransliterator::new()
+        .system(KoreanSystem::McCuneReischauer)
+        .transliterate(s)
+}
+
+#[test]
+fn test_revised_romanization() {
+    assert_eq!(rr("한국어"), "hangugeo");
+    assert_eq!(rr("서울"), "seoul");
+    assert_eq!(rr("부산"), "busan");
+    assert_eq!(rr("제주도"), "jejudo");
+    assert_eq!(rr("한글"), "hangeul");
+    assert_eq!(rr("대한민국, 만세!"), "daehanminguk, manse!");
+}
+
+#[test]
+fn test_assimilation() {
+    assert_eq!(rr("신라"), "silla");
+    assert_eq!(rr("설날"), "seollal");
+    assert_eq!(rr("종로"), "jongno");
+    assert_eq!(rr("독립문"), "dongnimmun");
+    assert_eq!(rr("백마"), "baengma");
+    assert_eq!(rr("국물"), "gungmul");
+    assert_eq!(rr("왕십리"), "wangsimni");
+}
+
+#[test]
+fn test_liaison() {
+    assert_eq!(rr("음악"), "eumak");
+    assert_eq!(rr("읽어"), "ilgeo");
+    assert_eq!(rr("좋아"), "joa");
+    assert_eq!(rr("많이"), "mani");
+    assert_eq!(rr("같이"), "gachi");
+    assert_eq!(rr("굳이"), "guji");
+}
+
+#[test]
+fn test_aspiration() {
+    assert_eq!(rr("좋고"), "joko");
+    assert_eq!(rr("놓다"), "nota");
+    assert_eq!(rr("잡혀"), "japyeo");
+    assert_eq!(rr("많다"), "manta");
+}
+
+#[test]
+fn test_mccune_reischauer() {
+    assert_eq!(mr("한글"), "hangul");
+    assert_eq!(mr("부산"), "pusan");
+    assert_eq!(mr("청주"), "ch'ongju");
+    assert_eq!(mr("신라"), "silla");
+    assert_eq!(mr("독립문"), "tongnimmun");
+    assert_eq!(mr("시장"), "sijang");
+    assert_eq!(mr("쉬다"), "shwida");
+    assert_eq!(mr("김치"), "kimch'i");
+}
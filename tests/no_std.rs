@@ -0,0 +1,65 @@
+// Exercises the API available without the `alloc` and `std` features from a
+// `#![no_std]` crate. Run with `cargo test --no-default-features`.
+#![no_std]
+
+extern crate unidecode;
+
+use core::fmt::{self, Write};
+use unidecode::{unidecode_char, unidecode_to_writer, UnidecodeExt, Unidecoded};
+
+// A fixed-size buffer implementing `fmt::Write`, standing in for the output of
+// an embedded device.
+struct Buffer {
+    data: [u8; 32],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Buffer {
+        Buffer { data: [0; 32], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.data[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.data.len() {
+            return Err(fmt::Error);
+        }
+        self.data[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_no_std_unidecode_char() {
+    assert_eq!(unidecode_char('Æ'), "AE");
+    assert_eq!(unidecode_char('𝐀'), "A");
+    #[cfg(feature = "cjk")]
+    assert_eq!(unidecode_char('北'), "Bei ");
+}
+
+#[test]
+fn test_no_std_writer() {
+    let mut buf = Buffer::new();
+    unidecode_to_writer("Æneid, étude", &mut buf).unwrap();
+    assert_eq!(buf.as_str(), "AEneid, etude");
+
+    let mut buf = Buffer::new();
+    write!(buf, "<{}>", Unidecoded("étude")).unwrap();
+    assert_eq!(buf.as_str(), "<etude>");
+}
+
+#[test]
+fn test_no_std_iter() {
+    let mut buf = Buffer::new();
+    for ch in "Æneid".chars().unidecode().chars().rev() {
+        buf.write_char(ch).unwrap();
+    }
+    assert_eq!(buf.as_str(), "dienEA");
+}
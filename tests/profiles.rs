@@ -0,0 +1,48 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::Transliterator;
+
+fn transliterate(tag: &str, s: &str) -> String {
+    Transliterator::for_language(tag).transliterate(s).unwrap()
+}
+
+#[test]
+fn test_profiles() {
+    assert_eq!(
+        transliterate("de", "Ärger über Öl, Straße"),
+        "Aerger ueber Oel, Strasse"
+    );
+    assert_eq!(transliterate("da", "Søren Kierkegård"), "Soeren Kierkegaard");
+    assert_eq!(transliterate("nb", "Bjørn"), "Bjoern");
+    assert_eq!(transliterate("sv", "Åsa Öberg"), "Aasa Oeberg");
+    assert_eq!(transliterate("ru", "Юрий Хрущёв"), "Yuriy Khrushchyov");
+    assert_eq!(transliterate("uk", "Григорій"), "Hryhorii");
+    assert_eq!(transliterate("bg", "България"), "Balgariya");
+    assert_eq!(transliterate("sr", "Ђорђе Ђoković"), "Djordje Djokovic");
+    assert_eq!(transliterate("el", "Βυζάντιο"), "Vyzantio");
+    assert_eq!(transliterate("az", "Heydər Əliyev"), "Heydar Aliyev");
+}
+
+#[test]
+fn test_profile_fallback() {
+    assert_eq!(transliterate("de-CH", "Zürich"), "Zuerich");
+    assert_eq!(transliterate("DE_at", "Zürich"), "Zuerich");
+    assert_eq!(transliterate("sr-Latn-RS", "Đorđe"), "Djordje");
+    // Languages without a profile use the data set
+    assert_eq!(transliterate("en-US", "Zürich"), "Zurich");
+    assert_eq!(transliterate("", "Zürich"), "Zurich");
+    assert_eq!(transliterate("fi", "Hämäläinen"), "Hamalainen");
+    assert_eq!(transliterate("tr", "Işık"), "Isik");
+    assert_eq!(transliterate("da", "Ærø"), "AEroe");
+}
+
+#[test]
+fn test_profile_overrides() {
+    // Later custom transliterations take precedence over the profile
+    let t = Transliterator::builder()
+        .language("de")
+        .override_char('ü', "u")
+        .build();
+    assert_eq!(t.transliterate("Müller Bär").unwrap(), "Muller Baer");
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "std")]
+
+extern crate unidecode;
+
+use std::io::{self, Read, Write};
+use unidecode::{InvalidUtf8, UnidecodeReader, UnidecodeWriter};
+
+const INPUT: &str = "Æneid, étude, げんまい, ᏔᎵᏆ, 𝐀𝐁𝐂";
+const OUTPUT: &str = "AEneid, etude, genmai, taliqua, ABC";
+
+// A reader that returns at most `chunk` bytes per call to `read()`.
+struct Chunked<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl<'a> Read for Chunked<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+fn read_chunked(data: &[u8], chunk: usize, invalid: InvalidUtf8) -> String {
+    let inner = Chunked { data, chunk };
+    let mut reader = UnidecodeReader::with_invalid_utf8(inner, invalid);
+    let mut out = String::new();
+    reader.read_to_string(&mut out).unwrap();
+    out
+}
+
+fn write_chunked(data: &[u8], chunk: usize, invalid: InvalidUtf8) -> String {
+    let mut writer = UnidecodeWriter::with_invalid_utf8(Vec::new(), invalid);
+    for piece in data.chunks(chunk) {
+        writer.write_all(piece).unwrap();
+    }
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn test_reader_chunk_sizes() {
+    for chunk in 1..9 {
+        let out = read_chunked(INPUT.as_bytes(), chunk, InvalidUtf8::Error);
+        assert_eq!(out, OUTPUT, "chunk size {}", chunk);
+    }
+    let mut out = String::new();
+    let mut reader = UnidecodeReader::new(INPUT.as_bytes());
+    reader.read_to_string(&mut out).unwrap();
+    assert_eq!(out, OUTPUT);
+}
+
+#[test]
+fn test_writer_chunk_sizes() {
+    for chunk in 1..9 {
+        let out = write_chunked(INPUT.as_bytes(), chunk, InvalidUtf8::Error);
+        assert_eq!(out, OUTPUT, "chunk size {}", chunk);
+    }
+}
+
+#[test]
+fn test_invalid_utf8() {
+    // An invalid byte, a truncated sequence followed by ASCII, and a truncated
+    // sequence at the end of the input.
+    let data = b"a\xFFb\xE2\x82c\xF0\x9F";
+    let cases = [
+        (InvalidUtf8::Replace, "a?b?c?"),
+        (InvalidUtf8::Skip, "abc"),
+        (InvalidUtf8::Escape, "a\\xFFb\\xE2\\x82c\\xF0\\x9F"),
+    ];
+    for chunk in 1..9 {
+        for &(invalid, expected) in cases.iter() {
+            assert_eq!(read_chunked(data, chunk, invalid), expected);
+            assert_eq!(write_chunked(data, chunk, invalid), expected);
+        }
+    }
+}
+
+#[test]
+fn test_invalid_utf8_error() {
+    let mut out = String::new();
+    let err = UnidecodeReader::new(&b"a\xFFb"[..])
+        .read_to_string(&mut out)
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let mut writer = UnidecodeWriter::new(Vec::new());
+    writer.write_all(b"a\xE2\x82").unwrap();
+    assert_eq!(writer.finish().unwrap_err().kind(), io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "alloc")]
+
+extern crate unidecode;
+use unidecode::{Escape, Transliterator, Unknown};
+
+// U+1900 (LIMBU VOWEL-CARRIER LETTER) is mapped to "[?]", U+10C80 (OLD
+// HUNGARIAN CAPITAL LETTER A) lies in a block without any data, and U+0301
+// (COMBINING ACUTE ACCENT) is deliberately mapped to an empty string.
+const INPUT: &str = "A\u{1900}B\u{10C80}C\u{301}";
+
+#[test]
+fn test_unknown_ignore() {
+    let t = Transliterator::new(Unknown::Ignore);
+    assert_eq!(t.transliterate(INPUT).unwrap(), "ABC");
+}
+
+#[test]
+fn test_unknown_replace() {
+    let t = Transliterator::new(Unknown::Replace("?".to_string()));
+    assert_eq!(t.transliterate(INPUT).unwrap(), "A?B?C");
+}
+
+#[test]
+fn test_unknown_keep() {
+    let t = Transliterator::new(Unknown::Keep);
+    assert_eq!(t.transliterate(INPUT).unwrap(), "A\u{1900}B\u{10C80}C");
+}
+
+#[test]
+fn test_unknown_escape() {
+    let t = Transliterator::new(Unknown::Escape(Escape::Rust));
+    assert_eq!(t.transliterate(INPUT).unwrap(), "A\\u{1900}B\\u{10C80}C");
+    let t = Transliterator::new(Unknown::Escape(Escape::CodePoint));
+    assert_eq!(t.transliterate(INPUT).unwrap(), "AU+1900BU+10C80C");
+    let t = Transliterator::new(Unknown::Escape(Escape::Html));
+    assert_eq!(t.transliterate(INPUT).unwrap(), "A&#x1900;B&#x10C80;C");
+}
+
+#[test]
+fn test_unknown_error() {
+    let t = Transliterator::new(Unknown::Error);
+    let err = t.transliterate(INPUT).unwrap_err();
+    assert_eq!(err.ch(), '\u{1900}');
+    assert_eq!(err.byte_index(), 1);
+    assert_eq!(
+        err.to_string(),
+        "cannot transliterate '\u{1900}' (U+1900, Limbu) at byte 1"
+    );
+    assert_eq!(t.transliterate("Æneid").unwrap(), "AEneid");
+}
+
+#[test]
+fn test_override_char() {
+    let t = Transliterator::builder()
+        .override_char('ä', "ae")
+        .override_char('ö', "oe")
+        .override_char('ü', "ue")
+        .override_char('ß', "ss")
+        .build();
+    assert_eq!(
+        t.transliterate("Jürgen Großmann").unwrap(),
+        "Juergen Grossmann"
+    );
+    assert_eq!(t.transliterate("Käthe Öz").unwrap(), "Kaethe Oz");
+}
+
+#[test]
+fn test_override_range() {
+    let t = Transliterator::builder()
+        .override_range('α'..='ω', "?")
+        .override_char('π', "pi")
+        .override_range('a'..='c', "x")
+        .build();
+    assert_eq!(t.transliterate("αβπωΩ").unwrap(), "??pi?O");
+    assert_eq!(t.transliterate("abcd").unwrap(), "xxxd");
+
+    // Later overrides take precedence over earlier ones
+    let t = Transliterator::builder()
+        .override_char('β', "beta")
+        .override_range('α'..='γ', "")
+        .build();
+    assert_eq!(t.transliterate("αβγδ").unwrap(), "d");
+}
+
+#[test]
+fn test_builder_options() {
+    let t = Transliterator::builder()
+        .unknown(Unknown::Escape(Escape::CodePoint))
+        .override_char('\u{1900}', "a")
+        .build();
+    assert_eq!(t.transliterate(INPUT).unwrap(), "AaBU+10C80C");
+    let t = Transliterator::builder().build();
+    assert_eq!(t.transliterate(INPUT).unwrap(), "ABC");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_shared_transliterator() {
+    use std::sync::Arc;
+    use std::thread;
+
+    let t = Transliterator::builder().override_char('ä', "ae").build();
+    let t = Arc::new(t);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let t = Arc::clone(&t);
+            thread::spawn(move || t.transliterate("Bär").unwrap())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), "Baer");
+    }
+}
+
+#[test]
+fn test_context_case() {
+    let t = Transliterator::builder().context_case(true).build();
+    assert_eq!(t.transliterate("Æneid").unwrap(), "Aeneid");
+    assert_eq!(t.transliterate("ÆNEID").unwrap(), "AENEID");
+    assert_eq!(t.transliterate("Æ").unwrap(), "Ae");
+    assert_eq!(t.transliterate("Жуков").unwrap(), "Zhukov");
+    assert_eq!(t.transliterate("ЖУКОВ").unwrap(), "ZHUKOV");
+    assert_eq!(t.transliterate("ЧАЩА Щи").unwrap(), "CHASHCHA Shchi");
+    assert_eq!(t.transliterate("æther").unwrap(), "aether");
+
+    let t = Transliterator::builder()
+        .language("de")
+        .context_case(true)
+        .build();
+    assert_eq!(t.transliterate("Ärger").unwrap(), "Aerger");
+    assert_eq!(t.transliterate("ÄRGER mit ÖL").unwrap(), "AERGER mit OEL");
+    assert_eq!(t.transliterate("GROẞ").unwrap(), "GROSS");
+
+    // Without the option, the data set decides
+    let t = Transliterator::builder().language("de").build();
+    assert_eq!(t.transliterate("ÄRGER Æneid").unwrap(), "AeRGER AEneid");
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_spacing() {
+    use unidecode::Spacing;
+
+    let t = Transliterator::builder().spacing(Spacing::Words).build();
+    assert_eq!(t.transliterate("北亰").unwrap(), "Bei Jing");
+    assert_eq!(t.transliterate("げんまい茶").unwrap(), "genmai Cha");
+    assert_eq!(t.transliterate("北亰, 茶!").unwrap(), "Bei Jing, Cha!");
+    assert_eq!(t.transliterate("茶 (北)").unwrap(), "Cha (Bei)");
+    assert_eq!(t.transliterate("A北 1茶").unwrap(), "A Bei 1 Cha");
+    assert_eq!(t.transliterate("茶げ").unwrap(), "Cha ge");
+    assert_eq!(t.transliterate("Æneid").unwrap(), "AEneid");
+
+    let t = Transliterator::builder().spacing(Spacing::Joined).build();
+    assert_eq!(t.transliterate("北亰").unwrap(), "BeiJing");
+    assert_eq!(t.transliterate("げんまい茶").unwrap(), "genmaiCha");
+    assert_eq!(t.transliterate("北亰 茶").unwrap(), "BeiJing Cha");
+
+    let t = Transliterator::builder().spacing(Spacing::Table).build();
+    assert_eq!(t.transliterate("北亰").unwrap(), "Bei Jing ");
+}
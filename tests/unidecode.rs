@@ -1,8 +1,13 @@
+#![cfg(feature = "alloc")]
+
 extern crate unidecode;
-use unidecode::{unidecode, unidecode_char};
+use unidecode::{try_unidecode, try_unidecode_all};
+use unidecode::{unidecode, unidecode_char, unidecode_emoji};
+use unidecode::{unidecode_cow, unidecode_into, unidecode_to_writer};
 
 // Tests that every character outputted by the unidecode() function is valid
-// ASCII.
+// ASCII, and that blocks outside the Basic Multilingual Plane are
+// transliterated.
 #[test]
 fn test_all_ascii() {
     use std::char;
@@ -24,6 +29,48 @@ fn test_all_ascii() {
             None => panic!("Test written incorrectly; invalid Unicode")
         }
     }
+
+    let astral_ranges = [
+        (0x1D400, 0x1D454), // Mathematical Alphanumeric Symbols (bold, italic)
+        (0x1D7CE, 0x1D7FF), // Mathematical digits
+        (0x1F110, 0x1F129), // Parenthesized Latin capital letters
+        (0x10000, 0x1000B), // Linear B syllables
+        (0x10300, 0x1031F), // Old Italic
+        (0x10330, 0x1034A), // Gothic
+        (0x10380, 0x1039D), // Ugaritic
+        (0x103A0, 0x103C3), // Old Persian
+        (0x10400, 0x1044F), // Deseret
+        (0x10900, 0x10915), // Phoenician
+    ];
+    for &(start, end) in astral_ranges.iter() {
+        for i in start..end + 1 {
+            let ch = char::from_u32(i).unwrap();
+            assert!(!unidecode_char(ch).is_empty(), "U+{:X} is not mapped", i);
+        }
+    }
+}
+
+#[test]
+fn test_historic_scripts() {
+    assert_eq!(unidecode("𐌲𐌿𐌸"), "guth");
+    assert_eq!(unidecode("𐍅𐌰𐍄𐍉"), "wato");
+    assert_eq!(unidecode("𐐔𐐯𐑅𐐨𐑉𐐯𐐻"), "Deseeret");
+    assert_eq!(unidecode("𐌀𐌓𐌕"), "art");
+    assert_eq!(unidecode("𐎭𐎠𐎼𐎹𐎺𐎢𐏁"), "daarayavausha");
+    assert_eq!(unidecode("𐤌𐤋𐤊"), "mlk");
+    assert_eq!(unidecode("\u{10012}\u{1001C}\u{10030}"), "konoso");
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_cjk_extensions() {
+    // Ideographs of Extensions B and G with a reading in the Unihan subset
+    assert_eq!(unidecode("𠮷"), "Ji ");
+    assert_eq!(unidecode("𠀀"), "He ");
+    assert_eq!(unidecode("𪚥"), "Zhe ");
+    assert_eq!(unidecode("𰻞𰻞面"), "Biang Biang Mian ");
+    // Ideographs of Extension B with a reading in CLDR only
+    assert_eq!(unidecode("𠳳𥥩"), "Ai A ");
 }
 
 // These tests were ported directly from the original `Text::Unidecode` Perl
@@ -32,21 +79,198 @@ fn test_all_ascii() {
 fn test_conversion() {
     assert_eq!(unidecode("Æneid"), "AEneid");
     assert_eq!(unidecode("étude"), "etude");
+    #[cfg(feature = "cjk")]
     assert_eq!(unidecode("北亰"), "Bei Jing ");
+    #[cfg(feature = "syllabics")]
     assert_eq!(unidecode("ᔕᓇᓇ"), "shanana");
     assert_eq!(unidecode("ᏔᎵᏆ"), "taliqua");
     assert_eq!(unidecode("ܦܛܽܐܺ"), "ptu'i");
+    #[cfg(feature = "indic")]
     assert_eq!(unidecode("अभिजीत"), "abhijiit");
+    #[cfg(feature = "indic")]
     assert_eq!(unidecode("অভিজীত"), "abhijiit");
+    #[cfg(feature = "indic")]
     assert_eq!(unidecode("അഭിജീത"), "abhijiit");
+    #[cfg(feature = "indic")]
     assert_eq!(unidecode("മലയാലമ്"), "mlyaalm");
+    #[cfg(feature = "cjk")]
     assert_eq!(unidecode("げんまい茶"), "genmaiCha ");
 }
 
 #[test]
 fn test_unidecode_char() {
     assert_eq!(unidecode_char('Æ'), "AE");
+    #[cfg(feature = "cjk")]
     assert_eq!(unidecode_char('北'), "Bei ");
+    #[cfg(feature = "cjk")]
     assert_eq!(unidecode_char('亰'), "Jing ");
+    #[cfg(feature = "syllabics")]
     assert_eq!(unidecode_char('ᔕ'), "sha");
+    assert_eq!(unidecode_char('\u{FFFF}'), "");
+    assert_eq!(unidecode_char('\u{10FFFF}'), "[?]");
+    assert_eq!(unidecode_char('\u{E0100}'), "");
+}
+
+#[test]
+fn test_astral() {
+    assert_eq!(unidecode("𝐀𝐁𝐂"), "ABC");
+    assert_eq!(unidecode("𝓯𝓪𝓷𝓬𝔂"), "fancy");
+    assert_eq!(unidecode("𝟙𝟚𝟛"), "123");
+    assert_eq!(unidecode("🄐"), "(A)");
+    #[cfg(feature = "cjk")]
+    assert_eq!(unidecode_char('丽'), "Li ");
+    // Decompositions added in Unicode 16.0
+    assert_eq!(unidecode("\u{1CCD6}\u{1CCF1}"), "A1");
+}
+
+#[test]
+fn test_unidecode_emoji() {
+    assert_eq!(
+        unidecode_emoji("I \u{2764}\u{FE0F} \u{1F600}"),
+        "I :red_heart: :grinning_face:"
+    );
+    assert_eq!(unidecode_emoji("I \u{2764}\u{FE0F}!"), "I :red_heart:!");
+    assert_eq!(unidecode_emoji("🇩🇪🇫🇷"), ":flag_de::flag_fr:");
+    assert_eq!(
+        unidecode_emoji("🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}"),
+        ":flag_gb_eng:"
+    );
+    assert_eq!(unidecode_emoji("👋🏽"), ":waving_hand_medium_skin_tone:");
+    assert_eq!(
+        unidecode_emoji("👨\u{200D}👩\u{200D}👧"),
+        ":family_man_woman_girl:"
+    );
+    assert_eq!(unidecode_emoji("1\u{FE0F}\u{20E3} #1"), ":keycap_1: #1");
+    assert_eq!(unidecode_emoji("Æneid"), "AEneid");
+}
+
+#[test]
+fn test_unidecode_emoji_text_presentation() {
+    // Displayed as text unless followed by a variation selector
+    assert_eq!(unidecode_emoji("© 2024 Foo™"), "(c) 2024 Footm");
+    assert_eq!(unidecode_emoji("\u{A9}\u{FE0F}"), ":copyright:");
+    assert_eq!(unidecode_emoji("\u{2764}"), unidecode("\u{2764}"));
+    assert_eq!(unidecode_emoji("1\u{20E3}"), "1");
+    // A skin tone modifier makes an emoji without a variation selector
+    assert_eq!(
+        unidecode_emoji("\u{261D}\u{1F3FD}"),
+        ":index_pointing_up_medium_skin_tone:"
+    );
+    assert_eq!(
+        unidecode_emoji("\u{2764}\u{FE0F}\u{200D}\u{1F525}"),
+        ":heart_on_fire:"
+    );
+}
+
+#[test]
+fn test_try_unidecode() {
+    assert_eq!(try_unidecode("Æneid").unwrap(), "AEneid");
+    assert_eq!(try_unidecode("e\u{301}tude").unwrap(), "etude");
+
+    let err = try_unidecode("Æ\u{1900}\u{10C80}").unwrap_err();
+    assert_eq!(err.ch(), '\u{1900}');
+    assert_eq!(err.byte_index(), 2);
+    assert_eq!(err.char_index(), 1);
+    assert_eq!(err.block(), "Limbu");
+}
+
+#[test]
+fn test_try_unidecode_all() {
+    #[cfg(feature = "cjk")]
+    assert_eq!(try_unidecode_all("北亰").unwrap(), "Bei Jing ");
+
+    let errors = try_unidecode_all("Æ\u{1900}x\u{10C80}").unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].ch(), '\u{1900}');
+    assert_eq!(errors[1].ch(), '\u{10C80}');
+    assert_eq!(errors[1].byte_index(), 6);
+    assert_eq!(errors[1].char_index(), 3);
+    assert_eq!(errors[1].block(), "Old Hungarian");
+    let errors = try_unidecode_all("\u{E01F0}").unwrap_err();
+    assert_eq!(errors[0].block(), "No_Block");
+    // Blocks added in Unicode 15.0 to 17.0
+    let errors = try_unidecode_all("\u{105C0}\u{1E4D0}").unwrap_err();
+    assert_eq!(errors[0].block(), "Todhri");
+    assert_eq!(errors[1].block(), "Nag Mundari");
+}
+
+#[test]
+fn test_try_unidecode_astral() {
+    // Unmapped characters of blocks that are partly covered, such as the
+    // reserved U+1D455 of the Mathematical Alphanumeric Symbols
+    assert_eq!(try_unidecode("\u{1D454}").unwrap(), "g");
+    let errors = try_unidecode_all("\u{1D455}\u{10350}").unwrap_err();
+    assert_eq!(errors[0].block(), "Mathematical Alphanumeric Symbols");
+    assert_eq!(errors[1].block(), "Old Permic");
+    // Ideographs without a reading
+    #[cfg(feature = "cjk")]
+    assert_eq!(try_unidecode("\u{20000}").unwrap(), "He ");
+    let err = try_unidecode("\u{2A6D6}").unwrap_err();
+    assert_eq!(err.block(), "CJK Unified Ideographs Extension B");
+    // Variation selectors and tags are deliberately mapped to nothing
+    assert_eq!(try_unidecode("A\u{E0100}\u{E0041}").unwrap(), "A");
+}
+
+#[test]
+fn test_unidecode_into() {
+    let mut out = String::from("name: ");
+    unidecode_into("Æneid", &mut out);
+    assert_eq!(out, "name: AEneid");
+    unidecode_into(", étude", &mut out);
+    assert_eq!(out, "name: AEneid, etude");
+}
+
+#[test]
+fn test_unidecode_to_writer() {
+    let mut out = String::new();
+    unidecode_to_writer("étude ᏔᎵᏆ", &mut out).unwrap();
+    assert_eq!(out, "etude taliqua");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_unidecode_to_io() {
+    use unidecode::unidecode_to_io;
+
+    let mut out = Vec::new();
+    unidecode_to_io("étude ᏔᎵᏆ", &mut out).unwrap();
+    assert_eq!(out, b"etude taliqua");
+}
+
+#[test]
+fn test_unidecode_cow() {
+    use std::borrow::Cow;
+
+    match unidecode_cow("Aeneid") {
+        Cow::Borrowed(s) => assert_eq!(s, "Aeneid"),
+        Cow::Owned(_) => panic!("ASCII input was copied"),
+    }
+    assert_eq!(unidecode_cow("Æneid"), "AEneid");
+}
+
+// Tests every transliteration against a digest of the whole data set, so that
+// changes to the encoding of the table cannot silently change the output. The
+// digest must be updated with every change to the data set.
+#[cfg(all(
+    feature = "cjk",
+    feature = "hangul",
+    feature = "indic",
+    feature = "yi",
+    feature = "syllabics"
+))]
+#[test]
+fn test_table_digest() {
+    use std::char;
+
+    // FNV-1a over every transliteration, each followed by a 0xFF separator
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for i in 0..0x10FFFF + 1 {
+        if let Some(ch) = char::from_u32(i) {
+            for &b in unidecode_char(ch).as_bytes().iter().chain(&[0xFF]) {
+                hash ^= b as u64;
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        }
+    }
+    assert_eq!(hash, 0xec7c_0e93_3f6b_ef34);
 }
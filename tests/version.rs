@@ -0,0 +1,40 @@
+#![cfg(feature = "table-1-30")]
+
+extern crate unidecode;
+use unidecode::{unidecode_char, TableVersion};
+
+// U+1CCD6 (OUTLINED LATIN CAPITAL LETTER A) was added in Unicode 16.0, so it is
+// only transliterated by the tables using its decomposition. The 1.30 table
+// only covers the Basic Multilingual Plane, like rust-unidecode 0.3.0.
+#[test]
+fn test_pinned_unidecode_char() {
+    assert_eq!(TableVersion::V1_30.unidecode_char('\u{1CCD6}'), "");
+    assert_eq!(TableVersion::V1_30.unidecode_char('\u{1D400}'), "");
+    assert_eq!(TableVersion::V1_30.unidecode_char('\u{FC00}'), "");
+    assert_eq!(TableVersion::LATEST.unidecode_char('\u{1CCD6}'), "A");
+    assert_eq!(unidecode_char('\u{1CCD6}'), "A");
+    assert_eq!(TableVersion::V1_30.unidecode_char('Æ'), "AE");
+    assert_eq!(TableVersion::default(), TableVersion::LATEST);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_pinned_unidecode() {
+    let s = "Æneid \u{1CCD6}\u{1CCF1}";
+    assert_eq!(TableVersion::V1_30.unidecode(s), "AEneid ");
+    assert_eq!(TableVersion::V1_30.unidecode("𝐀𝐁𝐂"), "");
+    assert_eq!(TableVersion::LATEST.unidecode(s), "AEneid A1");
+    assert_eq!(TableVersion::LATEST.unidecode(s), unidecode::unidecode(s));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_pinned_transliterator() {
+    use unidecode::{Transliterator, Unknown};
+
+    let unknown = Unknown::Replace("?".to_string());
+    let t = Transliterator::with_table(unknown.clone(), TableVersion::V1_30);
+    assert_eq!(t.transliterate("A\u{1CCD6}").unwrap(), "A?");
+    let t = Transliterator::new(unknown);
+    assert_eq!(t.transliterate("A\u{1CCD6}").unwrap(), "AA");
+}
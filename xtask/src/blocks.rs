@@ -0,0 +1,45 @@
+//! Renders the Unicode blocks of `Blocks.txt`, used to name the block of a
+//! character in error messages.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Reads `Blocks.txt`, returning the first and last code point and the name of
+/// every block, sorted by code point.
+pub fn read(path: &Path) -> Result<Vec<(u32, u32, String)>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut blocks = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = || format!("{}:{}: invalid line", path.display(), i + 1);
+        let (range, name) = line.split_once(';').ok_or_else(invalid)?;
+        let (first, last) = range.trim().split_once("..").ok_or_else(invalid)?;
+        let first = u32::from_str_radix(first, 16).map_err(|_| invalid())?;
+        let last = u32::from_str_radix(last, 16).map_err(|_| invalid())?;
+        blocks.push((first, last, name.trim().to_string()));
+    }
+    blocks.sort();
+    if blocks.windows(2).any(|w| w[0].1 >= w[1].0) {
+        return Err(format!("{}: overlapping blocks", path.display()));
+    }
+    Ok(blocks)
+}
+
+/// Renders the blocks.
+pub fn render(blocks: &[(u32, u32, String)]) -> String {
+    let mut out = String::new();
+    out.push_str("// File autogenerated with `cargo xtask generate`\n\n");
+    writeln!(out, "pub static BLOCKS: [(u32, u32, &str); {}] = [", blocks.len())
+        .unwrap();
+    for (first, last, name) in blocks {
+        writeln!(out, "    (0x{:X}, 0x{:X}, \"{}\"),", first, last, name)
+            .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
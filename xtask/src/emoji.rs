@@ -0,0 +1,130 @@
+//! Renders the emoji sequences of `emoji-test.txt` and their short names.
+//!
+//! The short names are derived from the CLDR names listed in the file, so
+//! "grinning face" becomes "grinning_face". Flags are named after their region
+//! codes, so the flag of Germany becomes "flag_de".
+//!
+//! Only the fully-qualified sequences are kept, with the variation selectors
+//! (U+FE0F) stripped, except the one following the first character of the
+//! sequence. That one marks a character displayed as text by default, which is
+//! only an emoji when the selector follows it in the input.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Reads `emoji-test.txt`, returning the sequences and their short names,
+/// sorted by sequence. `latest` is the transliteration of every code point,
+/// used to spell the names in ASCII.
+pub fn read(
+    path: &Path,
+    latest: &[String],
+) -> Result<Vec<(String, String)>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut names = BTreeMap::new();
+    for (i, line) in source.lines().enumerate() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let invalid = || format!("{}:{}: invalid line", path.display(), i + 1);
+        let (code_points, rest) = line.split_once(';').ok_or_else(invalid)?;
+        let (status, comment) = rest.split_once('#').ok_or_else(invalid)?;
+        if !matches!(status.trim(), "fully-qualified" | "component") {
+            continue;
+        }
+        let chars = code_points
+            .split_whitespace()
+            .map(|s| u32::from_str_radix(s, 16).ok().and_then(char::from_u32))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid)?;
+        // The emoji itself and its version precede the name
+        let name = comment
+            .split_whitespace()
+            .skip(1)
+            .skip_while(|s| is_version(s))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let sequence = chars
+            .iter()
+            .enumerate()
+            .filter(|&(i, &c)| c != '\u{FE0F}' || i == 1)
+            .map(|(_, &c)| c)
+            .collect::<String>();
+        let name = short_name(&sequence, &name, latest);
+        names.entry(sequence).or_insert(name);
+    }
+    Ok(names.into_iter().collect())
+}
+
+/// Returns whether a word of a comment is an emoji version, such as `"E1.0"`.
+fn is_version(s: &str) -> bool {
+    s.strip_prefix('E').is_some_and(|v| {
+        v.split('.').count() == 2
+            && v.split('.').all(|n| n.parse::<u32>().is_ok())
+    })
+}
+
+/// Returns the short name of an emoji sequence, given its CLDR name.
+fn short_name(sequence: &str, name: &str, latest: &[String]) -> String {
+    let chars = sequence
+        .chars()
+        .filter(|&c| c != '\u{FE0F}')
+        .collect::<Vec<_>>();
+    let region = |c: char, base: u32| {
+        char::from_u32(c as u32 - base + 'a' as u32).unwrap()
+    };
+    // Regional indicator pairs and tag sequences name a region
+    if chars.len() == 2
+        && chars.iter().all(|&c| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c))
+    {
+        return chars.iter().fold("flag_".to_string(), |mut s, &c| {
+            s.push(region(c, 0x1F1E6));
+            s
+        });
+    }
+    if chars.len() > 2 && chars[0] == '\u{1F3F4}' && chars[1] >= '\u{E0061}' {
+        let tag = chars
+            .iter()
+            .filter(|&&c| ('\u{E0061}'..='\u{E007A}').contains(&c))
+            .map(|&c| region(c, 0xE0061))
+            .collect::<String>();
+        return format!("flag_{}_{}", &tag[..2], &tag[2..]);
+    }
+
+    let name = name.replace('#', "number sign").replace('*', "asterisk");
+    let ascii = name
+        .chars()
+        .map(|c| &latest[c as usize][..])
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let mut out = String::new();
+    for word in ascii.split(|c: char| !c.is_ascii_alphanumeric()) {
+        if !word.is_empty() {
+            if !out.is_empty() {
+                out.push('_');
+            }
+            out.push_str(word);
+        }
+    }
+    out
+}
+
+/// Renders the emoji sequences and their short names.
+pub fn render(emoji: &[(String, String)]) -> String {
+    let mut out = String::new();
+    out.push_str("// File autogenerated with `cargo xtask generate`\n\n");
+    writeln!(out, "pub static EMOJI: [(&str, &str); {}] = [", emoji.len())
+        .unwrap();
+    for (sequence, name) in emoji {
+        out.push_str("    (\"");
+        for c in sequence.chars() {
+            write!(out, "\\u{{{:x}}}", c as u32).unwrap();
+        }
+        writeln!(out, "\", \"{}\"),", name).unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
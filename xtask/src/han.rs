@@ -0,0 +1,315 @@
+//! Renders the readings of Han characters and words from the Unihan database,
+//! the Han-Latin transform and Korean collation of CLDR and the word list
+//! vendored in `data/`.
+//!
+//! * `MANDARIN` maps characters to their pinyin, from the `kMandarin` field,
+//!   or from the Han-Latin transform of CLDR for the characters without it.
+//!   The pinyin is stored once in `PINYIN`, which `MANDARIN` refers to.
+//! * `MANDARIN_WORDS` maps words to the pinyin of their syllables, separated by
+//!   spaces.
+//! * `CANTONESE`, `VIETNAMESE` and `KOREAN` map characters to their readings
+//!   in these languages, from the `kCantonese`, `kVietnamese` and `kHangul`
+//!   fields, or from the Korean collation of CLDR for the Hanja without
+//!   `kHangul`, transliterated like the data set transliterates Han
+//!   characters.
+//!
+//! Pinyin is stored with tone numbers, the neutral tone being 5, and with `v`
+//! for `ü`. The tables are sorted so that they can be binary searched.
+
+use std::char;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use table::escape;
+
+/// The vowels with a tone mark, for the first to the fourth tone.
+const MARKS: [(char, [char; 4]); 6] = [
+    ('a', ['ā', 'á', 'ǎ', 'à']),
+    ('e', ['ē', 'é', 'ě', 'è']),
+    ('i', ['ī', 'í', 'ǐ', 'ì']),
+    ('o', ['ō', 'ó', 'ǒ', 'ò']),
+    ('u', ['ū', 'ú', 'ǔ', 'ù']),
+    ('v', ['ǖ', 'ǘ', 'ǚ', 'ǜ']),
+];
+
+/// The syllabic nasals with a tone mark, with their letter and tone.
+const NASALS: [(char, char, usize); 4] =
+    [('ḿ', 'm', 2), ('ń', 'n', 2), ('ň', 'n', 3), ('ǹ', 'n', 4)];
+
+/// Reads a field of `Unihan_Readings.txt`, returning the first value of every
+/// character that has it.
+pub fn read_unihan(
+    path: &Path,
+    field: &str,
+) -> Result<Vec<(char, String)>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut values = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let invalid = || format!("{}:{}: invalid line", path.display(), i + 1);
+        let mut fields = line.split('\t');
+        let ch = fields
+            .next()
+            .and_then(|s| s.strip_prefix("U+"))
+            .and_then(|s| u32::from_str_radix(s, 16).ok())
+            .and_then(char::from_u32)
+            .ok_or_else(invalid)?;
+        if fields.next() != Some(field) {
+            continue;
+        }
+        // The first value, without the sources of `kHangul`
+        let value = fields
+            .next()
+            .and_then(|s| s.split(' ').next())
+            .and_then(|s| s.split(':').next())
+            .filter(|s| !s.is_empty())
+            .ok_or_else(invalid)?;
+        values.push((ch, value.to_string()));
+    }
+    values.sort();
+    values.dedup_by_key(|&mut (ch, _)| ch);
+    Ok(values)
+}
+
+/// Reads the rules of the Han-Latin transform of CLDR, returning the pinyin of
+/// every character of the rules mapping a set of characters to their pinyin,
+/// such as `[哀哎唉] > āi;`.
+pub fn read_transform(path: &Path) -> Result<Vec<(char, String)>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut values = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let rule = line
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(';'))
+            .and_then(|s| s.split_once("] > "));
+        let (set, pinyin) = match rule {
+            Some(rule) => rule,
+            None => continue,
+        };
+        let invalid = || format!("{}:{}: invalid rule", path.display(), i + 1);
+        let mut chars = set.chars().peekable();
+        while let Some(first) = chars.next() {
+            // Ranges of characters, such as `凡-凣`
+            let last = if chars.peek() == Some(&'-') {
+                chars.next();
+                chars.next().ok_or_else(invalid)?
+            } else {
+                first
+            };
+            if first > last {
+                return Err(invalid());
+            }
+            for ch in first..=last {
+                values.push((ch, pinyin.to_string()));
+            }
+        }
+    }
+    values.sort_by_key(|&(ch, _)| ch);
+    values.dedup_by_key(|&mut (ch, _)| ch);
+    Ok(values)
+}
+
+/// Reads the rules of a CLDR collation, returning the Hanja sorted after a
+/// Hangul syllable, such as `&가<<*伽佳`, with the syllable.
+pub fn read_collation(path: &Path) -> Result<Vec<(char, String)>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut values = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let rule = match line.strip_prefix('&') {
+            Some(rule) => rule,
+            None => continue,
+        };
+        let invalid = || format!("{}:{}: invalid rule", path.display(), i + 1);
+        let (syllable, set) = rule.split_once("<<").ok_or_else(invalid)?;
+        // A set of characters, or a single one
+        let set = set.strip_prefix('*').unwrap_or(set);
+        if syllable.chars().count() != 1 || set.is_empty() {
+            return Err(invalid());
+        }
+        for ch in set.chars() {
+            values.push((ch, syllable.to_string()));
+        }
+    }
+    values.sort_by_key(|&(ch, _)| ch);
+    values.dedup_by_key(|&mut (ch, _)| ch);
+    Ok(values)
+}
+
+/// Converts pinyin with a tone mark, such as `"lǜ"`, to pinyin with a tone
+/// number, such as `"lv4"`.
+pub fn tone_number(pinyin: &str) -> String {
+    let mut tone = 5;
+    let mut out = String::new();
+    for ch in pinyin.chars() {
+        let marked = MARKS
+            .iter()
+            .find_map(|&(vowel, marks)| {
+                marks.iter().position(|&m| m == ch).map(|t| (vowel, t + 1))
+            })
+            .or_else(|| {
+                let nasal = NASALS.iter().find(|&&(m, _, _)| m == ch);
+                nasal.map(|&(_, c, t)| (c, t))
+            });
+        match marked {
+            Some((vowel, t)) => {
+                tone = t;
+                out.push(vowel);
+            },
+            None if ch == 'ü' => out.push('v'),
+            None => out.push(ch),
+        }
+    }
+    write!(out, "{}", tone).unwrap();
+    out
+}
+
+/// The Revised Romanization of the initial consonants, vowels and final
+/// consonants of Hangul syllables.
+const INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s",
+    "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+const VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa",
+    "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu", "ui",
+    "i",
+];
+const CODAS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k",
+    "m", "l", "l", "l", "p", "l", "m", "p", "p", "t",
+    "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// Romanizes Hangul syllables with the Revised Romanization, each on its own.
+pub fn revised_romanization(hangul: &str) -> String {
+    hangul
+        .chars()
+        .map(|ch| match (ch as usize).checked_sub(0xAC00) {
+            Some(s) if s < 11172 => {
+                let (l, v, t) = (s / 588, s % 588 / 28, s % 28);
+                format!("{}{}{}", INITIALS[l], VOWELS[v], CODAS[t])
+            },
+            _ => ch.to_string(),
+        })
+        .collect()
+}
+
+/// Spells a reading like the data set spells the readings of Han characters,
+/// capitalized and followed by a space, such as `"Bei "`.
+pub fn like_data_set(reading: &str) -> String {
+    let mut chars = reading.chars();
+    match chars.next() {
+        Some(first) => {
+            format!("{}{} ", first.to_ascii_uppercase(), chars.as_str())
+        },
+        None => String::new(),
+    }
+}
+
+/// Reads the list of words and their readings, one word per line separated
+/// from its reading by a tab.
+pub fn read_words(path: &Path) -> Result<Vec<(String, String)>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut words = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        match line.split_once('\t') {
+            Some((word, reading)) if !word.is_empty() => {
+                words.push((word.to_string(), reading.to_string()));
+            },
+            _ => {
+                let path = path.display();
+                return Err(format!("{}:{}: invalid line", path, i + 1));
+            },
+        }
+    }
+    words.sort();
+    words.dedup_by(|a, b| a.0 == b.0);
+    Ok(words)
+}
+
+/// Renders the readings of characters and words.
+pub fn render(
+    mandarin: &[(char, String)],
+    words: &[(String, String)],
+    readings: &[(&str, Vec<(char, String)>)],
+) -> String {
+    let mut out = String::new();
+    out.push_str("// File autogenerated with `cargo xtask generate`\n\n");
+
+    let max_word = words.iter().map(|(w, _)| w.chars().count()).max();
+    out.push_str("#[cfg(all(feature = \"alloc\", feature = \"cjk\"))]\n");
+    writeln!(out, "pub const MAX_WORD: usize = {};\n", max_word.unwrap_or(0))
+        .unwrap();
+
+    let mut pinyin = mandarin.iter().map(|(_, p)| &p[..]).collect::<Vec<_>>();
+    pinyin.sort();
+    pinyin.dedup();
+    out.push_str("#[cfg(all(feature = \"alloc\", feature = \"cjk\"))]\n");
+    writeln!(out, "pub static PINYIN: [&str; {}] = [", pinyin.len()).unwrap();
+    for chunk in pinyin.chunks(8) {
+        let line = chunk
+            .iter()
+            .map(|p| format!("\"{}\",", p))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(out, "    {}", line).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("#[cfg(all(feature = \"alloc\", feature = \"cjk\"))]\n");
+    writeln!(
+        out,
+        "pub static MANDARIN: [(char, u16); {}] = [",
+        mandarin.len()
+    )
+    .unwrap();
+    for (ch, p) in mandarin {
+        let i = pinyin.binary_search(&&p[..]).unwrap();
+        writeln!(out, "    ('\\u{{{:x}}}', {}),", *ch as u32, i).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("#[cfg(all(feature = \"alloc\", feature = \"cjk\"))]\n");
+    writeln!(
+        out,
+        "pub static MANDARIN_WORDS: [(&str, &str); {}] = [",
+        words.len()
+    )
+    .unwrap();
+    for (word, reading) in words {
+        writeln!(out, "    (\"{}\", \"{}\"),", escape(word), reading).unwrap();
+    }
+    out.push_str("];\n");
+
+    for (name, values) in readings {
+        out.push_str("\n#[cfg(feature = \"cjk\")]\n");
+        writeln!(
+            out,
+            "pub static {}: [(char, &str); {}] = [",
+            name,
+            values.len()
+        )
+        .unwrap();
+        for (ch, reading) in values {
+            writeln!(
+                out,
+                "    ('\\u{{{:x}}}', \"{}\"),",
+                *ch as u32,
+                escape(reading)
+            )
+            .unwrap();
+        }
+        out.push_str("];\n");
+    }
+    out
+}
@@ -0,0 +1,416 @@
+//! Generates `src/data.rs` from the data set of the `Text::Unidecode` Perl
+//! module, `src/han_data.rs` from the readings of Han characters and words of
+//! the Unihan database, CLDR and a word list, `src/emoji_data.rs` from the
+//! emoji sequences of the Unicode Consortium, and `src/blocks_data.rs` from
+//! the Unicode blocks, all vendored in `data/`.
+//!
+//! Usage:
+//!
+//! * `cargo xtask generate` writes the table to `src/data.rs`, the readings to
+//!   `src/han_data.rs`, the emoji to `src/emoji_data.rs`, the blocks of the
+//!   latest data set to `src/blocks_data.rs`, and the code points whose
+//!   transliteration changed between consecutive data sets to `data/changes/`.
+//! * `cargo xtask check` fails if any of these files differ from what would be
+//!   generated.
+
+mod blocks;
+mod emoji;
+mod han;
+mod perl;
+mod table;
+
+use std::char;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// A version of the data set.
+struct DataSet {
+    /// The name of the data set.
+    name: &'static str,
+    /// The version of `Text::Unidecode` whose block files are used.
+    unidecode: &'static str,
+    /// The version of the Unicode Character Database whose decompositions are
+    /// used for the characters outside of the Basic Multilingual Plane, or
+    /// `None` if the data set only covers the Basic Multilingual Plane, like
+    /// `rust-unidecode` 0.3.0, and transliterates every other code point and
+    /// the blocks missing from `Text::Unidecode` to an empty string.
+    unicode: Option<&'static str>,
+    /// The Cargo feature that keeps the table of the data set available as a
+    /// `TableVersion`, once it is not the latest one.
+    feature: &'static str,
+    /// Whether the ideographs outside of the Basic Multilingual Plane are read
+    /// with their `kMandarin` reading from `data/Unihan`, and the historic
+    /// scripts with `data/historic`.
+    supplementary: bool,
+}
+
+/// The data sets, oldest first. The table is generated from the last one, and
+/// the older ones are stored as their differences from it.
+const DATA_SETS: [DataSet; 2] = [
+    DataSet {
+        name: "1.30",
+        unidecode: "1.30",
+        unicode: None,
+        feature: "table-1-30",
+        supplementary: false,
+    },
+    DataSet {
+        name: "1.30+unicode17",
+        unidecode: "1.30",
+        unicode: Some("17.0.0"),
+        feature: "table-1-30-unicode17",
+        supplementary: true,
+    },
+];
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let command = env::args().nth(1);
+    let result = match command.as_ref().map(|s| &s[..]) {
+        Some("generate") => generate(root).and_then(|files| {
+            files.iter().try_for_each(|(path, contents)| {
+                fs::write(path, contents)
+                    .map_err(|e| format!("{}: {}", path.display(), e))
+            })
+        }),
+        Some("check") => generate(root).and_then(|files| {
+            files.iter().try_for_each(|(path, contents)| {
+                match fs::read_to_string(path) {
+                    Ok(ref current) if current == contents => Ok(()),
+                    _ => Err(format!(
+                        "{} is out of date, run `cargo xtask generate`",
+                        path.display()
+                    )),
+                }
+            })
+        }),
+        _ => Err("usage: cargo xtask (generate | check)".to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Reads the data sets and returns the generated files and their contents.
+fn generate(root: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    let data = root.join("data");
+    let unihan = data.join("Unihan").join("Unihan_Readings.txt");
+    let mandarin = han::read_unihan(&unihan, "kMandarin")?
+        .into_iter()
+        .map(|(ch, pinyin)| (ch, han::tone_number(&pinyin)))
+        .collect::<Vec<_>>();
+    // The readings of the Unihan database take precedence over CLDR
+    let transform = data.join("cldr").join("Han-Latin.txt");
+    let mut pinyin = mandarin.clone();
+    for (ch, reading) in han::read_transform(&transform)? {
+        if mandarin.binary_search_by_key(&ch, |&(c, _)| c).is_err() {
+            pinyin.push((ch, han::tone_number(&reading)));
+        }
+    }
+    pinyin.sort();
+    let mut supplementary =
+        read_letters(&data.join("historic").join("letters.txt"))?;
+    for (ch, pinyin) in &pinyin {
+        if *ch as u32 > 0xFFFF {
+            let plain = pinyin
+                .trim_end_matches(|c: char| c.is_ascii_digit())
+                .replace('v', "u");
+            supplementary.insert(*ch as u32, han::like_data_set(&plain));
+        }
+    }
+    let values = DATA_SETS
+        .iter()
+        .map(|set| read_data_set(&data, set, &supplementary))
+        .collect::<Result<Vec<_>, _>>()?;
+    let latest = values.last().unwrap();
+    let pinned = DATA_SETS
+        .iter()
+        .zip(&values)
+        .take(DATA_SETS.len() - 1)
+        .map(|(set, values)| {
+            // The code points a data set does not cover are left out
+            let end = set.unicode.map_or(0x10000, |_| values.len());
+            (set.feature, table::differences(&values[..end], &latest[..end]))
+        })
+        .collect::<Vec<_>>();
+    let mut files = Vec::new();
+    files.push((
+        root.join("src").join("data.rs"),
+        table::render(latest, &pinned),
+    ));
+    let words = han::read_words(&data.join("words").join("zh.txt"))?;
+    let readings = [
+        ("CANTONESE", "kCantonese"),
+        ("VIETNAMESE", "kVietnamese"),
+        ("KOREAN", "kHangul"),
+    ]
+    .iter()
+    .map(|&(name, field)| {
+        let mut values = han::read_unihan(&unihan, field)?;
+        // The Korean readings of the Unihan database take precedence over the
+        // Korean collation of CLDR
+        if field == "kHangul" {
+            let unihan = values.clone();
+            let collation = data.join("cldr").join("ko.txt");
+            for (ch, reading) in han::read_collation(&collation)? {
+                if unihan.binary_search_by_key(&ch, |&(c, _)| c).is_err() {
+                    values.push((ch, reading));
+                }
+            }
+            values.sort();
+        }
+        let values = values
+            .into_iter()
+            .map(|(ch, reading)| {
+                let reading = match field {
+                    "kCantonese" => reading
+                        .trim_end_matches(|c: char| c.is_ascii_digit())
+                        .to_string(),
+                    "kHangul" => han::revised_romanization(&reading),
+                    _ => reading
+                        .chars()
+                        .map(|c| &latest[c as usize][..])
+                        .collect(),
+                };
+                (ch, han::like_data_set(&reading))
+            })
+            .collect();
+        Ok((name, values))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+    files.push((
+        root.join("src").join("han_data.rs"),
+        han::render(&pinyin, &words, &readings),
+    ));
+    let emoji_test = data.join("emoji").join("emoji-test.txt");
+    let emoji = emoji::read(&emoji_test, latest)?;
+    files.push((
+        root.join("src").join("emoji_data.rs"),
+        emoji::render(&emoji),
+    ));
+    let unicode = DATA_SETS.last().unwrap().unicode.unwrap();
+    let blocks = data.join(format!("Unicode-{}", unicode)).join("Blocks.txt");
+    let blocks = blocks::read(&blocks)?;
+    files.push((
+        root.join("src").join("blocks_data.rs"),
+        blocks::render(&blocks),
+    ));
+    for i in 1..DATA_SETS.len() {
+        files.push((
+            data.join("changes").join(format!("{}.jsonl", DATA_SETS[i].name)),
+            changes(&values[i - 1], &values[i]),
+        ));
+    }
+    Ok(files)
+}
+
+/// Returns the transliteration of every code point in a data set, given the
+/// transliterations of the characters outside of the Basic Multilingual Plane
+/// read from the other sources.
+fn read_data_set(
+    data: &Path,
+    set: &DataSet,
+    supplementary: &HashMap<u32, String>,
+) -> Result<Vec<String>, String> {
+    let dir = data.join(format!("Text-Unidecode-{}", set.unidecode));
+    let blocks = read_blocks(&dir)?;
+    let unicode = match set.unicode {
+        Some(unicode) => unicode,
+        None => {
+            return Ok((0..0x110000)
+                .map(|u| match blocks.get(&(u >> 8)) {
+                    Some(block) => block[(u & 0xff) as usize].clone(),
+                    None => String::new(),
+                })
+                .collect())
+        },
+    };
+    let path = data
+        .join(format!("Unicode-{}", unicode))
+        .join("decompositions.txt");
+    let decompositions = read_decompositions(&path)?;
+    let none = HashMap::new();
+    let supplementary = if set.supplementary { supplementary } else { &none };
+    Ok((0..0x110000)
+        .map(|u| transliterate(u, &blocks, &decompositions, supplementary))
+        .collect())
+}
+
+/// Lists the code points whose transliteration differs between two data sets,
+/// as JSON Lines. Consecutive code points with the same change are listed as
+/// a range, such as `"U+E01F0..U+10FFFF"`.
+fn changes(old: &[String], new: &[String]) -> String {
+    let mut out = String::new();
+    let mut u = 0;
+    while u < old.len() {
+        if old[u] == new[u] {
+            u += 1;
+            continue;
+        }
+        let first = u;
+        while u + 1 < old.len() && old[u + 1] == old[first] {
+            if new[u + 1] != new[first] {
+                break;
+            }
+            u += 1;
+        }
+        let range = match u - first {
+            0 => format!("U+{:04X}", first),
+            _ => format!("U+{:04X}..U+{:04X}", first, u),
+        };
+        writeln!(
+            out,
+            "{{\"code_point\": \"{}\", \"old\": {}, \"new\": {}}}",
+            range,
+            json_string(&old[first]),
+            json_string(&new[first])
+        )
+        .unwrap();
+        u += 1;
+    }
+    out
+}
+
+/// Quotes a string for JSON.
+fn json_string(s: &str) -> String {
+    let mut v = String::from("\"");
+    for ch in s.chars() {
+        match ch {
+            '"' | '\\' => {
+                v.push('\\');
+                v.push(ch);
+            },
+            '\x20'..='\x7e' => v.push(ch),
+            _ => write!(v, "\\u{:04x}", ch as u32).unwrap(),
+        }
+    }
+    v.push('"');
+    v
+}
+
+/// Reads the `xNN.pm` files of `Text::Unidecode`, returning the
+/// transliterations of each block of 256 code points they cover.
+fn read_blocks(dir: &Path) -> Result<HashMap<u32, Vec<String>>, String> {
+    let mut blocks = HashMap::new();
+    for block in 0..0x100 {
+        let path: PathBuf = dir.join(format!("x{:02x}.pm", block));
+        if !path.exists() {
+            continue;
+        }
+        let source = fs::read_to_string(&path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        let strings = perl::parse_block(&source)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        blocks.insert(block, strings);
+    }
+    if blocks.is_empty() {
+        return Err(format!("{}: no block files found", dir.display()));
+    }
+    Ok(blocks)
+}
+
+/// Reads the decompositions of the characters outside of the Basic
+/// Multilingual Plane.
+fn read_decompositions(path: &Path) -> Result<HashMap<u32, Vec<u32>>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut decompositions = HashMap::new();
+    for (i, line) in source.lines().enumerate() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let invalid = || format!("{}:{}: invalid line", path.display(), i + 1);
+        let mut fields = line.split(';');
+        let u = fields.next().and_then(|s| u32::from_str_radix(s, 16).ok());
+        let chars = fields.next().map(|s| {
+            s.split(' ')
+                .map(|c| u32::from_str_radix(c, 16).ok())
+                .collect::<Option<Vec<_>>>()
+        });
+        match (u, chars) {
+            (Some(u), Some(Some(chars))) => {
+                decompositions.insert(u, chars);
+            },
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(decompositions)
+}
+
+/// The default ignorable code points outside of the Basic Multilingual Plane
+/// that are assigned, which are transliterated to an empty string like the
+/// format characters and variation selectors of the data set.
+const IGNORABLE: [(u32, u32); 5] = [
+    (0x1BCA0, 0x1BCA3),
+    (0x1D173, 0x1D17A),
+    (0xE0001, 0xE0001),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// Transliterates a code point like `Text::Unidecode`, except that for code
+/// points above U+FFFF that it does not know about, the transliteration from
+/// the other sources is used, or the characters of their compatibility
+/// decomposition are transliterated instead. Code points that none of them
+/// cover are transliterated to `"[?]"`, like the unknown characters of the
+/// data set.
+fn transliterate(
+    u: u32,
+    blocks: &HashMap<u32, Vec<String>>,
+    decompositions: &HashMap<u32, Vec<u32>>,
+    supplementary: &HashMap<u32, String>,
+) -> String {
+    if char::from_u32(u).is_none() {
+        return String::new();
+    }
+    let unidecode = |u: u32| -> Option<&str> {
+        blocks
+            .get(&(u >> 8))
+            .and_then(|block| block.get((u & 0xff) as usize))
+            .map(|s| &s[..])
+    };
+    let other = |u: u32| supplementary.get(&u).map_or("", |s| &s[..]);
+    let s = unidecode(u).unwrap_or("[?]");
+    if u > 0xFFFF && (s.is_empty() || s == "[?]") {
+        if let Some(s) = supplementary.get(&u) {
+            return s.clone();
+        }
+        if let Some(chars) = decompositions.get(&u) {
+            return chars
+                .iter()
+                .map(|&c| match unidecode(c) {
+                    Some(s) if c <= 0xFFFF => s,
+                    _ => other(c),
+                })
+                .collect();
+        }
+        if IGNORABLE.iter().any(|&(first, last)| u >= first && u <= last) {
+            return String::new();
+        }
+    }
+    s.to_string()
+}
+
+/// Reads the transliterations of the letters of historic scripts, one code
+/// point per line followed by a semicolon and its transliteration.
+fn read_letters(path: &Path) -> Result<HashMap<u32, String>, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut letters = HashMap::new();
+    for (i, line) in source.lines().enumerate() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let invalid = || format!("{}:{}: invalid line", path.display(), i + 1);
+        let line = line.split(" #").next().unwrap_or(line);
+        let (u, s) = line.split_once(';').ok_or_else(invalid)?;
+        let u = u32::from_str_radix(u, 16).map_err(|_| invalid())?;
+        letters.insert(u, s.to_string());
+    }
+    Ok(letters)
+}
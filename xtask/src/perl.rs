@@ -0,0 +1,114 @@
+//! A parser for the subset of Perl used by the `xNN.pm` block files of
+//! `Text::Unidecode`, which assign a list of string literals to an element of
+//! `@Text::Unidecode::Char`.
+
+use std::char;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Parses a block file, returning its strings in order.
+pub fn parse_block(source: &str) -> Result<Vec<String>, String> {
+    let start = source
+        .find("= [")
+        .ok_or_else(|| "missing assignment".to_string())?;
+    let mut chars = source[start + 3..].chars().peekable();
+    let mut strings = Vec::new();
+    loop {
+        skip_blank(&mut chars);
+        match chars.next() {
+            Some('\'') => strings.push(single_quoted(&mut chars)?),
+            Some('"') => strings.push(double_quoted(&mut chars)?),
+            Some(',') => {},
+            Some(']') => return Ok(strings),
+            Some(ch) => return Err(format!("unexpected {:?}", ch)),
+            None => return Err("unterminated list".to_string()),
+        }
+    }
+}
+
+/// Skips whitespace and comments.
+fn skip_blank(chars: &mut Peekable<Chars>) {
+    while let Some(&ch) = chars.peek() {
+        if ch == '#' {
+            while chars.next().is_some_and(|ch| ch != '\n') {}
+        } else if ch.is_whitespace() {
+            chars.next();
+        } else {
+            break;
+        }
+    }
+}
+
+/// Parses the rest of a `'...'` literal, in which only `\\` and `\'` are
+/// escapes.
+fn single_quoted(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('\'') => return Ok(s),
+            Some('\\') => match chars.peek() {
+                Some(&ch) if ch == '\\' || ch == '\'' => {
+                    s.push(ch);
+                    chars.next();
+                },
+                _ => s.push('\\'),
+            },
+            Some(ch) => s.push(ch),
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+}
+
+/// Parses the rest of a `"..."` literal. Interpolation is not supported.
+fn double_quoted(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => match chars.next() {
+                Some('x') => s.push(hex_escape(chars)?),
+                Some('n') => s.push('\n'),
+                Some('t') => s.push('\t'),
+                Some('r') => s.push('\r'),
+                Some('0') => s.push('\0'),
+                Some(ch) if !ch.is_alphanumeric() => s.push(ch),
+                Some(ch) => return Err(format!("unsupported escape \\{}", ch)),
+                None => return Err("unterminated string".to_string()),
+            },
+            Some(ch) if ch == '$' || ch == '@' => {
+                return Err(format!("unsupported interpolation {:?}", ch));
+            },
+            Some(ch) => s.push(ch),
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+}
+
+/// Parses the rest of a `\xHH` or `\x{HHHH}` escape.
+fn hex_escape(chars: &mut Peekable<Chars>) -> Result<char, String> {
+    let mut digits = String::new();
+    if chars.peek() == Some(&'{') {
+        chars.next();
+        loop {
+            match chars.next() {
+                Some('}') => break,
+                Some(ch) => digits.push(ch),
+                None => return Err("unterminated escape".to_string()),
+            }
+        }
+    } else {
+        while digits.len() < 2 {
+            match chars.peek() {
+                Some(&ch) if ch.is_ascii_hexdigit() => {
+                    digits.push(ch);
+                    chars.next();
+                },
+                _ => break,
+            }
+        }
+    }
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("invalid escape \\x{}", digits))
+}
@@ -0,0 +1,226 @@
+//! Renders the transliterations of every code point as a compact two-level
+//! lookup table:
+//!
+//! * `DATA` is a single string containing every transliteration. Each distinct
+//!   transliteration is stored once, and transliterations that are a suffix of
+//!   a longer one share its bytes.
+//! * `POINTERS` contains one `u32` per code point, holding the offset of its
+//!   transliteration in `DATA` in the upper 24 bits and its length in the lower
+//!   8 bits. The pointers are grouped into blocks of 256 code points, mirroring
+//!   the `xNN.pm` files of `Text::Unidecode`, and identical blocks are stored
+//!   once.
+//! * `BLOCKS` maps each block of 256 code points to its block of pointers. The
+//!   first block of pointers maps everything to an empty string and is used for
+//!   blocks the data set does not cover at all.
+//!
+//! The blocks of the scripts listed in `GROUPS` are stored in separate tables,
+//! each with its own `DATA` and `POINTERS`, which are only compiled in if the
+//! Cargo feature of the same name is enabled. `TABLES` lists the tables, with
+//! the table of every other block first, and the upper 4 bits of the entries in
+//! `BLOCKS` select the table of a block.
+//!
+//! The tables of older data sets are stored as the sorted list of code points
+//! whose transliteration differs from the latest table, each behind its own
+//! Cargo feature.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// The script blocks that can be left out with Cargo features, as inclusive
+/// ranges of blocks of 256 code points.
+const GROUPS: [(&str, &[(usize, usize)]); 5] = [
+    (
+        "cjk",
+        &[
+            (0x34, 0x4D),
+            (0x4E, 0x9F),
+            (0xF9, 0xFA),
+            (0x200, 0x2FA),
+            (0x300, 0x323),
+        ],
+    ),
+    ("hangul", &[(0x11, 0x11), (0xAC, 0xD7)]),
+    ("indic", &[(0x09, 0x0D)]),
+    ("yi", &[(0xA0, 0xA4)]),
+    ("syllabics", &[(0x14, 0x16)]),
+];
+
+/// Returns the index of the table of a block of 256 code points.
+fn group_of(block: usize) -> usize {
+    GROUPS
+        .iter()
+        .position(|&(_, ranges)| {
+            ranges.iter().any(|&(start, end)| block >= start && block <= end)
+        })
+        .map_or(0, |g| g + 1)
+}
+
+/// Lists the code points whose transliteration in `old` differs from `new`,
+/// with their transliteration in `old`.
+pub fn differences<'a>(
+    old: &'a [String],
+    new: &[String],
+) -> Vec<(usize, &'a str)> {
+    old.iter()
+        .zip(new)
+        .enumerate()
+        .filter(|&(_, (old, new))| old != new)
+        .map(|(u, (old, _))| (u, &old[..]))
+        .collect()
+}
+
+/// Renders the table, given the transliteration of every code point and the
+/// differences of older data sets from it, with the features enabling them.
+pub fn render(
+    values: &[String],
+    pinned: &[(&str, Vec<(usize, &str)>)],
+) -> String {
+    let mut blocks: Vec<Vec<&[String]>> = vec![Vec::new(); GROUPS.len() + 1];
+    let mut block_index: Vec<HashMap<&[String], usize>> =
+        vec![HashMap::new(); GROUPS.len() + 1];
+    let mut block_ids = Vec::new();
+    let empty = vec![String::new(); 256];
+    blocks[0].push(&empty);
+    block_index[0].insert(&empty, 0);
+    for (block, slice) in values.chunks(256).enumerate() {
+        if slice.iter().all(|s| s.is_empty()) {
+            block_ids.push(0);
+            continue;
+        }
+        let g = group_of(block);
+        let index = *block_index[g].entry(slice).or_insert_with(|| {
+            blocks[g].push(slice);
+            blocks[g].len() - 1
+        });
+        block_ids.push((g << 12) | index);
+    }
+
+    let mut out = String::new();
+    out.push_str("// File autogenerated with `cargo xtask generate`\n\n");
+
+    out.push_str("pub static BLOCKS: [u16; 0x1100] = [\n");
+    for row in block_ids.chunks(12) {
+        let row = row.iter().map(|id| format!("{},", id)).collect::<Vec<_>>();
+        writeln!(out, "    {}", row.join(" ")).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    writeln!(
+        out,
+        "pub static TABLES: [(&str, &[u32]); {}] = [",
+        GROUPS.len() + 1
+    )
+    .unwrap();
+    out.push_str("    (DATA, &POINTERS),\n");
+    for &(name, _) in GROUPS.iter() {
+        let prefix = name.to_uppercase();
+        writeln!(out, "    #[cfg(feature = \"{}\")]", name).unwrap();
+        writeln!(out, "    ({0}_DATA, &{0}_POINTERS),", prefix).unwrap();
+        writeln!(out, "    #[cfg(not(feature = \"{}\"))]", name).unwrap();
+        out.push_str("    (\"\", &[]),\n");
+    }
+    out.push_str("];\n");
+
+    for (g, blocks) in blocks.iter().enumerate() {
+        let (cfg, prefix) = match g {
+            0 => (String::new(), String::new()),
+            _ => {
+                let name = GROUPS[g - 1].0;
+                (
+                    format!("#[cfg(feature = \"{}\")]\n", name),
+                    format!("{}_", name.to_uppercase()),
+                )
+            },
+        };
+        let (data, offset) = layout(blocks);
+
+        write!(out, "\n{}pub static {}DATA: &str = concat!(\n", cfg, prefix)
+            .unwrap();
+        for piece in data.as_bytes().chunks(64) {
+            let piece = String::from_utf8_lossy(piece);
+            writeln!(out, "    \"{}\",", escape(&piece)).unwrap();
+        }
+        out.push_str(");\n");
+
+        write!(
+            out,
+            "\n{}pub static {}POINTERS: [u32; {}] = [\n",
+            cfg,
+            prefix,
+            blocks.len() * 256
+        )
+        .unwrap();
+        for block in blocks {
+            let pointers = block
+                .iter()
+                .map(|s| format!("0x{:08x},", (offset[&s[..]] << 8) | s.len()))
+                .collect::<Vec<_>>();
+            for row in pointers.chunks(8) {
+                writeln!(out, "    {}", row.join(" ")).unwrap();
+            }
+        }
+        out.push_str("];\n");
+    }
+
+    for &(feature, ref differences) in pinned {
+        let name = feature.to_uppercase().replace('-', "_");
+        writeln!(out, "\n#[cfg(feature = \"{}\")]", feature).unwrap();
+        writeln!(
+            out,
+            "pub static {}: [(u32, &str); {}] = [",
+            name,
+            differences.len()
+        )
+        .unwrap();
+        for &(u, s) in differences {
+            writeln!(out, "    (0x{:04X}, \"{}\"),", u, escape(s)).unwrap();
+        }
+        out.push_str("];\n");
+    }
+    out
+}
+
+/// Lays out the distinct transliterations of a list of blocks, longest first,
+/// so that shorter ones can reuse the bytes of a longer one they are a suffix
+/// of. Returns the string and the offset of every transliteration in it.
+fn layout<'a>(blocks: &[&'a [String]]) -> (String, HashMap<&'a str, usize>) {
+    let mut strings = blocks
+        .iter()
+        .flat_map(|block| block.iter())
+        .filter(|s| !s.is_empty())
+        .map(|s| &s[..])
+        .collect::<Vec<_>>();
+    strings.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    strings.dedup();
+
+    let mut data = String::new();
+    let mut offset = HashMap::new();
+    offset.insert("", 0);
+    for s in strings {
+        if offset.contains_key(s) {
+            continue;
+        }
+        let start = data.len();
+        data.push_str(s);
+        for (i, _) in s.char_indices() {
+            offset.entry(&s[i..]).or_insert(start + i);
+        }
+    }
+    (data, offset)
+}
+
+/// Escapes a string for a Rust string literal.
+pub fn escape(s: &str) -> String {
+    let mut v = String::new();
+    for ch in s.chars() {
+        match ch {
+            '\x20'..='\x7e' if ch == '"' || ch == '\\' => {
+                v.push('\\');
+                v.push(ch);
+            },
+            '\x20'..='\x7e' => v.push(ch),
+            _ => write!(v, "\\u{{{:x}}}", ch as u32).unwrap(),
+        }
+    }
+    v
+}